@@ -0,0 +1,441 @@
+// Copyright (c) 2024 DSR Corporation, Denver, Colorado.
+// https://www.dsr-corporation.com
+// SPDX-License-Identifier: Apache-2.0
+
+use std::{
+    collections::HashMap,
+    fmt::Debug,
+    sync::{Mutex, MutexGuard},
+};
+
+use chrono::Utc;
+use log::{trace, warn};
+use log_derive::{logfn, logfn_inputs};
+use serde::{de::DeserializeOwned, Serialize};
+use serde_derive::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::{
+    contracts::types::did::ParsedDid, error::VdrResult, CredentialDefinitionId, DidRecord,
+    RevocationRegistryDefinitionId, SchemaId, DID,
+};
+
+/// Max number of entries kept in memory if no other is configured
+pub const DEFAULT_CACHE_MAX_ENTRIES: usize = 1000;
+/// Time to live (in seconds) of cached DID documents if no other is configured
+pub const DEFAULT_CACHE_DID_TTL: u64 = 300;
+
+/// Settings of the cache for resolved ledger objects
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CacheConfig {
+    /// Max number of entries kept in memory. The least recently used entries are evicted first
+    pub max_entries: usize,
+    /// Time to live (in seconds) of cached DID documents. Immutable objects (Schemas, Credential Definitions,
+    /// Revocation Registry Definitions) never expire
+    pub did_ttl: u64,
+}
+
+impl Default for CacheConfig {
+    fn default() -> Self {
+        CacheConfig {
+            max_entries: DEFAULT_CACHE_MAX_ENTRIES,
+            did_ttl: DEFAULT_CACHE_DID_TTL,
+        }
+    }
+}
+
+/// Persistent storage backend for the cache (file system, database, key-value store etc.).
+///
+/// Values are JSON strings which already contain the expiration time, so the storage does not need to track it.
+/// Storage errors are logged and do not break the resolution.
+pub trait CacheStorage: Sync + Send + Debug {
+    /// Get the value stored for the key
+    fn get(&self, key: &str) -> VdrResult<Option<String>>;
+
+    /// Store the value for the key replacing the existing one
+    fn set(&self, key: &str, value: &str) -> VdrResult<()>;
+
+    /// Remove the value stored for the key
+    fn remove(&self, key: &str) -> VdrResult<()>;
+}
+
+/// Key of the cached ledger object
+#[derive(Debug)]
+pub(crate) enum CacheKey<'a> {
+    Schema(&'a SchemaId),
+    CredentialDefinition(&'a CredentialDefinitionId),
+    RevocationRegistryDefinition(&'a RevocationRegistryDefinitionId),
+    DidDocument(&'a DID),
+}
+
+impl CacheKey<'_> {
+    fn value(&self) -> String {
+        match self {
+            CacheKey::Schema(id) => format!("schema/{}", id.to_string()),
+            CacheKey::CredentialDefinition(id) => {
+                format!("credentialDefinition/{}", id.to_string())
+            }
+            CacheKey::RevocationRegistryDefinition(id) => {
+                format!("revocationRegistryDefinition/{}", id.to_string())
+            }
+            CacheKey::DidDocument(did) => {
+                // DIDs with and without the network part refer to the same document
+                let did = ParsedDid::try_from(*did)
+                    .map(|parsed_did| parsed_did.as_short_did())
+                    .unwrap_or_else(|_| (*did).clone());
+                format!("didDocument/{}", did.as_ref().to_lowercase())
+            }
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct CacheEntry {
+    value: Value,
+    /// Unix timestamp (in seconds) after which the entry is expired
+    #[serde(skip_serializing_if = "Option::is_none")]
+    expires_at: Option<i64>,
+    /// Block of the last DID change the cached DID document corresponds to
+    #[serde(skip_serializing_if = "Option::is_none")]
+    changed: Option<u64>,
+}
+
+impl CacheEntry {
+    fn expired(&self) -> bool {
+        self.expires_at
+            .map_or(false, |expires_at| expires_at <= Utc::now().timestamp())
+    }
+}
+
+#[derive(Debug, Default)]
+struct CacheEntries {
+    entries: HashMap<String, (CacheEntry, u64)>,
+    /// Counter of the cache accesses used to find the least recently used entry
+    tick: u64,
+}
+
+/// Size-bounded cache of the objects resolved from the ledger.
+///
+/// Entries are kept in memory and, if [CacheStorage] is set, in the persistent storage.
+/// Immutable objects are cached forever, DID documents are cached for the configured time and
+/// can be invalidated explicitly (see [crate::LedgerClient::invalidate_cache]).
+#[derive(Debug)]
+pub struct ResolutionCache {
+    config: CacheConfig,
+    entries: Mutex<CacheEntries>,
+    storage: Option<Box<dyn CacheStorage>>,
+}
+
+impl ResolutionCache {
+    pub fn new(config: CacheConfig, storage: Option<Box<dyn CacheStorage>>) -> ResolutionCache {
+        ResolutionCache {
+            config,
+            entries: Mutex::new(CacheEntries::default()),
+            storage,
+        }
+    }
+
+    pub(crate) fn get<T: DeserializeOwned>(&self, key: &CacheKey) -> Option<T> {
+        let entry = self.get_entry(&key.value())?;
+        serde_json::from_value(entry.value).ok()
+    }
+
+    pub(crate) fn set<T: Serialize>(&self, key: &CacheKey, value: &T) {
+        self.set_entry(&key.value(), value, None, None)
+    }
+
+    /// Get the cached DID record if it corresponds to the block of the last DID change
+    #[logfn(Trace)]
+    #[logfn_inputs(Trace)]
+    pub(crate) fn get_did(&self, did: &DID, changed: Option<u64>) -> Option<DidRecord> {
+        let entry = self.get_entry(&CacheKey::DidDocument(did).value())?;
+        if entry.changed != changed {
+            return None;
+        }
+        serde_json::from_value(entry.value).ok()
+    }
+
+    #[logfn(Trace)]
+    #[logfn_inputs(Trace)]
+    pub(crate) fn set_did(&self, did: &DID, changed: Option<u64>, record: &DidRecord) {
+        let expires_at = Utc::now().timestamp() + self.config.did_ttl as i64;
+        self.set_entry(
+            &CacheKey::DidDocument(did).value(),
+            record,
+            Some(expires_at),
+            changed,
+        )
+    }
+
+    /// Remove the cached DID document
+    ///
+    /// # Params
+    ///  - `did`: [DID] - DID which document is changed
+    #[logfn(Trace)]
+    #[logfn_inputs(Trace)]
+    pub fn invalidate_did(&self, did: &DID) {
+        self.remove_entry(&CacheKey::DidDocument(did).value())
+    }
+
+    /// Remove all entries kept in memory (the persistent storage is not affected)
+    pub fn clear(&self) {
+        self.entries().entries.clear();
+    }
+
+    fn get_entry(&self, key: &str) -> Option<CacheEntry> {
+        let entry = {
+            let mut entries = self.entries();
+            entries.tick += 1;
+            let tick = entries.tick;
+            entries.entries.get_mut(key).map(|(entry, last_used)| {
+                *last_used = tick;
+                entry.clone()
+            })
+        };
+
+        let entry = match entry {
+            Some(entry) => entry,
+            None => {
+                let entry = self.load(key)?;
+                self.insert(key, entry.clone());
+                entry
+            }
+        };
+
+        if entry.expired() {
+            self.remove_entry(key);
+            return None;
+        }
+        trace!("ResolutionCache: hit for key {}", key);
+        Some(entry)
+    }
+
+    fn set_entry<T: Serialize>(
+        &self,
+        key: &str,
+        value: &T,
+        expires_at: Option<i64>,
+        changed: Option<u64>,
+    ) {
+        let value = match serde_json::to_value(value) {
+            Ok(value) => value,
+            Err(err) => {
+                warn!(
+                    "ResolutionCache: unable to serialize value for key {}: {}",
+                    key, err
+                );
+                return;
+            }
+        };
+        let entry = CacheEntry {
+            value,
+            expires_at,
+            changed,
+        };
+        self.store(key, &entry);
+        self.insert(key, entry);
+    }
+
+    fn remove_entry(&self, key: &str) {
+        self.entries().entries.remove(key);
+        if let Some(storage) = &self.storage {
+            if let Err(err) = storage.remove(key) {
+                warn!(
+                    "ResolutionCache: unable to remove key {} from storage: {}",
+                    key, err
+                );
+            }
+        }
+    }
+
+    fn insert(&self, key: &str, entry: CacheEntry) {
+        let mut entries = self.entries();
+        entries.tick += 1;
+        let tick = entries.tick;
+        entries.entries.insert(key.to_string(), (entry, tick));
+
+        while entries.entries.len() > self.config.max_entries {
+            let least_recently_used = entries
+                .entries
+                .iter()
+                .min_by_key(|(_, (_, last_used))| *last_used)
+                .map(|(key, _)| key.to_string());
+            match least_recently_used {
+                Some(key) => entries.entries.remove(&key),
+                None => break,
+            };
+        }
+    }
+
+    fn load(&self, key: &str) -> Option<CacheEntry> {
+        let storage = self.storage.as_ref()?;
+        match storage.get(key) {
+            Ok(value) => value.and_then(|value| serde_json::from_str(&value).ok()),
+            Err(err) => {
+                warn!(
+                    "ResolutionCache: unable to read key {} from storage: {}",
+                    key, err
+                );
+                None
+            }
+        }
+    }
+
+    fn store(&self, key: &str, entry: &CacheEntry) {
+        let storage = match &self.storage {
+            Some(storage) => storage,
+            None => return,
+        };
+        let result = serde_json::to_string(entry)
+            .map_err(|err| err.to_string())
+            .and_then(|value| storage.set(key, &value).map_err(|err| err.to_string()));
+        if let Err(err) = result {
+            warn!(
+                "ResolutionCache: unable to write key {} to storage: {}",
+                key, err
+            );
+        }
+    }
+
+    fn entries(&self) -> MutexGuard<'_, CacheEntries> {
+        self.entries
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+#[cfg(test)]
+pub mod test {
+    use super::*;
+    use crate::{
+        contracts::DidMetadata,
+        contracts::{
+            anoncreds::types::schema::test::SCHEMA_ID,
+            did::types::did_doc::test::{TEST_ETHR_DID, TEST_ETHR_DID_WITHOUT_NETWORK},
+        },
+        DidDocumentBuilder,
+    };
+
+    /// In-memory [CacheStorage] standing for the persistent one
+    #[derive(Debug, Default)]
+    pub struct TestStorage(Mutex<HashMap<String, String>>);
+
+    impl CacheStorage for TestStorage {
+        fn get(&self, key: &str) -> VdrResult<Option<String>> {
+            Ok(self.0.lock().unwrap().get(key).cloned())
+        }
+
+        fn set(&self, key: &str, value: &str) -> VdrResult<()> {
+            self.0
+                .lock()
+                .unwrap()
+                .insert(key.to_string(), value.to_string());
+            Ok(())
+        }
+
+        fn remove(&self, key: &str) -> VdrResult<()> {
+            self.0.lock().unwrap().remove(key);
+            Ok(())
+        }
+    }
+
+    fn did_record() -> DidRecord {
+        DidRecord {
+            document: DidDocumentBuilder::base_for_did(&DID::from(TEST_ETHR_DID), 1)
+                .unwrap()
+                .build(),
+            metadata: DidMetadata::default(),
+        }
+    }
+
+    fn schema_key(id: &SchemaId) -> CacheKey {
+        CacheKey::Schema(id)
+    }
+
+    #[test]
+    fn cache_immutable_object_test() {
+        let cache = ResolutionCache::new(CacheConfig::default(), None);
+        let id = SchemaId::from(SCHEMA_ID);
+
+        cache.set(&schema_key(&id), &"schema".to_string());
+
+        assert_eq!(Some("schema".to_string()), cache.get(&schema_key(&id)));
+    }
+
+    #[test]
+    fn evict_least_recently_used_entry_test() {
+        let config = CacheConfig {
+            max_entries: 2,
+            ..CacheConfig::default()
+        };
+        let cache = ResolutionCache::new(config, None);
+        let ids: Vec<SchemaId> = (0..3)
+            .map(|i| SchemaId::from(format!("{}{}", SCHEMA_ID, i).as_str()))
+            .collect();
+
+        cache.set(&schema_key(&ids[0]), &0);
+        cache.set(&schema_key(&ids[1]), &1);
+        cache.get::<u32>(&schema_key(&ids[0]));
+        cache.set(&schema_key(&ids[2]), &2);
+
+        assert_eq!(Some(0), cache.get::<u32>(&schema_key(&ids[0])));
+        assert_eq!(None, cache.get::<u32>(&schema_key(&ids[1])));
+        assert_eq!(Some(2), cache.get::<u32>(&schema_key(&ids[2])));
+    }
+
+    #[test]
+    fn cache_did_for_changed_block_test() {
+        let cache = ResolutionCache::new(CacheConfig::default(), None);
+        let did = DID::from(TEST_ETHR_DID);
+
+        cache.set_did(&did, Some(10), &did_record());
+
+        assert_eq!(Some(did_record()), cache.get_did(&did, Some(10)));
+        assert_eq!(
+            Some(did_record()),
+            cache.get_did(&DID::from(TEST_ETHR_DID_WITHOUT_NETWORK), Some(10))
+        );
+        assert_eq!(None, cache.get_did(&did, Some(11)));
+    }
+
+    #[test]
+    fn expire_did_test() {
+        let config = CacheConfig {
+            did_ttl: 0,
+            ..CacheConfig::default()
+        };
+        let cache = ResolutionCache::new(config, None);
+        let did = DID::from(TEST_ETHR_DID);
+
+        cache.set_did(&did, None, &did_record());
+
+        assert_eq!(None, cache.get_did(&did, None));
+    }
+
+    #[test]
+    fn invalidate_did_test() {
+        let cache = ResolutionCache::new(CacheConfig::default(), None);
+        let did = DID::from(TEST_ETHR_DID);
+        cache.set_did(&did, None, &did_record());
+
+        cache.invalidate_did(&did);
+
+        assert_eq!(None, cache.get_did(&did, None));
+    }
+
+    #[test]
+    fn load_from_storage_test() {
+        let cache = ResolutionCache::new(
+            CacheConfig::default(),
+            Some(Box::new(TestStorage::default())),
+        );
+        let id = SchemaId::from(SCHEMA_ID);
+        cache.set(&schema_key(&id), &"schema".to_string());
+
+        cache.clear();
+
+        assert_eq!(Some("schema".to_string()), cache.get(&schema_key(&id)));
+    }
+}
@@ -5,6 +5,8 @@
 use std::{
     collections::HashMap,
     fmt::{Debug, Formatter},
+    future::Future,
+    time::Duration,
 };
 
 use ethabi::{AbiError, Param, ParamType};
@@ -13,17 +15,31 @@ use log_derive::{logfn, logfn_inputs};
 
 use crate::{
     client::{
-        implementation::web3::{client::Web3Client, contract::Web3Contract},
-        Client, Contract, QuorumHandler,
+        implementation::{
+            failover::{FailoverClient, NodeSelection},
+            offline::OfflineClient,
+            web3::{client::Web3Client, contract::Web3Contract},
+        },
+        subscription::{self, EVENT_POLL_INTERVAL},
+        CacheConfig, CacheStorage, Client, Contract, EventStream, GasPolicy, NonceManager,
+        QuorumHandler, QuorumReport, ResolutionCache,
     },
-    error::{VdrError, VdrResult},
+    contracts::endorsing::build_endorsement_transaction,
+    did_ethr_registry::ETHR_DID_METHOD,
+    did_indy_registry::INDYBESU_DID_METHOD,
+    error::{ContractError, VdrError, VdrResult},
+    signer::Signer,
     types::{
-        Block, ContractConfig, ContractSpec, EventLog, EventQuery, PingStatus, Transaction,
-        TransactionType,
+        BatchRequest, BatchResponse, Block, ContractConfig, ContractParam, ContractSpec,
+        DecodedEvent, DecodedParam, DecodedTransaction, EventLog, EventQuery, PingStatus,
+        ReceiptEvent, Transaction, TransactionEndorsingData, TransactionReceipt, TransactionType,
     },
-    Address, BlockDetails, QuorumConfig,
+    Address, BlockDetails, QuorumConfig, DID,
 };
 
+/// Interval (in milliseconds) between the transaction receipt requests while waiting for the confirmation
+pub const RECEIPT_POLL_INTERVAL: u64 = 200;
+
 /// Client object for interaction with the network
 pub struct LedgerClient {
     chain_id: u64,
@@ -32,6 +48,10 @@ pub struct LedgerClient {
     errors: HashMap<[u8; 4], AbiError>,
     network: Option<String>,
     quorum_handler: Option<QuorumHandler>,
+    gas_policy: GasPolicy,
+    nonce_manager: Option<NonceManager>,
+    offline: bool,
+    cache: Option<ResolutionCache>,
 }
 
 impl LedgerClient {
@@ -39,7 +59,8 @@ impl LedgerClient {
     ///
     /// # Params
     ///  - `chain_id`: [u64] - chain id of network (chain ID is part of the transaction signing process to protect against transaction replay attack)
-    ///  - `rpc_node`: [String] - RPC node endpoint
+    ///  - `rpc_node`: [String] - RPC node endpoint: `http(s)://` URL, `ws(s)://` URL, `ipc://` URL or absolute IPC socket path.
+    ///     Other addresses are rejected with [VdrError::CommonInvalidData]
     ///  - `network`: [String] - Name of the network
     ///  - `contract_configs`: [ContractSpec] - specifications for contracts  deployed on the network
     ///  - `quorum_config`: Option<[QuorumConfig]> - quorum configuration. Can be None if quorum check is not needed
@@ -55,25 +76,45 @@ impl LedgerClient {
         network: Option<&str>,
         quorum_config: Option<&QuorumConfig>,
     ) -> VdrResult<LedgerClient> {
-        let client = Box::new(Web3Client::new(rpc_node)?);
-
-        let contracts = Self::init_contracts(&client, contract_configs)?;
-        let errors = Self::build_error_map(&contracts)?;
-
-        let quorum_handler = match quorum_config {
-            Some(quorum_config) => Some(QuorumHandler::new(quorum_config.clone())?),
-            None => None,
-        };
+        let mut builder = LedgerClientBuilder::new()
+            .set_chain_id(chain_id)
+            .set_rpc_node(rpc_node)
+            .set_contract_configs(contract_configs);
+        if let Some(network) = network {
+            builder = builder.set_network(network);
+        }
+        if let Some(quorum_config) = quorum_config {
+            builder = builder.set_quorum_config(quorum_config);
+        }
+        builder.build()
+    }
 
-        let ledger_client = LedgerClient {
-            chain_id,
-            client,
-            contracts,
-            errors,
-            network: network.map(String::from),
-            quorum_handler,
-        };
-        Ok(ledger_client)
+    /// Create client for building transactions without connection to the ledger (offline mode)
+    ///     Nonces of the sender accounts must be set explicitly with [LedgerClient::set_next_nonce].
+    ///     Built transactions can be signed offline and submitted later by a client connected to the ledger
+    ///
+    /// # Params
+    ///  - `chain_id`: [u64] - chain id of network (chain ID is part of the transaction signing process to protect against transaction replay attack)
+    ///  - `contract_configs`: [ContractSpec] - specifications for contracts  deployed on the network
+    ///  - `network`: [String] - Name of the network
+    ///
+    /// # Returns
+    ///  client: [LedgerClient] - client to use for building transactions
+    #[logfn(Info)]
+    #[logfn_inputs(Debug)]
+    pub fn offline(
+        chain_id: u64,
+        contract_configs: &[ContractConfig],
+        network: Option<&str>,
+    ) -> VdrResult<LedgerClient> {
+        let mut builder = LedgerClientBuilder::new()
+            .set_chain_id(chain_id)
+            .set_contract_configs(contract_configs)
+            .set_offline(true);
+        if let Some(network) = network {
+            builder = builder.set_network(network);
+        }
+        builder.build()
     }
 
     /// Ping Ledger.
@@ -107,15 +148,19 @@ impl LedgerClient {
                     .call_transaction(transaction.to.as_ref(), &transaction.data)
                     .await
             }
-            TransactionType::Write => self.client.submit_transaction(&transaction.encode()?).await,
+            TransactionType::Write => {
+                let result = self.client.submit_transaction(&transaction.encode()?).await;
+                if result.is_err() {
+                    self.release_nonce(transaction);
+                }
+                result
+            }
         };
 
         let data = match result {
             Ok(data) => data,
             Err(VdrError::ClientTransactionReverted(revert_reason)) => {
-                let decoded_reason = self.decode_revert_reason(&revert_reason)?;
-
-                return Err(VdrError::ClientTransactionReverted(decoded_reason));
+                return Err(self.decode_revert_reason(Some(&transaction.to), &revert_reason));
             }
             Err(error) => return Err(error),
         };
@@ -127,7 +172,273 @@ impl LedgerClient {
         Ok(data)
     }
 
+    /// Sign prepared transaction with the key of the sender account and submit it to the ledger
+    ///     Read transactions are submitted without signing
+    ///
+    /// #Params
+    ///  `transaction`: [Transaction] - transaction to sign and submit
+    ///  `signer`: [Signer] - signer holding the key of the transaction sender
+    ///
+    /// #Returns
+    ///  response: [Vec] - transaction execution result (see [LedgerClient::submit_transaction])
+    #[logfn(Info)]
+    #[logfn_inputs(Debug)]
+    pub async fn sign_and_submit(
+        &self,
+        transaction: &Transaction,
+        signer: &dyn Signer,
+    ) -> VdrResult<Vec<u8>> {
+        if transaction.type_ == TransactionType::Read {
+            return self.submit_transaction(transaction).await;
+        }
+
+        let mut transaction = transaction.clone();
+        if let Err(err) = transaction.sign(signer).await {
+            self.release_nonce(&transaction);
+            return Err(err);
+        }
+        self.submit_transaction(&transaction).await
+    }
+
+    /// Endorse the transaction prepared by the author and submit it to the ledger
+    ///     The endorsing data is signed with the author key only if it does not contain the author signature yet
+    ///
+    /// #Params
+    ///  `endorsing_data`: [TransactionEndorsingData] - transaction endorsing data prepared by the author
+    ///  `from`: [Address] - account address of the endorser sending the transaction
+    ///  `signer`: [Signer] - signer holding the key of the endorser (and the author if the data is not signed)
+    ///
+    /// #Returns
+    ///  response: [Vec] - transaction execution result (see [LedgerClient::submit_transaction])
+    #[logfn(Info)]
+    #[logfn_inputs(Debug)]
+    pub async fn sign_and_submit_endorsement(
+        &self,
+        endorsing_data: &TransactionEndorsingData,
+        from: &Address,
+        signer: &dyn Signer,
+    ) -> VdrResult<Vec<u8>> {
+        let transaction = match endorsing_data.signature {
+            Some(_) => build_endorsement_transaction(self, from, endorsing_data).await?,
+            None => {
+                let mut endorsing_data = endorsing_data.clone();
+                endorsing_data.sign(signer).await?;
+                build_endorsement_transaction(self, from, &endorsing_data).await?
+            }
+        };
+        self.sign_and_submit(&transaction, signer).await
+    }
+
+    /// Send prepared write transaction to the ledger without waiting for its inclusion into a block
+    ///     Use [LedgerClient::wait_for_receipt] to confirm the transaction later
+    ///
+    /// #Params
+    ///  `transaction`: [Transaction] - signed write transaction to send
+    ///
+    /// #Returns
+    ///  hash: [Vec] - hash of the sent transaction
+    #[logfn(Info)]
+    #[logfn_inputs(Debug)]
+    pub async fn send_transaction(&self, transaction: &Transaction) -> VdrResult<Vec<u8>> {
+        if transaction.type_ != TransactionType::Write {
+            return Err(VdrError::ClientInvalidTransaction(
+                "Only write transactions can be sent".to_string(),
+            ));
+        }
+
+        let result = self.client.send_transaction(&transaction.encode()?).await;
+        if result.is_err() {
+            self.release_nonce(transaction);
+        }
+
+        match result {
+            Err(VdrError::ClientTransactionReverted(revert_reason)) => {
+                Err(self.decode_revert_reason(Some(&transaction.to), &revert_reason))
+            }
+            result => result,
+        }
+    }
+
+    /// Simulate the execution of the write transaction without submitting it to the ledger
+    ///     The transaction data is executed as a call from the sender account at the latest block,
+    ///     so the transaction does not need to be signed.
+    ///     Custom contract errors are reported as [VdrError::ContractError]
+    ///
+    /// #Params
+    ///  `transaction`: [Transaction] - write transaction to simulate
+    ///
+    /// #Returns
+    ///  response: [Vec] - result data of the transaction execution
+    #[logfn(Info)]
+    #[logfn_inputs(Debug)]
+    pub async fn simulate_transaction(&self, transaction: &Transaction) -> VdrResult<Vec<u8>> {
+        if transaction.type_ != TransactionType::Write {
+            return Err(VdrError::ClientInvalidTransaction(
+                "Only write transactions can be simulated".to_string(),
+            ));
+        }
+        let from = transaction.from.as_ref().ok_or_else(|| {
+            VdrError::ClientInvalidTransaction("Transaction sender address is not set".to_string())
+        })?;
+
+        let result = self
+            .client
+            .simulate_transaction(from, transaction.to.as_ref(), &transaction.data)
+            .await;
+
+        match result {
+            Err(VdrError::ClientTransactionReverted(revert_reason)) => {
+                Err(self.decode_revert_reason(Some(&transaction.to), &revert_reason))
+            }
+            result => result,
+        }
+    }
+
+    /// Wait until the transaction is included into a block and confirmed by the requested number of blocks
+    ///     The receipt is returned for reverted transactions as well (see [TransactionReceipt::status])
+    ///
+    /// #Params
+    ///  `hash`: [Vec] - hash of the transaction returned by [LedgerClient::send_transaction]
+    ///  `confirmations`: [u64] - number of blocks (including the one containing the transaction) to wait for
+    ///  `timeout`: [Duration] - max time to wait for the receipt
+    ///
+    /// #Returns
+    ///  receipt: [TransactionReceipt] - receipt of the transaction with decoded events
+    #[logfn(Info)]
+    #[logfn_inputs(Debug)]
+    pub async fn wait_for_receipt(
+        &self,
+        hash: &[u8],
+        confirmations: u64,
+        timeout: Duration,
+    ) -> VdrResult<TransactionReceipt> {
+        let wait = async {
+            loop {
+                if let Some(receipt) = self.client.get_transaction_receipt(hash).await? {
+                    let latest_block = self.client.get_block(None).await?;
+                    if latest_block.number + 1 >= receipt.block_number + confirmations {
+                        return Ok::<TransactionReceipt, VdrError>(receipt);
+                    }
+                }
+                async_std::task::sleep(Duration::from_millis(RECEIPT_POLL_INTERVAL)).await;
+            }
+        };
+
+        let receipt = async_std::future::timeout(timeout, wait)
+            .await
+            .map_err(|_| {
+                VdrError::ClientTransactionTimeout(format!(
+                    "Transaction 0x{} is not confirmed within {:?}",
+                    hex::encode(hash),
+                    timeout
+                ))
+            })??;
+
+        Ok(self.decode_receipt(receipt))
+    }
+
+    /// Decode the contract method call encoded by the transaction.
+    ///     The contract is found by the transaction recipient address and the method by its 4-byte selector
+    ///
+    /// # Params
+    ///  `transaction`: [Transaction] - transaction to decode
+    ///
+    /// # Returns
+    ///  decoded: [DecodedTransaction] - contract method name, named parameters and parsed payload
+    #[logfn(Info)]
+    #[logfn_inputs(Debug)]
+    pub fn decode_transaction(&self, transaction: &Transaction) -> VdrResult<DecodedTransaction> {
+        let (name, contract) = self.contract_by_address(&transaction.to)?;
+        if transaction.data.len() < 4 {
+            return Err(VdrError::ClientInvalidTransaction(
+                "Transaction data does not contain method selector".to_string(),
+            ));
+        }
+        let (selector, arguments) = transaction.data.split_at(4);
+
+        let function = contract
+            .functions()
+            .into_iter()
+            .find(|function| function.short_signature() == selector)
+            .ok_or_else(|| {
+                VdrError::ClientInvalidTransaction(format!(
+                    "Unknown method selector 0x{} of {} contract",
+                    hex::encode(selector),
+                    name
+                ))
+            })?;
+        let params = function.decode_input(arguments).map_err(|err| {
+            VdrError::ClientInvalidTransaction(format!(
+                "Unable to decode {} method parameters. Err: {:?}",
+                function.name, err
+            ))
+        })?;
+
+        Ok(DecodedTransaction::new(
+            name,
+            &function.name,
+            &function.inputs,
+            &params,
+        ))
+    }
+
+    /// Decode the contract method call requested by the endorsing data
+    ///
+    /// # Params
+    ///  `data`: [TransactionEndorsingData] - endorsing data to decode
+    ///
+    /// # Returns
+    ///  decoded: [DecodedTransaction] - contract method name, named parameters (including author identity) and parsed payload
+    #[logfn(Info)]
+    #[logfn_inputs(Debug)]
+    pub fn decode_endorsing_data(
+        &self,
+        data: &TransactionEndorsingData,
+    ) -> VdrResult<DecodedTransaction> {
+        let (name, contract) = self.contract_by_address(&data.to)?;
+        let function = contract.function(&data.endorsing_method)?;
+
+        // endorsing methods accept author identity and signature (v, r, s) before the original method params
+        let mut inputs = function.inputs.iter().take(1).cloned().collect::<Vec<_>>();
+        inputs.extend(function.inputs.iter().skip(4).cloned());
+        let mut params: Vec<ContractParam> = vec![(&data.from).try_into()?];
+        params.extend(data.params.iter().cloned());
+
+        Ok(DecodedTransaction::new(
+            name,
+            &data.method,
+            &inputs,
+            &params,
+        ))
+    }
+
+    /// Request the transaction result from the quorum nodes and build the detailed report
+    ///     In contrast to [LedgerClient::submit_transaction], the report is returned even if the quorum is not reached
+    ///
+    /// #Params
+    ///  `transaction`: [Transaction] - submitted transaction
+    ///  `response`: [Vec] - transaction execution result returned by [LedgerClient::submit_transaction]
+    ///
+    /// #Returns
+    ///  report: [QuorumReport] - results of the quorum nodes
+    #[logfn(Info)]
+    #[logfn_inputs(Debug)]
+    pub async fn quorum_report(
+        &self,
+        transaction: &Transaction,
+        response: &[u8],
+    ) -> VdrResult<QuorumReport> {
+        match &self.quorum_handler {
+            Some(quorum_handler) => Ok(quorum_handler.report(transaction, response).await),
+            None => Err(VdrError::ClientInvalidState(
+                "Quorum is not configured".to_string(),
+            )),
+        }
+    }
+
     /// Submit prepared events query to the ledger
+    ///     If quorum is configured, the events returned by the quorum nodes are compared with the received ones.
+    ///     Query without `to_block` is bound by the latest block to get the same log set from all nodes.
     ///
     /// #Params
     ///  `query`: [EventQuery] - events query to submit
@@ -137,22 +448,192 @@ impl LedgerClient {
     #[logfn(Info)]
     #[logfn_inputs(Debug)]
     pub async fn query_events(&self, query: &EventQuery) -> VdrResult<Vec<EventLog>> {
-        let events = self.client.query_events(query).await?;
-        // TODO: Check quorum for events
+        let quorum_handler = match &self.quorum_handler {
+            Some(quorum_handler) => quorum_handler,
+            None => return self.client.query_events(query).await,
+        };
+
+        // fix the upper bound of the range so that all quorum nodes return the same log set
+        let query = match query.to_block {
+            Some(_) => query.clone(),
+            None => {
+                let latest_block = self.client.get_block(None).await?;
+                EventQuery {
+                    to_block: Some(Block::from(latest_block.number)),
+                    ..query.clone()
+                }
+            }
+        };
+
+        let events = self.client.query_events(&query).await?;
+        quorum_handler.check_events(&query, &events).await?;
         Ok(events)
     }
 
-    /// Get receipt for the given block hash
+    /// Submit multiple read transactions and events queries to the ledger as a single JSON-RPC batch
+    ///
+    /// #Params
+    ///  `requests`: [Vec] - list of read transactions and events queries to submit
+    ///
+    /// #Returns
+    ///  responses: [Vec] - results of requests execution in the order of requests.
+    ///    Failure of a single request does not affect the results of others
+    #[logfn(Info)]
+    #[logfn_inputs(Debug)]
+    pub async fn submit_batch(
+        &self,
+        requests: &[BatchRequest],
+    ) -> VdrResult<Vec<VdrResult<BatchResponse>>> {
+        let has_write_transaction = requests.iter().any(|request| match request {
+            BatchRequest::Call(transaction) => transaction.type_ == TransactionType::Write,
+            BatchRequest::Events(_) => false,
+        });
+        if has_write_transaction {
+            return Err(VdrError::ClientInvalidTransaction(
+                "Only read transactions can be submitted as a batch".to_string(),
+            ));
+        }
+        if requests.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let quorum_handler = match &self.quorum_handler {
+            Some(quorum_handler) => quorum_handler,
+            None => {
+                let responses = self.client.submit_batch(requests).await?;
+                return Ok(self.decode_batch_responses(requests, responses));
+            }
+        };
+
+        // fix the upper bound of events queries so that all quorum nodes return the same log set
+        let mut requests = requests.to_vec();
+        let has_open_query = requests.iter().any(|request| match request {
+            BatchRequest::Events(query) => query.to_block.is_none(),
+            BatchRequest::Call(_) => false,
+        });
+        if has_open_query {
+            let latest_block = Block::from(self.client.get_block(None).await?.number);
+            for request in requests.iter_mut() {
+                if let BatchRequest::Events(query) = request {
+                    query.to_block.get_or_insert_with(|| latest_block.clone());
+                }
+            }
+        }
+
+        // every quorum node receives the whole batch once, results are compared by request position
+        let responses = self.client.submit_batch(&requests).await?;
+        let reports = quorum_handler.report_batch(&requests, &responses).await;
+        let mut responses = self.decode_batch_responses(&requests, responses);
+        for ((request, response), report) in requests.iter().zip(responses.iter_mut()).zip(reports)
+        {
+            if response.is_ok() && !report.reached {
+                *response = Err(VdrError::QuorumNotReached(
+                    format!("Quorum not reached for batch request: {:?}", request),
+                    report,
+                ));
+            }
+        }
+        Ok(responses)
+    }
+
+    /// Resolve multiple ledger objects with a single batch request.
+    ///     Objects found by `lookup` (e.g. in the cache) are not requested from the ledger
+    pub(crate) async fn resolve_batch<'a, I, T, Fut>(
+        &'a self,
+        ids: &'a [I],
+        lookup: impl Fn(&'a I) -> VdrResult<Option<T>>,
+        build: impl Fn(&'a I) -> Fut,
+        process: impl Fn(&'a I, &[u8]) -> VdrResult<T>,
+    ) -> VdrResult<Vec<VdrResult<T>>>
+    where
+        Fut: Future<Output = VdrResult<Transaction>>,
+    {
+        let mut results: Vec<Option<VdrResult<T>>> = Vec::with_capacity(ids.len());
+        let mut pending = Vec::new();
+        let mut requests = Vec::new();
+        for (index, id) in ids.iter().enumerate() {
+            match lookup(id) {
+                Ok(None) => match build(id).await {
+                    Ok(transaction) => {
+                        requests.push(BatchRequest::Call(transaction));
+                        pending.push(index);
+                        results.push(None);
+                    }
+                    Err(err) => results.push(Some(Err(err))),
+                },
+                result => results.push(result.transpose()),
+            }
+        }
+
+        let responses = self.submit_batch(&requests).await?;
+        for (index, response) in pending.into_iter().zip(responses) {
+            let result = match response {
+                Ok(BatchResponse::Call(data)) => process(&ids[index], &data),
+                Ok(BatchResponse::Events(_)) => Err(VdrError::ClientInvalidResponse(
+                    "Unexpected events response for the read transaction".to_string(),
+                )),
+                Err(err) => Err(err),
+            };
+            results[index] = Some(result);
+        }
+
+        Ok(results
+            .into_iter()
+            .map(|result| {
+                result.unwrap_or_else(|| {
+                    Err(VdrError::ClientInvalidResponse(
+                        "Missing batch response".to_string(),
+                    ))
+                })
+            })
+            .collect())
+    }
+
+    /// Subscribe to log events matching to the query
+    ///
+    /// Events are received via `eth_subscribe` when the client is connected to the node using WebSocket or IPC,
+    /// otherwise the ledger is polled with `eth_getLogs` requests.
+    /// In case of connection lost, the subscription is re-established and all events emitted since the last seen block are delivered.
+    ///
+    /// #Params
+    ///  `query`: [EventQuery] - events query. If `from_block` is set, past events starting from this block are delivered first.
+    ///     `to_block` is ignored.
+    ///
+    /// #Returns
+    ///  stream: [EventStream] - endless stream of log events received from the ledger
+    #[logfn_inputs(Debug)]
+    pub fn subscribe_events(&self, query: &EventQuery) -> EventStream<'_, EventLog> {
+        subscription::subscribe(
+            self,
+            query.clone(),
+            Duration::from_millis(EVENT_POLL_INTERVAL),
+        )
+    }
+
+    /// Get receipt for the given transaction hash
     ///
     /// # Params
-    ///  `transaction`: [Transaction] - transaction to submit
+    ///  `hash`: [Vec] - hash of the transaction included into a block
     ///
     /// # Returns
-    ///  receipt: [String] - receipt for the given block
+    ///  receipt: [TransactionReceipt] - receipt of the transaction with decoded events
     #[logfn(Info)]
     #[logfn_inputs(Debug)]
-    pub async fn get_receipt(&self, hash: &[u8]) -> VdrResult<String> {
-        self.client.get_receipt(hash).await
+    pub async fn get_receipt(&self, hash: &[u8]) -> VdrResult<TransactionReceipt> {
+        let receipt = self
+            .client
+            .get_transaction_receipt(hash)
+            .await?
+            .ok_or_else(|| {
+                let vdr_error =
+                    VdrError::ClientInvalidResponse("Missing transaction receipt".to_string());
+
+                warn!("Error: {} getting receipt", vdr_error);
+
+                vdr_error
+            })?;
+
+        Ok(self.decode_receipt(receipt))
     }
 
     /// Get a number of transactions sent by the given account address
@@ -168,6 +649,144 @@ impl LedgerClient {
         self.client.get_transaction_count(address).await
     }
 
+    /// Get the manager of the write transaction nonces if nonce management is enabled
+    ///     (see [LedgerClientBuilder::set_nonce_management])
+    ///
+    /// # Returns
+    ///  nonce_manager: Option<[NonceManager]> - nonce manager of the client
+    pub fn nonce_manager(&self) -> Option<&NonceManager> {
+        self.nonce_manager.as_ref()
+    }
+
+    /// Set the nonce to use for the next write transaction of the account.
+    ///     Required in the offline mode where the nonce cannot be requested from the ledger
+    ///
+    /// # Params
+    ///  - `address`: [Address] - account address
+    ///  - `nonce`: [u64] - nonce of the next transaction (number of the account transactions sent to the ledger)
+    #[logfn(Info)]
+    #[logfn_inputs(Debug)]
+    pub fn set_next_nonce(&self, address: &Address, nonce: u64) -> VdrResult<()> {
+        let nonce_manager = self.nonce_manager.as_ref().ok_or_else(|| {
+            VdrError::ClientInvalidState(
+                "Nonce management is not enabled for the client".to_string(),
+            )
+        })?;
+        nonce_manager.set_next(address, nonce);
+        Ok(())
+    }
+
+    /// Whether the client is in the offline mode (see [LedgerClientBuilder::set_offline])
+    pub fn is_offline(&self) -> bool {
+        self.offline
+    }
+
+    /// Get the cache of the resolved ledger objects if caching is enabled
+    ///     (see [LedgerClientBuilder::set_cache_config])
+    ///
+    /// # Returns
+    ///  cache: Option<[ResolutionCache]> - cache of the client
+    pub fn cache(&self) -> Option<&ResolutionCache> {
+        self.cache.as_ref()
+    }
+
+    /// Invalidate the cached DID document changed by the event.
+    ///     Intended to be called for the events received from the DID registries (e.g. with [LedgerClient::subscribe_events])
+    ///     `DIDCreated`, `DIDUpdated`, `DIDDeactivated` events of `IndyDidRegistry` and
+    ///     `DIDOwnerChanged`, `DIDDelegateChanged`, `DIDAttributeChanged` events of `EthereumExtDidRegistry` are handled
+    ///
+    /// # Params
+    ///  `log`: [EventLog] - event received from the ledger
+    #[logfn(Info)]
+    #[logfn_inputs(Debug)]
+    pub fn invalidate_cache(&self, log: &EventLog) {
+        let cache = match &self.cache {
+            Some(cache) => cache,
+            None => return,
+        };
+        let event = match self.decode_event(log) {
+            Some(event) => event,
+            None => return,
+        };
+        let method = match (event.contract.as_str(), event.name.as_str()) {
+            ("IndyDidRegistry", "DIDCreated" | "DIDUpdated" | "DIDDeactivated") => {
+                INDYBESU_DID_METHOD
+            }
+            (
+                "EthereumExtDidRegistry",
+                "DIDOwnerChanged" | "DIDDelegateChanged" | "DIDAttributeChanged",
+            ) => ETHR_DID_METHOD,
+            _ => return,
+        };
+        if let Some(identity) = event.params.iter().find(|param| param.name == "identity") {
+            let did = DID::build(method, None, &format!("0x{}", identity.value));
+            cache.invalidate_did(&did);
+        }
+    }
+
+    /// Get the nonce for the next write transaction of the account
+    ///     The nonce is reserved by the [NonceManager] if nonce management is enabled,
+    ///     otherwise the number of the account transactions is used
+    #[logfn(Info)]
+    #[logfn_inputs(Debug)]
+    pub(crate) async fn next_nonce(&self, address: &Address) -> VdrResult<u64> {
+        match &self.nonce_manager {
+            Some(nonce_manager) if self.offline => nonce_manager.reserve_offline(address),
+            Some(nonce_manager) => nonce_manager.reserve(self, address).await,
+            None => self.get_transaction_count(address).await,
+        }
+    }
+
+    fn release_nonce(&self, transaction: &Transaction) {
+        if let (Some(nonce_manager), Some(from), Some(nonce)) = (
+            &self.nonce_manager,
+            transaction.from.as_ref(),
+            transaction.nonce,
+        ) {
+            nonce_manager.release(from, nonce);
+        }
+    }
+
+    /// Estimate gas required for the transaction execution
+    ///
+    /// # Params
+    ///  `transaction`: [Transaction] - transaction to estimate
+    ///
+    /// # Returns
+    ///  gas: [u64] - estimated amount of gas
+    #[logfn(Info)]
+    #[logfn_inputs(Debug)]
+    pub(crate) async fn estimate_gas(&self, transaction: &Transaction) -> VdrResult<u64> {
+        let result = self
+            .client
+            .estimate_gas(
+                transaction.from.as_ref(),
+                transaction.to.as_ref(),
+                &transaction.data,
+            )
+            .await;
+
+        match result {
+            Err(VdrError::ClientTransactionReverted(revert_reason)) => {
+                Err(self.decode_revert_reason(Some(&transaction.to), &revert_reason))
+            }
+            result => result,
+        }
+    }
+
+    #[logfn(Info)]
+    #[logfn_inputs(Debug)]
+    pub(crate) async fn get_gas_price(&self) -> VdrResult<u64> {
+        self.client.get_gas_price().await
+    }
+
+    pub(crate) async fn open_event_subscription(
+        &self,
+        query: &EventQuery,
+    ) -> VdrResult<EventStream<'static, EventLog>> {
+        self.client.subscribe_events(query).await
+    }
+
     pub(crate) fn contract(&self, name: &str) -> VdrResult<&dyn Contract> {
         self.contracts
             .get(name)
@@ -181,6 +800,24 @@ impl LedgerClient {
             })
     }
 
+    fn contract_by_address(&self, address: &Address) -> VdrResult<(&str, &dyn Contract)> {
+        self.contracts
+            .iter()
+            .find(|(_, contract)| {
+                contract
+                    .address()
+                    .as_ref()
+                    .eq_ignore_ascii_case(address.as_ref())
+            })
+            .map(|(name, contract)| (name.as_str(), contract.as_ref()))
+            .ok_or_else(|| {
+                VdrError::ClientInvalidTransaction(format!(
+                    "Unknown contract address: {}",
+                    address.as_ref()
+                ))
+            })
+    }
+
     pub(crate) fn chain_id(&self) -> u64 {
         self.chain_id
     }
@@ -189,10 +826,13 @@ impl LedgerClient {
         self.network.as_ref()
     }
 
+    pub(crate) fn gas_policy(&self) -> &GasPolicy {
+        &self.gas_policy
+    }
+
     #[logfn(Info)]
     #[logfn_inputs(Debug)]
     fn init_contracts(
-        client: &Web3Client,
         contract_configs: &[ContractConfig],
     ) -> VdrResult<HashMap<String, Box<dyn Contract>>> {
         let mut contracts: HashMap<String, Box<dyn Contract>> = HashMap::new();
@@ -215,7 +855,7 @@ impl LedgerClient {
                 }
             };
 
-            let contract = Web3Contract::new(client, &contract_config.address, &spec)?;
+            let contract = Web3Contract::new(&contract_config.address, &spec)?;
             contracts.insert(spec.name.clone(), Box::new(contract));
         }
 
@@ -261,7 +901,65 @@ impl LedgerClient {
             .collect()
     }
 
-    fn decode_revert_reason(&self, revert_reason: &str) -> VdrResult<String> {
+    /// Decode revert reasons of the read transactions failed in a batch
+    fn decode_batch_responses(
+        &self,
+        requests: &[BatchRequest],
+        responses: Vec<VdrResult<BatchResponse>>,
+    ) -> Vec<VdrResult<BatchResponse>> {
+        requests
+            .iter()
+            .zip(responses)
+            .map(|(request, response)| match (request, response) {
+                (
+                    BatchRequest::Call(transaction),
+                    Err(VdrError::ClientTransactionReverted(revert_reason)),
+                ) => Err(self.decode_revert_reason(Some(&transaction.to), &revert_reason)),
+                (_, response) => response,
+            })
+            .collect()
+    }
+
+    /// Convert the revert reason returned by the ledger into the error reported to the caller:
+    ///     custom errors of the registered contracts are decoded into [VdrError::ContractError],
+    ///     `Error(string)` and `Panic(uint256)` are formatted into [VdrError::ClientTransactionReverted]
+    fn decode_revert_reason(&self, to: Option<&Address>, revert_reason: &str) -> VdrError {
+        let (error, arguments) = match self.parse_revert_reason(revert_reason) {
+            Ok(parsed) => parsed,
+            Err(err) => return err,
+        };
+
+        if error.name == "Error" || error.name == "Panic" {
+            let inputs: Vec<String> = error
+                .inputs
+                .iter()
+                .zip(arguments.iter())
+                .map(|(input, argument)| format!("{}: {}", input.name, argument))
+                .collect();
+            return VdrError::ClientTransactionReverted(format!(
+                "{}({})",
+                error.name,
+                inputs.join(", ")
+            ));
+        }
+
+        let contract = to
+            .and_then(|to| self.contract_by_address(to).ok())
+            .map(|(contract, _)| contract);
+        let args = error
+            .inputs
+            .iter()
+            .zip(arguments.iter())
+            .map(|(input, argument)| DecodedParam::new(input, argument))
+            .collect();
+        VdrError::ContractError(ContractError::new(contract, &error.name, args))
+    }
+
+    /// Match the revert reason with the registered errors and decode its arguments
+    fn parse_revert_reason(
+        &self,
+        revert_reason: &str,
+    ) -> VdrResult<(&AbiError, Vec<ContractParam>)> {
         let error_data = hex::decode(revert_reason.trim_start_matches("0x")).map_err(|_| {
             VdrError::ContractInvalidResponseData(
                 format!(
@@ -309,17 +1007,47 @@ impl LedgerClient {
             )
         })?;
 
-        let inputs_str: Vec<String> = error
-            .inputs
+        Ok((error, decoded_args))
+    }
+
+    /// Decode the revert reason and the logs of the receipt with the registered contract ABIs
+    fn decode_receipt(&self, receipt: TransactionReceipt) -> TransactionReceipt {
+        let revert_reason = receipt.revert_reason.map(|revert_reason| {
+            match self.decode_revert_reason(None, &revert_reason) {
+                VdrError::ClientTransactionReverted(decoded) => decoded,
+                VdrError::ContractError(error) => error.to_string(),
+                _ => revert_reason,
+            }
+        });
+        let events = receipt
+            .logs
             .iter()
-            .enumerate()
-            .map(|(i, input)| format!("{}: {}", input.name, decoded_args[i].to_string()))
+            .filter_map(|log| ReceiptEvent::parse(self, log))
             .collect();
 
-        let inputs_joined = inputs_str.join(", ");
-        let decoded_str = format!("{}({})", error.name, inputs_joined);
+        TransactionReceipt {
+            revert_reason,
+            events,
+            ..receipt
+        }
+    }
 
-        Ok(decoded_str)
+    /// Decode the log with the ABI of the registered contract emitted it.
+    ///     If the log address is unknown, the first contract (by name) having the matching event is used
+    pub(crate) fn decode_event(&self, log: &EventLog) -> Option<DecodedEvent> {
+        let mut contracts: Vec<(&String, &Box<dyn Contract>)> = self
+            .contracts
+            .iter()
+            .filter(|(_, contract)| log.is_emitted_by(contract.address()))
+            .collect();
+        contracts.sort_by_key(|(name, _)| *name);
+
+        contracts.into_iter().find_map(|(name, contract)| {
+            contract
+                .events()
+                .into_iter()
+                .find_map(|event| DecodedEvent::decode(name, event, log))
+        })
     }
 
     #[logfn(Info)]
@@ -337,11 +1065,220 @@ impl Debug for LedgerClient {
     }
 }
 
+/// Builder object for [LedgerClient]
+///
+/// Exactly one of `rpc_node`, `rpc_nodes` or a custom [Client] implementation must be set.
+/// A custom client allows to put an own transport (instrumentation, request signing proxy,
+/// in-process fake ledger etc.) under all `build_*`/`parse_*` functions of the library.
+#[derive(Default)]
+pub struct LedgerClientBuilder {
+    chain_id: u64,
+    rpc_node: Option<String>,
+    rpc_nodes: Vec<String>,
+    node_selection: NodeSelection,
+    client: Option<Box<dyn Client>>,
+    contract_configs: Vec<ContractConfig>,
+    network: Option<String>,
+    quorum_config: Option<QuorumConfig>,
+    gas_policy: GasPolicy,
+    nonce_management: bool,
+    offline: bool,
+    cache_config: Option<CacheConfig>,
+    cache_storage: Option<Box<dyn CacheStorage>>,
+}
+
+impl LedgerClientBuilder {
+    pub fn new() -> LedgerClientBuilder {
+        LedgerClientBuilder::default()
+    }
+
+    /// Set chain id of network (chain ID is part of the transaction signing process to protect against transaction replay attack)
+    pub fn set_chain_id(mut self, chain_id: u64) -> LedgerClientBuilder {
+        self.chain_id = chain_id;
+        self
+    }
+
+    /// Set RPC node endpoint: `http(s)://` URL, `ws(s)://` URL, `ipc://` URL or absolute IPC socket path.
+    ///     Other addresses are rejected with [VdrError::CommonInvalidData] on build
+    pub fn set_rpc_node(mut self, rpc_node: &str) -> LedgerClientBuilder {
+        self.rpc_node = Some(rpc_node.to_string());
+        self
+    }
+
+    /// Set several RPC node endpoints in the order of preference.
+    ///     Requests are switched to the next healthy node if the current one is unreachable (see [FailoverClient])
+    pub fn set_rpc_nodes(mut self, rpc_nodes: &[String]) -> LedgerClientBuilder {
+        self.rpc_nodes = rpc_nodes.to_vec();
+        self
+    }
+
+    /// Set strategy of choosing the node for read requests when several RPC nodes are set
+    pub fn set_node_selection(mut self, node_selection: NodeSelection) -> LedgerClientBuilder {
+        self.node_selection = node_selection;
+        self
+    }
+
+    /// Set custom [Client] implementation to use for interaction with the network instead of RPC node
+    pub fn set_client(mut self, client: Box<dyn Client>) -> LedgerClientBuilder {
+        self.client = Some(client);
+        self
+    }
+
+    /// Set specifications for contracts deployed on the network
+    pub fn set_contract_configs(
+        mut self,
+        contract_configs: &[ContractConfig],
+    ) -> LedgerClientBuilder {
+        self.contract_configs = contract_configs.to_vec();
+        self
+    }
+
+    /// Set name of the network
+    pub fn set_network(mut self, network: &str) -> LedgerClientBuilder {
+        self.network = Some(network.to_string());
+        self
+    }
+
+    /// Set quorum configuration
+    pub fn set_quorum_config(mut self, quorum_config: &QuorumConfig) -> LedgerClientBuilder {
+        self.quorum_config = Some(quorum_config.clone());
+        self
+    }
+
+    /// Set gas settings used for building write transactions (gas-free network settings are used by default)
+    pub fn set_gas_policy(mut self, gas_policy: &GasPolicy) -> LedgerClientBuilder {
+        self.gas_policy = gas_policy.clone();
+        self
+    }
+
+    /// Enable tracking of the write transaction nonces by the client (see [NonceManager]).
+    ///     Required to build transactions for the same account concurrently
+    pub fn set_nonce_management(mut self, enabled: bool) -> LedgerClientBuilder {
+        self.nonce_management = enabled;
+        self
+    }
+
+    /// Enable the offline mode: transactions are built without connection to the ledger.
+    ///     None of `rpc_node`, `rpc_nodes` or `client` must be set. Nonce management is enabled automatically
+    ///     and nonces of the sender accounts must be set explicitly with [LedgerClient::set_next_nonce]
+    pub fn set_offline(mut self, offline: bool) -> LedgerClientBuilder {
+        self.offline = offline;
+        self
+    }
+
+    /// Enable caching of the resolved ledger objects (DID Documents, Schemas, Credential Definitions,
+    ///     Revocation Registry Definitions) with the given settings (see [ResolutionCache])
+    pub fn set_cache_config(mut self, cache_config: &CacheConfig) -> LedgerClientBuilder {
+        self.cache_config = Some(cache_config.clone());
+        self
+    }
+
+    /// Set persistent storage for the cached objects. Enables caching with default settings if no config is set
+    pub fn set_cache_storage(
+        mut self,
+        cache_storage: Box<dyn CacheStorage>,
+    ) -> LedgerClientBuilder {
+        self.cache_storage = Some(cache_storage);
+        self
+    }
+
+    #[logfn(Info)]
+    #[logfn_inputs(Debug)]
+    pub fn build(self) -> VdrResult<LedgerClient> {
+        self.gas_policy.validate()?;
+        if self.offline {
+            self.validate_offline()?;
+        }
+
+        let client: Box<dyn Client> = match (self.client, self.rpc_node, self.rpc_nodes.is_empty())
+        {
+            (None, None, true) if self.offline => Box::new(OfflineClient::new()),
+            (Some(client), None, true) => client,
+            (None, Some(rpc_node), true) => Box::new(Web3Client::new(&rpc_node)?),
+            (None, None, false) => {
+                Box::new(FailoverClient::new(&self.rpc_nodes, self.node_selection)?)
+            }
+            _ => {
+                return Err(VdrError::ClientInvalidState(
+                    "Exactly one of `rpc_node`, `rpc_nodes` or `client` must be provided"
+                        .to_string(),
+                ));
+            }
+        };
+
+        let contracts = LedgerClient::init_contracts(&self.contract_configs)?;
+        let errors = LedgerClient::build_error_map(&contracts)?;
+
+        let quorum_handler = match self.quorum_config {
+            Some(quorum_config) => Some(QuorumHandler::new(quorum_config)?),
+            None => None,
+        };
+
+        Ok(LedgerClient {
+            chain_id: self.chain_id,
+            client,
+            contracts,
+            errors,
+            network: self.network,
+            quorum_handler,
+            gas_policy: self.gas_policy,
+            nonce_manager: (self.nonce_management || self.offline).then(NonceManager::new),
+            offline: self.offline,
+            cache: match (self.cache_config, self.cache_storage) {
+                (None, None) => None,
+                (cache_config, cache_storage) => Some(ResolutionCache::new(
+                    cache_config.unwrap_or_default(),
+                    cache_storage,
+                )),
+            },
+        })
+    }
+
+    fn validate_offline(&self) -> VdrResult<()> {
+        if self.client.is_some() || self.rpc_node.is_some() || !self.rpc_nodes.is_empty() {
+            return Err(VdrError::ClientInvalidState(
+                "Ledger connection cannot be set in the offline mode".to_string(),
+            ));
+        }
+        if self.quorum_config.is_some() {
+            return Err(VdrError::ClientInvalidState(
+                "Quorum check is not available in the offline mode".to_string(),
+            ));
+        }
+        if self.gas_policy.estimate_gas_limit || self.gas_policy.estimate_gas_price {
+            return Err(VdrError::ClientInvalidState(
+                "Gas estimation is not available in the offline mode".to_string(),
+            ));
+        }
+        Ok(())
+    }
+}
+
+impl Debug for LedgerClientBuilder {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            r#"LedgerClientBuilder {{ chain_id: {}, rpc_node: {:?}, rpc_nodes: {:?}, node_selection: {:?}, custom_client: {}, gas_policy: {:?}, nonce_management: {}, offline: {}, cache_config: {:?}, cache_storage: {:?} }}"#,
+            self.chain_id,
+            self.rpc_node,
+            self.rpc_nodes,
+            self.node_selection,
+            self.client.is_some(),
+            self.gas_policy,
+            self.nonce_management,
+            self.offline,
+            self.cache_config,
+            self.cache_storage
+        )
+    }
+}
+
 #[cfg(test)]
 pub mod test {
     use super::*;
     use crate::{
         client::MockClient, types::transaction::test::read_transaction, utils::init_env_logger,
+        TransactionStatus,
     };
     use once_cell::sync::Lazy;
     use serde::{Deserialize, Serialize};
@@ -412,7 +1349,7 @@ pub mod test {
         serde_json::from_reader(file).expect("Unable to parse besu config file")
     }
 
-    fn contracts() -> Vec<ContractConfig> {
+    pub fn contracts() -> Vec<ContractConfig> {
         vec![
             ContractConfig {
                 address: CONFIG.contracts.ethereum_did_registry.address.to_string(),
@@ -503,23 +1440,46 @@ pub mod test {
     }
 
     pub fn mock_custom_client(client: Box<dyn Client>) -> LedgerClient {
-        let mut ledger_client = LedgerClient::new(
-            CONFIG.chain_id,
-            &CONFIG.node_address,
-            &contracts(),
-            Some(TEST_NETWORK),
-            None,
-        )
-        .unwrap();
+        LedgerClientBuilder::new()
+            .set_chain_id(CONFIG.chain_id)
+            .set_client(client)
+            .set_contract_configs(&contracts())
+            .set_network(TEST_NETWORK)
+            .build()
+            .unwrap()
+    }
 
-        ledger_client.client = client;
-        ledger_client
+    pub fn mock_cached_client(client: Box<dyn Client>) -> LedgerClient {
+        LedgerClientBuilder::new()
+            .set_chain_id(CONFIG.chain_id)
+            .set_client(client)
+            .set_contract_configs(&contracts())
+            .set_cache_config(&CacheConfig::default())
+            .build()
+            .unwrap()
+    }
+
+    pub const TX_HASH: [u8; 32] = [1; 32];
+    pub const TX_BLOCK: u64 = 10;
+
+    pub fn mock_receipt(logs: Vec<EventLog>) -> TransactionReceipt {
+        TransactionReceipt {
+            transaction_hash: TX_HASH.to_vec(),
+            status: TransactionStatus::Success,
+            block_number: TX_BLOCK,
+            block_hash: vec![2; 32],
+            gas_used: 21_000,
+            contract_address: None,
+            revert_reason: None,
+            logs,
+            events: Vec::new(),
+        }
     }
 
     mod create {
         use crate::{
             transaction::test::write_transaction, validator_control::test::VALIDATOR_CONTROL_NAME,
-            SignatureData,
+            ContractErrorKind, SignatureData,
         };
         use mockall::predicate::eq;
         use rstest::rstest;
@@ -544,6 +1504,85 @@ pub mod test {
             .err()
             .unwrap();
 
+            assert!(matches!(client_err, VdrError::CommonInvalidData(_)));
+        }
+
+        #[test]
+        fn create_client_with_custom_client_test() {
+            let client = mock_custom_client(Box::new(MockClient::new()));
+
+            client.contract(VALIDATOR_CONTROL_NAME).unwrap();
+        }
+
+        #[test]
+        fn create_client_with_rpc_nodes_test() {
+            let client = LedgerClientBuilder::new()
+                .set_chain_id(CONFIG.chain_id)
+                .set_rpc_nodes(&QuorumConfig::default().nodes)
+                .set_node_selection(NodeSelection::Latency)
+                .set_contract_configs(&contracts())
+                .set_network(TEST_NETWORK)
+                .build()
+                .unwrap();
+
+            client.contract(VALIDATOR_CONTROL_NAME).unwrap();
+        }
+
+        #[rstest]
+        #[case::no_client(None, None, vec![])]
+        #[case::both_client_and_rpc_node(
+            Some(Box::new(MockClient::new()) as Box<dyn Client>),
+            Some(CONFIG.node_address.as_str()),
+            vec![]
+        )]
+        #[case::both_rpc_node_and_rpc_nodes(
+            None,
+            Some(CONFIG.node_address.as_str()),
+            vec![CONFIG.node_address.clone()]
+        )]
+        fn create_client_builder_errors(
+            #[case] client: Option<Box<dyn Client>>,
+            #[case] rpc_node: Option<&str>,
+            #[case] rpc_nodes: Vec<String>,
+        ) {
+            let mut builder = LedgerClientBuilder::new()
+                .set_chain_id(CONFIG.chain_id)
+                .set_contract_configs(&contracts());
+            if let Some(client) = client {
+                builder = builder.set_client(client);
+            }
+            if let Some(rpc_node) = rpc_node {
+                builder = builder.set_rpc_node(rpc_node);
+            }
+            if !rpc_nodes.is_empty() {
+                builder = builder.set_rpc_nodes(&rpc_nodes);
+            }
+
+            let client_err = builder.build().err().unwrap();
+
+            assert_eq!(
+                client_err,
+                VdrError::ClientInvalidState(
+                    "Exactly one of `rpc_node`, `rpc_nodes` or `client` must be provided"
+                        .to_string()
+                )
+            );
+        }
+
+        #[rstest]
+        #[case::websocket("ws://127.0.0.1:1111")]
+        #[case::ipc("ipc:///tmp/non-existent-besu.ipc")]
+        fn create_client_unreachable_persistent_transport(#[case] node_address: &str) {
+            let client_err = LedgerClient::new(
+                CONFIG.chain_id,
+                node_address,
+                &contracts(),
+                Some(TEST_NETWORK),
+                None,
+            )
+            .err()
+            .unwrap();
+
             assert!(matches!(
                 client_err,  | VdrError::ClientNodeUnreachable { .. }
             ));
@@ -624,7 +1663,7 @@ pub mod test {
         )]
         #[case::custom_error(
             "0x863b93fe000000000000000000000000f0e2db6c8dc6c681bb5d6ad121a107f300e9b2b5",
-            VdrError::ClientTransactionReverted("DidNotFound(identity: f0e2db6c8dc6c681bb5d6ad121a107f300e9b2b5)".to_string()),
+            VdrError::ContractError(did_not_found_error())
         )]
         #[case::error_without_required_argument(
             "0x863b93fe",
@@ -681,6 +1720,72 @@ pub mod test {
             assert_eq!(actual_error, expected_error);
         }
 
+        fn did_not_found_error() -> ContractError {
+            ContractError {
+                contract: Some("EthereumExtDidRegistry".to_string()),
+                name: "DidNotFound".to_string(),
+                args: vec![DecodedParam {
+                    name: "identity".to_string(),
+                    type_: "address".to_string(),
+                    value: "0xf0e2db6c8dc6c681bb5d6ad121a107f300e9b2b5".to_string(),
+                }],
+                kind: ContractErrorKind::DidNotFound,
+            }
+        }
+
+        #[async_std::test]
+        async fn simulate_transaction_test() {
+            let transaction = write_transaction();
+            let mut client_mock = MockClient::new();
+            client_mock
+                .expect_simulate_transaction()
+                .with(
+                    eq(TRUSTEE_ACCOUNT.clone()),
+                    eq(transaction.to.to_string()),
+                    eq(transaction.data.clone()),
+                )
+                .returning(|_, _, _| Ok(vec![]));
+            let client = mock_custom_client(Box::new(client_mock));
+
+            let response = client.simulate_transaction(&transaction).await.unwrap();
+
+            assert!(response.is_empty());
+        }
+
+        #[async_std::test]
+        async fn simulate_reverted_transaction_test() {
+            let transaction = Transaction {
+                to: CONFIG.contracts.ethereum_did_registry.address.clone(),
+                ..write_transaction()
+            };
+            let mut client_mock = MockClient::new();
+            client_mock
+                .expect_simulate_transaction()
+                .returning(|_, _, _| {
+                    Err(VdrError::ClientTransactionReverted(
+                        "0x863b93fe000000000000000000000000f0e2db6c8dc6c681bb5d6ad121a107f300e9b2b5"
+                            .to_string(),
+                    ))
+                });
+            let client = mock_custom_client(Box::new(client_mock));
+
+            let error = client.simulate_transaction(&transaction).await.unwrap_err();
+
+            assert_eq!(VdrError::ContractError(did_not_found_error()), error);
+        }
+
+        #[async_std::test]
+        async fn simulate_read_transaction_test() {
+            let client = mock_client();
+
+            let error = client
+                .simulate_transaction(&read_transaction())
+                .await
+                .unwrap_err();
+
+            assert!(matches!(error, VdrError::ClientInvalidTransaction(_)));
+        }
+
         #[async_std::test]
         async fn get_receipt_invalid_transaction_hash() {
             let client = client();
@@ -698,13 +1803,9 @@ pub mod test {
             let mut client_mock = MockClient::new();
             let txn_hash = vec![1; 32];
             client_mock
-                .expect_get_receipt()
+                .expect_get_transaction_receipt()
                 .with(eq(txn_hash.clone()))
-                .returning(|_| {
-                    Err(VdrError::ClientInvalidResponse(
-                        "Missing transaction receipt".to_string(),
-                    ))
-                });
+                .returning(|_| Ok(None));
 
             let client = mock_custom_client(Box::new(client_mock));
 
@@ -720,13 +1821,15 @@ pub mod test {
             let mut client_mock = MockClient::new();
             let txn_hash = vec![1; 32];
             client_mock
-                .expect_get_receipt()
+                .expect_get_transaction_receipt()
                 .with(eq(txn_hash.clone()))
-                .returning(|_| Ok("".to_string()));
+                .returning(|_| Ok(Some(mock_receipt(Vec::new()))));
 
             let client = mock_custom_client(Box::new(client_mock));
 
-            client.get_receipt(&txn_hash).await.unwrap();
+            let receipt = client.get_receipt(&txn_hash).await.unwrap();
+
+            assert_eq!(mock_receipt(Vec::new()), receipt);
         }
 
         #[async_std::test]
@@ -755,6 +1858,481 @@ pub mod test {
         }
     }
 
+    mod receipt {
+        use super::*;
+        use crate::{
+            signer::MockSigner, transaction::test::write_transaction, DidEvents, DidOwnerChanged,
+            SignatureData,
+        };
+        use mockall::predicate::eq;
+        use std::sync::atomic::{AtomicU64, Ordering};
+
+        #[async_std::test]
+        async fn send_transaction_test() {
+            let transaction = write_transaction();
+            let mut client_mock = MockClient::new();
+            client_mock
+                .expect_send_transaction()
+                .with(eq(transaction.encode().unwrap()))
+                .returning(|_| Ok(TX_HASH.to_vec()));
+            let client = mock_custom_client(Box::new(client_mock));
+
+            let hash = client.send_transaction(&transaction).await.unwrap();
+
+            assert_eq!(TX_HASH.to_vec(), hash);
+        }
+
+        #[async_std::test]
+        async fn sign_and_submit_test() {
+            let signature = SignatureData {
+                recovery_id: 1,
+                signature: vec![1; 64],
+            };
+            let transaction = write_transaction();
+            let mut signed_transaction = transaction.clone();
+            signed_transaction.set_signature(signature.clone());
+
+            let mut signer = MockSigner::new();
+            signer
+                .expect_sign()
+                .with(
+                    eq(transaction.get_signing_bytes().unwrap()),
+                    eq(TRUSTEE_ACCOUNT.clone()),
+                )
+                .returning(move |_, _| Ok(signature.clone()));
+            let mut client_mock = MockClient::new();
+            client_mock
+                .expect_submit_transaction()
+                .with(eq(signed_transaction.encode().unwrap()))
+                .returning(|_| Ok(TX_HASH.to_vec()));
+            let client = mock_custom_client(Box::new(client_mock));
+
+            let hash = client.sign_and_submit(&transaction, &signer).await.unwrap();
+
+            assert_eq!(TX_HASH.to_vec(), hash);
+        }
+
+        #[async_std::test]
+        async fn sign_and_submit_missing_key_test() {
+            let mut signer = MockSigner::new();
+            signer
+                .expect_sign()
+                .returning(|_, account| Err(VdrError::SignerMissingKey(account.to_string())));
+            let client = mock_custom_client(Box::new(MockClient::new()));
+
+            let err = client
+                .sign_and_submit(&write_transaction(), &signer)
+                .await
+                .unwrap_err();
+
+            assert!(matches!(err, VdrError::SignerMissingKey(_)));
+        }
+
+        #[async_std::test]
+        async fn send_read_transaction_test() {
+            let client = mock_custom_client(Box::new(MockClient::new()));
+
+            let err = client
+                .send_transaction(&read_transaction())
+                .await
+                .unwrap_err();
+
+            assert!(matches!(err, VdrError::ClientInvalidTransaction(_)));
+        }
+
+        #[async_std::test]
+        async fn wait_for_receipt_confirmations_test() {
+            let mut client_mock = MockClient::new();
+            client_mock
+                .expect_get_transaction_receipt()
+                .with(eq(TX_HASH.to_vec()))
+                .returning(|_| Ok(Some(mock_receipt(Vec::new()))));
+            // the latest block grows by one on each request
+            let latest_block = AtomicU64::new(TX_BLOCK);
+            client_mock.expect_get_block().times(3).returning(move |_| {
+                Ok(BlockDetails {
+                    number: latest_block.fetch_add(1, Ordering::SeqCst),
+                    timestamp: 0,
+                })
+            });
+            let client = mock_custom_client(Box::new(client_mock));
+
+            let receipt = client
+                .wait_for_receipt(&TX_HASH, 3, Duration::from_secs(5))
+                .await
+                .unwrap();
+
+            assert_eq!(TX_BLOCK, receipt.block_number);
+            assert_eq!(TransactionStatus::Success, receipt.status);
+        }
+
+        #[async_std::test]
+        async fn wait_for_receipt_timeout_test() {
+            let mut client_mock = MockClient::new();
+            client_mock
+                .expect_get_transaction_receipt()
+                .returning(|_| Ok(None));
+            let client = mock_custom_client(Box::new(client_mock));
+
+            let err = client
+                .wait_for_receipt(&TX_HASH, 1, Duration::from_millis(500))
+                .await
+                .unwrap_err();
+
+            assert!(matches!(err, VdrError::ClientTransactionTimeout(_)));
+        }
+
+        fn event_signature(client: &LedgerClient, contract: &str, event: &str) -> Vec<u8> {
+            client
+                .contract(contract)
+                .unwrap()
+                .event(event)
+                .unwrap()
+                .signature()
+                .as_bytes()
+                .to_vec()
+        }
+
+        fn address_topic(byte: u8) -> Vec<u8> {
+            let mut topic = vec![0; 12];
+            topic.extend_from_slice(&[byte; 20]);
+            topic
+        }
+
+        #[async_std::test]
+        async fn wait_for_receipt_decodes_events_test() {
+            let abi_client = mock_custom_client(Box::new(MockClient::new()));
+            let did_owner_changed_log = EventLog::new(
+                vec![
+                    event_signature(&abi_client, "EthereumExtDidRegistry", "DIDOwnerChanged"),
+                    address_topic(1),
+                ],
+                [address_topic(2), vec![0; 32]].concat(),
+                TX_BLOCK,
+            );
+            let role_assigned_log = EventLog::new(
+                vec![
+                    event_signature(&abi_client, "RoleControl", "RoleAssigned"),
+                    address_topic(1),
+                    address_topic(3),
+                ],
+                vec![0; 32],
+                TX_BLOCK,
+            );
+            let logs = vec![did_owner_changed_log, role_assigned_log];
+
+            let mut client_mock = MockClient::new();
+            client_mock
+                .expect_get_transaction_receipt()
+                .returning(move |_| Ok(Some(mock_receipt(logs.clone()))));
+            client_mock.expect_get_block().returning(|_| {
+                Ok(BlockDetails {
+                    number: TX_BLOCK,
+                    timestamp: 0,
+                })
+            });
+            let client = mock_custom_client(Box::new(client_mock));
+
+            let receipt = client
+                .wait_for_receipt(&TX_HASH, 1, Duration::from_secs(5))
+                .await
+                .unwrap();
+
+            let expected_did_event = ReceiptEvent::Did(DidEvents::OwnerChanged(DidOwnerChanged {
+                identity: Address::from(hex::encode([1; 20]).as_str()),
+                owner: Address::from(hex::encode([2; 20]).as_str()),
+                previous_change: Block::from(0),
+            }));
+            assert_eq!(2, receipt.events.len());
+            assert_eq!(expected_did_event, receipt.events[0]);
+            match &receipt.events[1] {
+                ReceiptEvent::Other(event) => {
+                    assert_eq!("RoleControl", event.contract);
+                    assert_eq!("RoleAssigned", event.name);
+                    assert_eq!(
+                        vec!["role", "account", "sender"],
+                        event
+                            .params
+                            .iter()
+                            .map(|param| param.name.as_str())
+                            .collect::<Vec<&str>>()
+                    );
+                }
+                event => panic!("Unexpected event: {:?}", event),
+            }
+        }
+
+        #[test]
+        fn decode_event_checks_emitter_address_test() {
+            let client = mock_client();
+            let log = EventLog::new(
+                vec![
+                    event_signature(&client, "RoleControl", "RoleAssigned"),
+                    address_topic(1),
+                    address_topic(3),
+                ],
+                vec![0; 32],
+                TX_BLOCK,
+            );
+            let role_control_log = EventLog {
+                address: Some(client.contract("RoleControl").unwrap().address().clone()),
+                ..log.clone()
+            };
+            let other_contract_log = EventLog {
+                address: Some(Address::from("0x00000000000000000000000000000000000000ff")),
+                ..log
+            };
+
+            let event = client.decode_event(&role_control_log).unwrap();
+            assert_eq!("RoleControl", event.contract);
+            assert_eq!("RoleAssigned", event.name);
+            assert_eq!(None, client.decode_event(&other_contract_log));
+        }
+    }
+
+    mod offline {
+        use super::*;
+        use crate::{
+            contracts::{
+                anoncreds::{schema_registry, types::schema::test::schema},
+                did::types::did_doc::test::TEST_ETHR_DID,
+            },
+            DID,
+        };
+
+        const NONCE: u64 = 7;
+
+        fn offline_client() -> LedgerClient {
+            LedgerClient::offline(CONFIG.chain_id, &contracts(), Some(TEST_NETWORK)).unwrap()
+        }
+
+        #[async_std::test]
+        async fn build_transactions_offline_test() {
+            let client = offline_client();
+            client.set_next_nonce(&TEST_ACCOUNT, NONCE).unwrap();
+            let schema = schema(&DID::from(TEST_ETHR_DID), None);
+
+            let first =
+                schema_registry::build_create_schema_transaction(&client, &TEST_ACCOUNT, &schema)
+                    .await
+                    .unwrap();
+            let second =
+                schema_registry::build_create_schema_transaction(&client, &TEST_ACCOUNT, &schema)
+                    .await
+                    .unwrap();
+
+            assert!(client.is_offline());
+            assert_eq!(CONFIG.chain_id, first.chain_id);
+            assert_eq!(CONFIG.contracts.schema_registry.address, first.to);
+            assert_eq!(Some(NONCE), first.nonce);
+            assert_eq!(Some(NONCE + 1), second.nonce);
+        }
+
+        #[async_std::test]
+        async fn build_transaction_offline_without_nonce_test() {
+            let client = offline_client();
+            let schema = schema(&DID::from(TEST_ETHR_DID), None);
+
+            let err =
+                schema_registry::build_create_schema_transaction(&client, &TEST_ACCOUNT, &schema)
+                    .await
+                    .unwrap_err();
+
+            assert!(matches!(err, VdrError::ClientInvalidState(_)));
+        }
+
+        #[async_std::test]
+        async fn submit_transaction_offline_test() {
+            let client = offline_client();
+
+            let err = client
+                .submit_transaction(&read_transaction())
+                .await
+                .unwrap_err();
+
+            assert!(matches!(err, VdrError::ClientInvalidState(_)));
+        }
+
+        #[rstest::rstest]
+        #[case::rpc_node(LedgerClientBuilder::new().set_rpc_node(&CONFIG.node_address))]
+        #[case::quorum(LedgerClientBuilder::new().set_quorum_config(&QuorumConfig::default()))]
+        #[case::gas_estimation(LedgerClientBuilder::new().set_gas_policy(&GasPolicy {
+            estimate_gas_limit: true,
+            ..GasPolicy::default()
+        }))]
+        fn create_offline_client_errors(#[case] builder: LedgerClientBuilder) {
+            let err = builder
+                .set_chain_id(CONFIG.chain_id)
+                .set_contract_configs(&contracts())
+                .set_offline(true)
+                .build()
+                .unwrap_err();
+
+            assert!(matches!(err, VdrError::ClientInvalidState(_)));
+        }
+    }
+
+    mod decode {
+        use super::*;
+        use crate::{
+            contracts::{
+                anoncreds::{schema_registry, types::schema::test::schema},
+                did::types::did_doc::test::TEST_ETHR_DID,
+            },
+            DecodedPayload, DID,
+        };
+
+        #[async_std::test]
+        async fn decode_create_schema_transaction_test() {
+            let client = mock_client();
+            let schema = schema(&DID::from(TEST_ETHR_DID), None);
+            let transaction =
+                schema_registry::build_create_schema_transaction(&client, &TEST_ACCOUNT, &schema)
+                    .await
+                    .unwrap();
+
+            let decoded = client.decode_transaction(&transaction).unwrap();
+
+            assert_eq!("SchemaRegistry", decoded.contract);
+            assert_eq!("createSchema", decoded.method);
+            assert_eq!(
+                vec!["identity", "id", "issuerId", "schema"],
+                decoded
+                    .params
+                    .iter()
+                    .map(|param| param.name.as_str())
+                    .collect::<Vec<&str>>()
+            );
+            assert_eq!(Some(DecodedPayload::Schema(schema)), decoded.payload);
+        }
+
+        #[async_std::test]
+        async fn decode_create_schema_endorsing_data_test() {
+            let client = mock_client();
+            let schema = schema(&DID::from(TEST_ETHR_DID), None);
+            let data = schema_registry::build_create_schema_endorsing_data(&client, &schema)
+                .await
+                .unwrap();
+
+            let decoded = client.decode_endorsing_data(&data).unwrap();
+
+            assert_eq!("SchemaRegistry", decoded.contract);
+            assert_eq!("createSchema", decoded.method);
+            assert_eq!("identity", decoded.params[0].name);
+            assert_eq!(data.from.as_ref().to_lowercase(), decoded.params[0].value);
+            assert_eq!(Some(DecodedPayload::Schema(schema)), decoded.payload);
+        }
+
+        #[async_std::test]
+        async fn decode_transaction_for_unknown_contract_test() {
+            let client = mock_client();
+            let mut transaction = read_transaction();
+            transaction.to = Address::from(INVALID_ADDRESS);
+
+            let err = client.decode_transaction(&transaction).unwrap_err();
+
+            assert!(matches!(err, VdrError::ClientInvalidTransaction(_)));
+        }
+
+        #[async_std::test]
+        async fn decode_transaction_with_unknown_selector_test() {
+            let client = mock_client();
+            let mut transaction = read_transaction();
+            transaction.data = vec![0; 4];
+
+            let err = client.decode_transaction(&transaction).unwrap_err();
+
+            assert!(matches!(err, VdrError::ClientInvalidTransaction(_)));
+        }
+    }
+
+    mod batch {
+        use super::*;
+        use crate::{transaction::test::write_transaction, ContractErrorKind};
+
+        fn events_query() -> EventQuery {
+            EventQuery {
+                addresses: vec![CONFIG.contracts.ethereum_did_registry.address.clone()],
+                from_block: Some(Block::from(1)),
+                to_block: Some(Block::from(TX_BLOCK)),
+                topics: vec![],
+            }
+        }
+
+        #[async_std::test]
+        async fn submit_batch_test() {
+            let requests = vec![
+                BatchRequest::Call(read_transaction()),
+                BatchRequest::Events(events_query()),
+            ];
+            let mut client_mock = MockClient::new();
+            client_mock
+                .expect_submit_batch()
+                .withf(|requests| requests.len() == 2)
+                .times(1)
+                .returning(|_| {
+                    Ok(vec![
+                        Ok(BatchResponse::Call(vec![1, 2, 3])),
+                        Ok(BatchResponse::Events(vec![])),
+                    ])
+                });
+            let client = mock_custom_client(Box::new(client_mock));
+
+            let responses = client.submit_batch(&requests).await.unwrap();
+
+            assert_eq!(
+                vec![
+                    Ok(BatchResponse::Call(vec![1, 2, 3])),
+                    Ok(BatchResponse::Events(vec![]))
+                ],
+                responses
+            );
+        }
+
+        #[async_std::test]
+        async fn submit_batch_with_reverted_transaction_test() {
+            let transaction = Transaction {
+                to: CONFIG.contracts.ethereum_did_registry.address.clone(),
+                ..read_transaction()
+            };
+            let requests = vec![
+                BatchRequest::Call(transaction),
+                BatchRequest::Call(read_transaction()),
+            ];
+            let mut client_mock = MockClient::new();
+            client_mock.expect_submit_batch().returning(|_| {
+                Ok(vec![
+                    Err(VdrError::ClientTransactionReverted(
+                        "0x863b93fe000000000000000000000000f0e2db6c8dc6c681bb5d6ad121a107f300e9b2b5"
+                            .to_string(),
+                    )),
+                    Ok(BatchResponse::Call(vec![1])),
+                ])
+            });
+            let client = mock_custom_client(Box::new(client_mock));
+
+            let responses = client.submit_batch(&requests).await.unwrap();
+
+            assert!(matches!(
+                &responses[0],
+                Err(VdrError::ContractError(error)) if error.kind == ContractErrorKind::DidNotFound
+            ));
+            assert_eq!(Ok(BatchResponse::Call(vec![1])), responses[1]);
+        }
+
+        #[async_std::test]
+        async fn submit_batch_with_write_transaction_test() {
+            let client = mock_client();
+
+            let error = client
+                .submit_batch(&[BatchRequest::Call(write_transaction())])
+                .await
+                .unwrap_err();
+
+            assert!(matches!(error, VdrError::ClientInvalidTransaction(_)));
+        }
+    }
+
     #[cfg(feature = "ledger_test")]
     mod ping {
         use super::*;
@@ -5,7 +5,8 @@
 use ethereum_types::U256;
 use once_cell::sync::Lazy;
 
-/// As the network is fee and gas free, use the max available gas value for each transaction
+/// As the network is fee and gas free by default, use the max available gas value for each transaction.
+/// Networks charging gas are configured with [crate::GasPolicy]
 pub const GAS: u64 = 9_007_199_254_719_927;
 pub static GAS_PRICE: Lazy<U256> = Lazy::new(|| U256([0, 0, 0, 0]));
 pub static GAS_LIMIT: Lazy<U256> = Lazy::new(|| U256([GAS, 0, 0, 0]));
@@ -0,0 +1,325 @@
+// Copyright (c) 2024 DSR Corporation, Denver, Colorado.
+// https://www.dsr-corporation.com
+// SPDX-License-Identifier: Apache-2.0
+
+use log_derive::{logfn, logfn_inputs};
+use serde_derive::{Deserialize, Serialize};
+
+use crate::{
+    client::GAS,
+    error::{VdrError, VdrResult},
+    types::{Transaction, TransactionEnvelopeType},
+    LedgerClient,
+};
+
+/// Multiplier applied to the estimated gas values if no other is configured
+pub const DEFAULT_GAS_MULTIPLIER: f64 = 1.2;
+
+/// Gas settings used for building write transactions.
+///
+/// Default policy corresponds to a gas-free network: legacy transactions with [GAS] limit and zero gas price.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct GasPolicy {
+    /// Envelope type of built transactions
+    #[serde(default)]
+    pub envelope_type: TransactionEnvelopeType,
+    /// Request gas limit from the network using `eth_estimateGas`
+    #[serde(default)]
+    pub estimate_gas_limit: bool,
+    /// Request gas price from the network using `eth_gasPrice`
+    #[serde(default)]
+    pub estimate_gas_price: bool,
+    /// Safety multiplier (>= 1.0) applied to the estimated gas limit and price. [DEFAULT_GAS_MULTIPLIER] if not set
+    pub multiplier: Option<f64>,
+    /// Fixed gas limit used when the estimation is disabled
+    pub gas_limit: Option<u64>,
+    /// Fixed gas price (max fee per gas for EIP-1559 transactions) used when the estimation is disabled
+    pub gas_price: Option<u64>,
+    /// Max priority fee per gas for EIP-1559 transactions
+    pub max_priority_fee_per_gas: Option<u64>,
+    /// Upper bound for the transaction gas limit (network block gas limit)
+    pub max_gas_limit: Option<u64>,
+}
+
+impl GasPolicy {
+    #[logfn(Trace)]
+    #[logfn_inputs(Trace)]
+    pub(crate) fn validate(&self) -> VdrResult<()> {
+        if let Some(multiplier) = self.multiplier {
+            if !multiplier.is_finite() || multiplier < 1.0 {
+                return Err(VdrError::CommonInvalidData(format!(
+                    "Gas multiplier must be a finite number of at least 1.0, got: {}",
+                    multiplier
+                )));
+            }
+        }
+        if let (TransactionEnvelopeType::Eip1559, false, Some(max_fee), Some(max_priority_fee)) = (
+            &self.envelope_type,
+            self.estimate_gas_price,
+            self.gas_price,
+            self.max_priority_fee_per_gas,
+        ) {
+            if max_priority_fee > max_fee {
+                return Err(VdrError::CommonInvalidData(format!(
+                    "Max priority fee per gas {} exceeds max fee per gas {}",
+                    max_priority_fee, max_fee
+                )));
+            }
+        }
+        Ok(())
+    }
+
+    /// Set gas fields of the transaction according to the policy
+    #[logfn(Trace)]
+    #[logfn_inputs(Trace)]
+    pub(crate) async fn apply(
+        &self,
+        client: &LedgerClient,
+        transaction: &mut Transaction,
+    ) -> VdrResult<()> {
+        transaction.envelope_type = self.envelope_type.clone();
+        transaction.gas_limit = self.gas_limit(client, transaction).await?;
+
+        let gas_price = if self.estimate_gas_price {
+            Some(self.multiply(client.get_gas_price().await?)?)
+        } else {
+            self.gas_price
+        };
+        match self.envelope_type {
+            TransactionEnvelopeType::Eip1559 => {
+                transaction.max_fee_per_gas = gas_price;
+                // priority fee can not exceed the max fee, which may be lower when it is estimated
+                transaction.max_priority_fee_per_gas =
+                    match (self.max_priority_fee_per_gas, gas_price) {
+                        (Some(max_priority_fee), Some(max_fee)) => {
+                            Some(max_priority_fee.min(max_fee))
+                        }
+                        (max_priority_fee, _) => max_priority_fee,
+                    };
+            }
+            TransactionEnvelopeType::Legacy | TransactionEnvelopeType::Eip2930 => {
+                transaction.gas_price = gas_price;
+            }
+        }
+        Ok(())
+    }
+
+    async fn gas_limit(
+        &self,
+        client: &LedgerClient,
+        transaction: &Transaction,
+    ) -> VdrResult<Option<u64>> {
+        if !self.estimate_gas_limit {
+            return Ok(match self.max_gas_limit {
+                Some(max_gas_limit) => Some(self.gas_limit.unwrap_or(GAS).min(max_gas_limit)),
+                None => self.gas_limit,
+            });
+        }
+
+        let estimated_gas = client.estimate_gas(transaction).await?;
+        match self.max_gas_limit {
+            Some(max_gas_limit) if estimated_gas > max_gas_limit => {
+                Err(VdrError::ClientInvalidTransaction(format!(
+                    "Estimated gas {} exceeds max gas limit {}",
+                    estimated_gas, max_gas_limit
+                )))
+            }
+            Some(max_gas_limit) => Ok(Some(self.multiply(estimated_gas)?.min(max_gas_limit))),
+            None => Ok(Some(self.multiply(estimated_gas)?)),
+        }
+    }
+
+    fn multiply(&self, value: u64) -> VdrResult<u64> {
+        let multiplier = self.multiplier.unwrap_or(DEFAULT_GAS_MULTIPLIER);
+        let result = (value as f64 * multiplier).ceil();
+        // `as` conversion silently saturates, so overflow must be checked explicitly
+        if !result.is_finite() || result >= u64::MAX as f64 {
+            return Err(VdrError::ClientInvalidTransaction(format!(
+                "Gas value {} multiplied by {} exceeds the maximal value",
+                value, multiplier
+            )));
+        }
+        Ok(result as u64)
+    }
+}
+
+#[cfg(test)]
+pub mod test {
+    use super::*;
+    use crate::{
+        client::{client::test::mock_custom_client, MockClient},
+        types::transaction::test::write_transaction,
+    };
+    use rstest::rstest;
+
+    const ESTIMATED_GAS: u64 = 100_000;
+    const NETWORK_GAS_PRICE: u64 = 1_000;
+
+    fn client() -> LedgerClient {
+        let mut client = MockClient::new();
+        client
+            .expect_estimate_gas()
+            .returning(|_, _, _| Ok(ESTIMATED_GAS));
+        client
+            .expect_get_gas_price()
+            .returning(|| Ok(NETWORK_GAS_PRICE));
+        mock_custom_client(Box::new(client))
+    }
+
+    #[async_std::test]
+    async fn apply_default_gas_policy_test() {
+        let mut transaction = write_transaction();
+
+        GasPolicy::default()
+            .apply(&client(), &mut transaction)
+            .await
+            .unwrap();
+
+        assert_eq!(write_transaction(), transaction);
+    }
+
+    #[rstest]
+    #[case::estimated(None, None, Some(120_000))]
+    #[case::custom_multiplier(Some(1.5), None, Some(150_000))]
+    #[case::capped_by_max_gas_limit(None, Some(110_000), Some(110_000))]
+    #[async_std::test]
+    async fn apply_estimated_gas_limit_test(
+        #[case] multiplier: Option<f64>,
+        #[case] max_gas_limit: Option<u64>,
+        #[case] expected_gas_limit: Option<u64>,
+    ) {
+        let mut transaction = write_transaction();
+        let policy = GasPolicy {
+            estimate_gas_limit: true,
+            multiplier,
+            max_gas_limit,
+            ..GasPolicy::default()
+        };
+
+        policy.apply(&client(), &mut transaction).await.unwrap();
+
+        assert_eq!(expected_gas_limit, transaction.gas_limit);
+    }
+
+    #[async_std::test]
+    async fn apply_estimated_gas_exceeds_max_gas_limit_test() {
+        let mut transaction = write_transaction();
+        let policy = GasPolicy {
+            estimate_gas_limit: true,
+            max_gas_limit: Some(ESTIMATED_GAS - 1),
+            ..GasPolicy::default()
+        };
+
+        let err = policy.apply(&client(), &mut transaction).await.unwrap_err();
+
+        assert!(matches!(err, VdrError::ClientInvalidTransaction(_)));
+    }
+
+    #[async_std::test]
+    async fn apply_fixed_gas_limit_capped_test() {
+        let mut transaction = write_transaction();
+        let policy = GasPolicy {
+            max_gas_limit: Some(30_000_000),
+            ..GasPolicy::default()
+        };
+
+        policy.apply(&client(), &mut transaction).await.unwrap();
+
+        assert_eq!(Some(30_000_000), transaction.gas_limit);
+    }
+
+    #[rstest]
+    #[case::legacy(TransactionEnvelopeType::Legacy)]
+    #[case::eip2930(TransactionEnvelopeType::Eip2930)]
+    #[case::eip1559(TransactionEnvelopeType::Eip1559)]
+    #[async_std::test]
+    async fn apply_estimated_gas_price_test(#[case] envelope_type: TransactionEnvelopeType) {
+        let mut transaction = write_transaction();
+        let policy = GasPolicy {
+            envelope_type: envelope_type.clone(),
+            estimate_gas_price: true,
+            max_priority_fee_per_gas: Some(10),
+            ..GasPolicy::default()
+        };
+
+        policy.apply(&client(), &mut transaction).await.unwrap();
+
+        let expected_price = Some(1_200);
+        assert_eq!(envelope_type, transaction.envelope_type);
+        match envelope_type {
+            TransactionEnvelopeType::Eip1559 => {
+                assert_eq!(expected_price, transaction.max_fee_per_gas);
+                assert_eq!(Some(10), transaction.max_priority_fee_per_gas);
+                assert_eq!(None, transaction.gas_price);
+            }
+            _ => {
+                assert_eq!(expected_price, transaction.gas_price);
+                assert_eq!(None, transaction.max_fee_per_gas);
+            }
+        }
+    }
+
+    #[rstest]
+    #[case::valid(Some(1.0), true)]
+    #[case::not_set(None, true)]
+    #[case::less_than_one(Some(0.5), false)]
+    #[case::nan(Some(f64::NAN), false)]
+    #[case::infinite(Some(f64::INFINITY), false)]
+    fn validate_gas_policy_test(#[case] multiplier: Option<f64>, #[case] valid: bool) {
+        let policy = GasPolicy {
+            multiplier,
+            ..GasPolicy::default()
+        };
+
+        assert_eq!(valid, policy.validate().is_ok());
+    }
+
+    #[rstest]
+    #[case::priority_fee_within_max_fee(Some(1_000), Some(10), true)]
+    #[case::priority_fee_exceeds_max_fee(Some(1_000), Some(1_001), false)]
+    #[case::max_fee_not_set(None, Some(1_001), true)]
+    fn validate_eip1559_gas_policy_test(
+        #[case] gas_price: Option<u64>,
+        #[case] max_priority_fee_per_gas: Option<u64>,
+        #[case] valid: bool,
+    ) {
+        let policy = GasPolicy {
+            envelope_type: TransactionEnvelopeType::Eip1559,
+            gas_price,
+            max_priority_fee_per_gas,
+            ..GasPolicy::default()
+        };
+
+        assert_eq!(valid, policy.validate().is_ok());
+    }
+
+    #[async_std::test]
+    async fn apply_estimated_gas_price_caps_priority_fee_test() {
+        let mut transaction = write_transaction();
+        let policy = GasPolicy {
+            envelope_type: TransactionEnvelopeType::Eip1559,
+            estimate_gas_price: true,
+            max_priority_fee_per_gas: Some(NETWORK_GAS_PRICE * 2),
+            ..GasPolicy::default()
+        };
+
+        policy.apply(&client(), &mut transaction).await.unwrap();
+
+        assert_eq!(Some(1_200), transaction.max_fee_per_gas);
+        assert_eq!(Some(1_200), transaction.max_priority_fee_per_gas);
+    }
+
+    #[async_std::test]
+    async fn apply_gas_multiplier_overflow_test() {
+        let mut transaction = write_transaction();
+        let policy = GasPolicy {
+            estimate_gas_limit: true,
+            multiplier: Some(f64::MAX),
+            ..GasPolicy::default()
+        };
+
+        let err = policy.apply(&client(), &mut transaction).await.unwrap_err();
+
+        assert!(matches!(err, VdrError::ClientInvalidTransaction(_)));
+    }
+}
@@ -0,0 +1,659 @@
+// Copyright (c) 2024 DSR Corporation, Denver, Colorado.
+// https://www.dsr-corporation.com
+// SPDX-License-Identifier: Apache-2.0
+
+use std::{
+    fmt::{Debug, Formatter},
+    future::Future,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Mutex, MutexGuard,
+    },
+};
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use log::{trace, warn};
+use log_derive::{logfn, logfn_inputs};
+use once_cell::sync::OnceCell;
+use serde_derive::{Deserialize, Serialize};
+
+use crate::{
+    client::{implementation::web3::client::Web3Client, Client, EventStream},
+    error::{VdrError, VdrResult},
+    types::{
+        Address, BatchRequest, BatchResponse, BlockDetails, EventLog, EventQuery, Transaction,
+        TransactionReceipt,
+    },
+};
+
+/// Interval (in milliseconds) during which a failed node is used only if all other nodes failed as well
+pub const NODE_RETRY_INTERVAL: i64 = 10000;
+
+/// Strategy of choosing the node for read requests
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub enum NodeSelection {
+    /// Rotate requests over the healthy nodes
+    #[default]
+    RoundRobin,
+    /// Send requests to the healthy node with the lowest response time
+    Latency,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum RequestKind {
+    Read,
+    Write,
+}
+
+#[derive(Debug, Default)]
+struct NodeHealth {
+    failed_at: Option<DateTime<Utc>>,
+    // smoothed response time in milliseconds
+    latency: Option<u64>,
+}
+
+struct FailoverNode {
+    address: String,
+    // connection is established on the first use if the node was unreachable on the client creation
+    client: OnceCell<Box<dyn Client>>,
+    health: Mutex<NodeHealth>,
+}
+
+impl FailoverNode {
+    fn new(address: String, client: Option<Box<dyn Client>>) -> FailoverNode {
+        FailoverNode {
+            address,
+            client: client.map(OnceCell::with_value).unwrap_or_default(),
+            health: Mutex::new(NodeHealth::default()),
+        }
+    }
+
+    fn client(&self) -> VdrResult<&dyn Client> {
+        self.client
+            .get_or_try_init(|| {
+                let client: Box<dyn Client> = Box::new(Web3Client::new(&self.address)?);
+                Ok(client)
+            })
+            .map(|client| client.as_ref())
+    }
+
+    fn health(&self) -> MutexGuard<'_, NodeHealth> {
+        self.health
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    fn is_healthy(&self, now: DateTime<Utc>) -> bool {
+        match self.health().failed_at {
+            Some(failed_at) => (now - failed_at).num_milliseconds() >= NODE_RETRY_INTERVAL,
+            None => true,
+        }
+    }
+
+    fn failed_at(&self) -> Option<DateTime<Utc>> {
+        self.health().failed_at
+    }
+
+    fn latency(&self) -> Option<u64> {
+        self.health().latency
+    }
+
+    fn mark_succeeded(&self, latency: u64) {
+        let mut health = self.health();
+        health.failed_at = None;
+        health.latency = Some(match health.latency {
+            Some(previous) => (previous * 3 + latency) / 4,
+            None => latency,
+        });
+    }
+
+    fn mark_failed(&self) {
+        self.health().failed_at = Some(Utc::now());
+    }
+}
+
+/// [Client] implementation distributing requests over several RPC nodes.
+///
+/// Nodes failed to respond are excluded from the selection for [NODE_RETRY_INTERVAL] and the
+/// request is repeated on the next node, so the client keeps working while a part of the nodes is down.
+/// Write requests (and nonce requests) are sent to the first healthy node in the configured order,
+/// read requests are distributed according to [NodeSelection].
+pub struct FailoverClient {
+    nodes: Vec<FailoverNode>,
+    selection: NodeSelection,
+    next: AtomicUsize,
+}
+
+impl FailoverClient {
+    /// Create client distributing requests over the RPC nodes
+    ///     Nodes unreachable at the moment are marked as failed and connected on their next use
+    ///
+    /// # Params
+    ///  - `nodes`: [String] - RPC node endpoints in the order of preference
+    ///  - `selection`: [NodeSelection] - strategy of choosing the node for read requests
+    ///
+    /// # Returns
+    ///  client: [FailoverClient] - client to use for [crate::LedgerClient]
+    #[logfn(Info)]
+    #[logfn_inputs(Debug)]
+    pub fn new(nodes: &[String], selection: NodeSelection) -> VdrResult<FailoverClient> {
+        let nodes = nodes
+            .iter()
+            .map(|address| match Web3Client::new(address) {
+                Ok(client) => Ok(FailoverNode::new(
+                    address.to_string(),
+                    Some(Box::new(client)),
+                )),
+                // unreachable node must not prevent using the other ones - connection is retried later
+                Err(VdrError::ClientNodeUnreachable) => {
+                    warn!("Node {} is unreachable on the client creation", address);
+                    let node = FailoverNode::new(address.to_string(), None);
+                    node.mark_failed();
+                    Ok(node)
+                }
+                Err(err) => Err(err),
+            })
+            .collect::<VdrResult<Vec<_>>>()?;
+        FailoverClient::with_nodes(nodes, selection)
+    }
+
+    /// Create client distributing requests over the custom [Client] implementations
+    ///
+    /// # Params
+    ///  - `clients`: list of node names and clients in the order of preference
+    ///  - `selection`: [NodeSelection] - strategy of choosing the node for read requests
+    ///
+    /// # Returns
+    ///  client: [FailoverClient] - client to use for [crate::LedgerClient]
+    pub fn with_clients(
+        clients: Vec<(String, Box<dyn Client>)>,
+        selection: NodeSelection,
+    ) -> VdrResult<FailoverClient> {
+        let nodes = clients
+            .into_iter()
+            .map(|(address, client)| FailoverNode::new(address, Some(client)))
+            .collect();
+        FailoverClient::with_nodes(nodes, selection)
+    }
+
+    fn with_nodes(nodes: Vec<FailoverNode>, selection: NodeSelection) -> VdrResult<FailoverClient> {
+        if nodes.is_empty() {
+            return Err(VdrError::ClientInvalidState(
+                "At least one RPC node must be provided".to_string(),
+            ));
+        }
+
+        Ok(FailoverClient {
+            nodes,
+            selection,
+            next: AtomicUsize::new(0),
+        })
+    }
+
+    /// Request the latest block from all nodes to refresh their health state and response time
+    ///
+    /// # Returns
+    ///  list of node addresses and their health status
+    pub async fn check_health(&self) -> Vec<(String, bool)> {
+        let mut statuses = Vec::with_capacity(self.nodes.len());
+        for node in self.nodes.iter() {
+            let started = Utc::now();
+            let healthy = match Self::request(node, |client| client.get_block(None)).await {
+                Ok(_) => {
+                    node.mark_succeeded(elapsed_millis(started));
+                    true
+                }
+                Err(err) => {
+                    warn!("Node {} health check failed: {}", node.address, err);
+                    node.mark_failed();
+                    false
+                }
+            };
+            statuses.push((node.address.clone(), healthy));
+        }
+        statuses
+    }
+
+    // Nodes in the order they should be tried for the request.
+    //  Healthy nodes go first, failed nodes are kept as a last resort (the oldest failure first)
+    fn candidates(&self, kind: RequestKind) -> Vec<&FailoverNode> {
+        let now = Utc::now();
+        let (mut healthy, mut failed): (Vec<&FailoverNode>, Vec<&FailoverNode>) =
+            self.nodes.iter().partition(|node| node.is_healthy(now));
+
+        if kind == RequestKind::Read && !healthy.is_empty() {
+            match self.selection {
+                NodeSelection::RoundRobin => {
+                    let shift = self.next.fetch_add(1, Ordering::Relaxed) % healthy.len();
+                    healthy.rotate_left(shift);
+                }
+                NodeSelection::Latency => {
+                    healthy.sort_by_key(|node| node.latency().unwrap_or(u64::MAX));
+                }
+            }
+        }
+
+        failed.sort_by_key(|node| node.failed_at());
+        healthy.extend(failed);
+        healthy
+    }
+
+    async fn execute<'a, T, F, Fut>(&'a self, kind: RequestKind, request: F) -> VdrResult<T>
+    where
+        F: Fn(&'a dyn Client) -> Fut,
+        Fut: Future<Output = VdrResult<T>>,
+    {
+        let mut last_error = VdrError::ClientNodeUnreachable;
+        for node in self.candidates(kind) {
+            let started = Utc::now();
+            match Self::request(node, &request).await {
+                Err(err) if is_node_failure(&err) => {
+                    warn!(
+                        "Node {} failed with error: {}. Switching to the next node",
+                        node.address, err
+                    );
+                    node.mark_failed();
+                    last_error = err;
+                }
+                result => {
+                    trace!("Request is handled by node: {}", node.address);
+                    node.mark_succeeded(elapsed_millis(started));
+                    return result;
+                }
+            }
+        }
+        Err(last_error)
+    }
+
+    async fn request<'a, T, F, Fut>(node: &'a FailoverNode, request: F) -> VdrResult<T>
+    where
+        F: Fn(&'a dyn Client) -> Fut,
+        Fut: Future<Output = VdrResult<T>>,
+    {
+        request(node.client()?).await
+    }
+}
+
+// Only connection errors are treated as node failures.
+//  Errors returned by the node (like rejected or already known transaction) are caused by the request itself,
+//  so repeating it on another node would just repeat the error or, even worse, send a write transaction twice
+fn is_node_failure(err: &VdrError) -> bool {
+    matches!(err, VdrError::ClientNodeUnreachable)
+}
+
+fn elapsed_millis(started: DateTime<Utc>) -> u64 {
+    (Utc::now() - started).num_milliseconds().max(0) as u64
+}
+
+#[cfg_attr(not(feature = "wasm"), async_trait)]
+#[cfg_attr(feature = "wasm", async_trait(?Send))]
+impl Client for FailoverClient {
+    async fn get_transaction_count(&self, address: &Address) -> VdrResult<u64> {
+        // nonce is requested from the node receiving transactions to take pending ones into account
+        self.execute(RequestKind::Write, |client| {
+            client.get_transaction_count(address)
+        })
+        .await
+    }
+
+    async fn submit_transaction(&self, transaction: &[u8]) -> VdrResult<Vec<u8>> {
+        self.execute(RequestKind::Write, |client| {
+            client.submit_transaction(transaction)
+        })
+        .await
+    }
+
+    async fn send_transaction(&self, transaction: &[u8]) -> VdrResult<Vec<u8>> {
+        self.execute(RequestKind::Write, |client| {
+            client.send_transaction(transaction)
+        })
+        .await
+    }
+
+    async fn call_transaction(&self, to: &str, transaction: &[u8]) -> VdrResult<Vec<u8>> {
+        self.execute(RequestKind::Read, |client| {
+            client.call_transaction(to, transaction)
+        })
+        .await
+    }
+
+    async fn simulate_transaction(
+        &self,
+        from: &Address,
+        to: &str,
+        transaction: &[u8],
+    ) -> VdrResult<Vec<u8>> {
+        self.execute(RequestKind::Read, |client| {
+            client.simulate_transaction(from, to, transaction)
+        })
+        .await
+    }
+
+    async fn estimate_gas(
+        &self,
+        from: Option<&Address>,
+        to: &str,
+        transaction: &[u8],
+    ) -> VdrResult<u64> {
+        self.execute(RequestKind::Read, |client| {
+            client.estimate_gas(from, to, transaction)
+        })
+        .await
+    }
+
+    async fn get_gas_price(&self) -> VdrResult<u64> {
+        self.execute(RequestKind::Read, |client| client.get_gas_price())
+            .await
+    }
+
+    async fn query_events(&self, query: &EventQuery) -> VdrResult<Vec<EventLog>> {
+        self.execute(RequestKind::Read, |client| client.query_events(query))
+            .await
+    }
+
+    async fn submit_batch(
+        &self,
+        requests: &[BatchRequest],
+    ) -> VdrResult<Vec<VdrResult<BatchResponse>>> {
+        self.execute(RequestKind::Read, |client| client.submit_batch(requests))
+            .await
+    }
+
+    async fn subscribe_events(
+        &self,
+        query: &EventQuery,
+    ) -> VdrResult<EventStream<'static, EventLog>> {
+        self.execute(RequestKind::Write, |client| client.subscribe_events(query))
+            .await
+    }
+
+    async fn get_transaction_receipt(&self, hash: &[u8]) -> VdrResult<Option<TransactionReceipt>> {
+        self.execute(RequestKind::Read, |client| {
+            client.get_transaction_receipt(hash)
+        })
+        .await
+    }
+
+    async fn get_block(&self, block: Option<u64>) -> VdrResult<BlockDetails> {
+        self.execute(RequestKind::Read, |client| client.get_block(block))
+            .await
+    }
+
+    async fn get_transaction(&self, hash: &[u8]) -> VdrResult<Option<Transaction>> {
+        self.execute(RequestKind::Read, |client| client.get_transaction(hash))
+            .await
+    }
+}
+
+impl Debug for FailoverClient {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            r#"FailoverClient {{ nodes: {:?}, selection: {:?} }}"#,
+            self.nodes
+                .iter()
+                .map(|node| node.address.as_str())
+                .collect::<Vec<_>>(),
+            self.selection
+        )
+    }
+}
+
+#[cfg(test)]
+pub mod test {
+    use super::*;
+    use crate::client::MockClient;
+    use jsonrpc_core::types::error::{Error as RpcError, ErrorCode};
+
+    const NODE1: &str = "node1";
+    const NODE2: &str = "node2";
+    const NODE3: &str = "node3";
+
+    fn block(number: u64) -> BlockDetails {
+        BlockDetails {
+            number,
+            timestamp: 0,
+        }
+    }
+
+    fn mock_node(name: &str, result: VdrResult<BlockDetails>) -> (String, Box<dyn Client>) {
+        let mut client = MockClient::new();
+        client.expect_get_block().returning(move |_| result.clone());
+        (name.to_string(), Box::new(client))
+    }
+
+    fn failover_client(
+        nodes: Vec<(String, Box<dyn Client>)>,
+        selection: NodeSelection,
+    ) -> FailoverClient {
+        FailoverClient::with_clients(nodes, selection).unwrap()
+    }
+
+    #[async_std::test]
+    async fn failover_to_next_node_test() {
+        let client = failover_client(
+            vec![
+                mock_node(NODE1, Err(VdrError::ClientNodeUnreachable)),
+                mock_node(NODE2, Ok(block(2))),
+            ],
+            NodeSelection::RoundRobin,
+        );
+
+        for _ in 0..3 {
+            assert_eq!(block(2), client.get_block(None).await.unwrap());
+        }
+        assert!(client.nodes[0].failed_at().is_some());
+        assert!(client.nodes[1].failed_at().is_none());
+    }
+
+    #[async_std::test]
+    async fn failover_all_nodes_unreachable_test() {
+        let client = failover_client(
+            vec![
+                mock_node(NODE1, Err(VdrError::ClientNodeUnreachable)),
+                mock_node(NODE2, Err(VdrError::ClientNodeUnreachable)),
+            ],
+            NodeSelection::RoundRobin,
+        );
+
+        let err = client.get_block(None).await.unwrap_err();
+        assert_eq!(VdrError::ClientNodeUnreachable, err);
+    }
+
+    #[async_std::test]
+    async fn failover_request_error_is_not_retried_test() {
+        let reverted = VdrError::ClientTransactionReverted("0x".to_string());
+        let mut node1 = MockClient::new();
+        node1
+            .expect_call_transaction()
+            .times(1)
+            .returning(move |_, _| Err(reverted.clone()));
+        let mut node2 = MockClient::new();
+        node2.expect_call_transaction().never();
+
+        let client = failover_client(
+            vec![
+                (NODE1.to_string(), Box::new(node1)),
+                (NODE2.to_string(), Box::new(node2)),
+            ],
+            NodeSelection::Latency,
+        );
+
+        let err = client.call_transaction("0x", &[]).await.unwrap_err();
+        assert!(matches!(err, VdrError::ClientTransactionReverted(_)));
+    }
+
+    #[async_std::test]
+    async fn round_robin_selection_test() {
+        let client = failover_client(
+            vec![
+                mock_node(NODE1, Ok(block(1))),
+                mock_node(NODE2, Ok(block(2))),
+                mock_node(NODE3, Ok(block(3))),
+            ],
+            NodeSelection::RoundRobin,
+        );
+
+        let mut blocks = Vec::new();
+        for _ in 0..3 {
+            blocks.push(client.get_block(None).await.unwrap().number);
+        }
+        assert_eq!(vec![1, 2, 3], blocks);
+    }
+
+    #[async_std::test]
+    async fn latency_selection_test() {
+        let client = failover_client(
+            vec![
+                mock_node(NODE1, Ok(block(1))),
+                mock_node(NODE2, Ok(block(2))),
+            ],
+            NodeSelection::Latency,
+        );
+        client.nodes[0].mark_succeeded(100);
+        client.nodes[1].mark_succeeded(10);
+
+        assert_eq!(block(2), client.get_block(None).await.unwrap());
+    }
+
+    #[async_std::test]
+    async fn write_requests_use_preferred_node_test() {
+        let mut node1 = MockClient::new();
+        node1
+            .expect_submit_transaction()
+            .times(2)
+            .returning(|_| Ok(vec![1]));
+        let mut node2 = MockClient::new();
+        node2.expect_submit_transaction().never();
+
+        let client = failover_client(
+            vec![
+                (NODE1.to_string(), Box::new(node1)),
+                (NODE2.to_string(), Box::new(node2)),
+            ],
+            NodeSelection::RoundRobin,
+        );
+
+        for _ in 0..2 {
+            assert_eq!(vec![1], client.submit_transaction(&[]).await.unwrap());
+        }
+    }
+
+    #[async_std::test]
+    async fn check_health_test() {
+        let client = failover_client(
+            vec![
+                mock_node(NODE1, Err(VdrError::ClientNodeUnreachable)),
+                mock_node(NODE2, Ok(block(2))),
+            ],
+            NodeSelection::RoundRobin,
+        );
+
+        let statuses = client.check_health().await;
+        assert_eq!(
+            vec![(NODE1.to_string(), false), (NODE2.to_string(), true)],
+            statuses
+        );
+    }
+
+    #[async_std::test]
+    async fn failover_rejected_write_is_not_resent_test() {
+        // node rejection of the transaction is converted from the RPC error without revert data
+        let rejected: VdrError = RpcError {
+            code: ErrorCode::ServerError(-32000),
+            message: "nonce too low".to_string(),
+            data: None,
+        }
+        .into();
+        let mut node1 = MockClient::new();
+        node1
+            .expect_submit_transaction()
+            .times(1)
+            .returning(move |_| Err(rejected.clone()));
+        let mut node2 = MockClient::new();
+        node2.expect_submit_transaction().never();
+
+        let client = failover_client(
+            vec![
+                (NODE1.to_string(), Box::new(node1)),
+                (NODE2.to_string(), Box::new(node2)),
+            ],
+            NodeSelection::RoundRobin,
+        );
+
+        let err = client.submit_transaction(&[]).await.unwrap_err();
+        assert!(matches!(err, VdrError::ClientUnexpectedError(_)));
+        assert!(client.nodes[0].failed_at().is_none());
+    }
+
+    #[async_std::test]
+    async fn failover_unconfirmed_write_is_not_resent_test() {
+        let mut node1 = MockClient::new();
+        node1.expect_submit_transaction().times(1).returning(|_| {
+            Err(VdrError::ClientTransactionTimeout(
+                "Transaction is sent, but its confirmation failed".to_string(),
+            ))
+        });
+        let mut node2 = MockClient::new();
+        node2.expect_submit_transaction().never();
+
+        let client = failover_client(
+            vec![
+                (NODE1.to_string(), Box::new(node1)),
+                (NODE2.to_string(), Box::new(node2)),
+            ],
+            NodeSelection::RoundRobin,
+        );
+
+        let err = client.submit_transaction(&[]).await.unwrap_err();
+        assert!(matches!(err, VdrError::ClientTransactionTimeout(_)));
+    }
+
+    #[async_std::test]
+    async fn failover_query_error_is_not_retried_test() {
+        let mut node1 = MockClient::new();
+        node1.expect_query_events().times(1).returning(|_| {
+            Err(VdrError::GetTransactionError(
+                "query returned more than 10000 results".to_string(),
+            ))
+        });
+        let mut node2 = MockClient::new();
+        node2.expect_query_events().never();
+
+        let client = failover_client(
+            vec![
+                (NODE1.to_string(), Box::new(node1)),
+                (NODE2.to_string(), Box::new(node2)),
+            ],
+            NodeSelection::RoundRobin,
+        );
+
+        let query = EventQuery {
+            addresses: vec![Address::from("0x0000000000000000000000000000000000000001")],
+            from_block: None,
+            to_block: None,
+            topics: Vec::new(),
+        };
+        let err = client.query_events(&query).await.unwrap_err();
+        assert!(matches!(err, VdrError::GetTransactionError(_)));
+        assert!(client.nodes[0].failed_at().is_none());
+    }
+
+    #[test]
+    fn create_client_with_unreachable_node_test() {
+        let nodes = vec![
+            "/tmp/indy-besu-vdr-missing-node.ipc".to_string(),
+            "http://127.0.0.1:8545".to_string(),
+        ];
+        let client = FailoverClient::new(&nodes, NodeSelection::RoundRobin).unwrap();
+        assert!(client.nodes[0].failed_at().is_some());
+        assert!(client.nodes[1].failed_at().is_none());
+    }
+
+    #[test]
+    fn create_client_without_nodes_test() {
+        let err = FailoverClient::new(&[], NodeSelection::RoundRobin).unwrap_err();
+        assert!(matches!(err, VdrError::ClientInvalidState(_)));
+    }
+}
@@ -2,4 +2,6 @@
 // https://www.dsr-corporation.com
 // SPDX-License-Identifier: Apache-2.0
 
+pub mod failover;
+pub mod offline;
 pub mod web3;
@@ -0,0 +1,65 @@
+// Copyright (c) 2024 DSR Corporation, Denver, Colorado.
+// https://www.dsr-corporation.com
+// SPDX-License-Identifier: Apache-2.0
+
+use async_trait::async_trait;
+
+use crate::{
+    client::Client,
+    error::{VdrError, VdrResult},
+    types::{
+        Address, BatchRequest, BatchResponse, BlockDetails, EventLog, EventQuery, Transaction,
+    },
+};
+
+/// Client used in the offline mode: transactions can be built and signed, but any request to the ledger fails
+#[derive(Debug, Default)]
+pub struct OfflineClient;
+
+impl OfflineClient {
+    pub fn new() -> OfflineClient {
+        OfflineClient
+    }
+
+    fn unavailable<T>(request: &str) -> VdrResult<T> {
+        Err(VdrError::ClientInvalidState(format!(
+            "{} is not available in the offline mode",
+            request
+        )))
+    }
+}
+
+#[cfg_attr(not(feature = "wasm"), async_trait)]
+#[cfg_attr(feature = "wasm", async_trait(?Send))]
+impl Client for OfflineClient {
+    async fn get_transaction_count(&self, _address: &Address) -> VdrResult<u64> {
+        Self::unavailable("Transaction count request")
+    }
+
+    async fn submit_transaction(&self, _transaction: &[u8]) -> VdrResult<Vec<u8>> {
+        Self::unavailable("Transaction submission")
+    }
+
+    async fn call_transaction(&self, _to: &str, _transaction: &[u8]) -> VdrResult<Vec<u8>> {
+        Self::unavailable("Contract call")
+    }
+
+    async fn query_events(&self, _query: &EventQuery) -> VdrResult<Vec<EventLog>> {
+        Self::unavailable("Events query")
+    }
+
+    async fn submit_batch(
+        &self,
+        _requests: &[BatchRequest],
+    ) -> VdrResult<Vec<VdrResult<BatchResponse>>> {
+        Self::unavailable("Batch request")
+    }
+
+    async fn get_block(&self, _block: Option<u64>) -> VdrResult<BlockDetails> {
+        Self::unavailable("Block request")
+    }
+
+    async fn get_transaction(&self, _hash: &[u8]) -> VdrResult<Option<Transaction>> {
+        Self::unavailable("Transaction request")
+    }
+}
@@ -3,18 +3,24 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::{
-    client::Client,
+    client::{Client, EventStream},
     error::{VdrError, VdrResult},
-    types::EventQuery,
+    types::{
+        AccessListItem, BatchRequest, BatchResponse, EventQuery, TransactionEnvelopeType,
+        TransactionReceipt, TransactionStatus, MAX_EVENT_TOPICS,
+    },
     Address, Block, BlockDetails, Transaction,
 };
 
 use async_std::task;
 use async_trait::async_trait;
 use ethereum_types::{H160, U64};
+#[cfg(not(feature = "wasm"))]
+use futures::StreamExt;
 use log::{trace, warn};
 use log_derive::{logfn, logfn_inputs};
-use serde_json::json;
+#[cfg(not(feature = "wasm"))]
+use std::path::Path;
 use std::{
     fmt::{Debug, Formatter},
     str::FromStr,
@@ -23,31 +29,48 @@ use std::{
 
 #[cfg(not(feature = "wasm"))]
 use web3::{
-    api::Eth,
-    transports::Http,
+    confirm::wait_for_transaction_confirmation,
+    transports::{Batch, Either, Http, Ipc, WebSocket},
     types::{
-        Address as EthAddress, BlockId, BlockNumber, Bytes, CallRequest, FilterBuilder,
-        TransactionId, H256,
+        Address as EthAddress, BlockId, BlockNumber, Bytes, CallRequest, Filter, FilterBuilder,
+        Log, TransactionId, TransactionReceipt as Web3TransactionReceipt, H256,
     },
-    Web3,
+    Error as Web3Error, Web3,
 };
 
 use crate::types::EventLog;
 #[cfg(feature = "wasm")]
 use web3_wasm::{
-    api::Eth,
-    transports::Http,
+    confirm::wait_for_transaction_confirmation,
+    transports::{Batch, Http},
     types::{
-        Address as EthAddress, BlockId, BlockNumber, Bytes, CallRequest, FilterBuilder,
-        TransactionId, H256,
+        Address as EthAddress, BlockId, BlockNumber, Bytes, CallRequest, Filter, FilterBuilder,
+        Log, TransactionId, TransactionReceipt as Web3TransactionReceipt, H256,
     },
-    Web3,
+    Error as Web3Error, Web3,
 };
 
+/// Transport used to communicate with the node.
+///   HTTP is always supported, WebSocket and IPC are available for non-wasm targets only
+#[cfg(not(feature = "wasm"))]
+pub(crate) type Web3Transport = Either<Http, Either<WebSocket, Ipc>>;
+#[cfg(feature = "wasm")]
+pub(crate) type Web3Transport = Http;
+
+#[cfg(not(feature = "wasm"))]
+type Web3DuplexTransport = Either<WebSocket, Ipc>;
+
 pub struct Web3Client {
-    client: Web3<Http>,
+    client: Web3<Web3Transport>,
+    node_address: String,
 }
 
+const HTTP_SCHEMES: [&str; 2] = ["http://", "https://"];
+#[cfg(not(feature = "wasm"))]
+const WS_SCHEMES: [&str; 2] = ["ws://", "wss://"];
+#[cfg(not(feature = "wasm"))]
+const IPC_SCHEME: &str = "ipc://";
+
 const POLL_INTERVAL: u64 = 200;
 const NUMBER_TX_CONFIRMATIONS: usize = 1; // FIXME: what number of confirmation events should we wait? 2n+1?
 
@@ -55,14 +78,231 @@ impl Web3Client {
     #[logfn(Info)]
     #[logfn_inputs(Debug)]
     pub fn new(node_address: &str) -> VdrResult<Web3Client> {
-        let transport = Http::new(node_address).map_err(|_| VdrError::ClientNodeUnreachable)?;
+        let transport = Self::build_transport(node_address)?;
         let web3 = Web3::new(transport);
-        let web3_client = Web3Client { client: web3 };
+        let web3_client = Web3Client {
+            client: web3,
+            node_address: node_address.to_string(),
+        };
         Ok(web3_client)
     }
 
-    pub(crate) fn eth(&self) -> Eth<Http> {
-        self.client.eth()
+    // Connection errors are always reported as `ClientNodeUnreachable`,
+    //  so they can be distinguished from the errors caused by the request itself
+    fn map_web3_error(err: Web3Error, map: impl FnOnce(Web3Error) -> VdrError) -> VdrError {
+        match err {
+            Web3Error::Unreachable | Web3Error::Transport(_) | Web3Error::Io(_) => {
+                VdrError::ClientNodeUnreachable
+            }
+            err => map(err),
+        }
+    }
+
+    fn is_http(node_address: &str) -> bool {
+        HTTP_SCHEMES
+            .iter()
+            .any(|scheme| node_address.starts_with(scheme))
+    }
+
+    /// Pick the transport matching to the node address:
+    ///  - `http://`, `https://` - HTTP transport
+    ///  - `ws://`, `wss://` - WebSocket transport keeping a persistent connection
+    ///  - `ipc://<path>` or an absolute file system path - IPC socket transport
+    #[cfg(not(feature = "wasm"))]
+    fn build_transport(node_address: &str) -> VdrResult<Web3Transport> {
+        if Self::is_http(node_address) {
+            let transport = Http::new(node_address).map_err(|_| VdrError::ClientNodeUnreachable)?;
+            return Ok(Either::Left(transport));
+        }
+
+        let transport = task::block_on(Self::connect_duplex_transport(node_address))?;
+        Ok(Either::Right(transport))
+    }
+
+    #[cfg(feature = "wasm")]
+    fn build_transport(node_address: &str) -> VdrResult<Web3Transport> {
+        if !Self::is_http(node_address) {
+            warn!(
+                "Only HTTP transport is supported for wasm, node address: {}",
+                node_address
+            );
+            return Err(VdrError::CommonInvalidData(format!(
+                "Unsupported node address: {}. Only http:// and https:// addresses are supported for wasm",
+                node_address
+            )));
+        }
+        Http::new(node_address).map_err(|_| VdrError::ClientNodeUnreachable)
+    }
+
+    /// Open a new persistent (WebSocket or IPC) connection to the node
+    #[cfg(not(feature = "wasm"))]
+    async fn connect_duplex_transport(node_address: &str) -> VdrResult<Web3DuplexTransport> {
+        if WS_SCHEMES
+            .iter()
+            .any(|scheme| node_address.starts_with(scheme))
+        {
+            let transport = WebSocket::new(node_address).await.map_err(|err| {
+                warn!(
+                    "Error: {} during connecting to WebSocket node: {}",
+                    err, node_address
+                );
+                VdrError::ClientNodeUnreachable
+            })?;
+            return Ok(Either::Left(transport));
+        }
+
+        let path = match node_address.strip_prefix(IPC_SCHEME) {
+            Some(path) => path,
+            None if Path::new(node_address).is_absolute() => node_address,
+            None => {
+                return Err(VdrError::CommonInvalidData(format!(
+                    "Unsupported node address: {}. Expected http(s)://, ws(s)://, ipc:// address or an absolute IPC socket path",
+                    node_address
+                )))
+            }
+        };
+        let transport = Ipc::new(path).await.map_err(|err| {
+            warn!(
+                "Error: {} during connecting to IPC socket: {}",
+                err, node_address
+            );
+            VdrError::ClientNodeUnreachable
+        })?;
+        Ok(Either::Right(transport))
+    }
+
+    fn build_filter(query: &EventQuery) -> VdrResult<FilterBuilder> {
+        // filter without addresses matches events of all contracts on the ledger
+        if query.addresses.is_empty() {
+            return Err(VdrError::ClientInvalidTransaction(
+                "Event query must contain at least one contract address".to_string(),
+            ));
+        }
+
+        let addresses = query
+            .addresses
+            .iter()
+            .map(|address| {
+                H160::from_str(address.as_ref()).map_err(|_| {
+                    VdrError::ClientInvalidTransaction(format!(
+                        "Invalid transaction target address {:?}",
+                        address
+                    ))
+                })
+            })
+            .collect::<VdrResult<Vec<H160>>>()?;
+
+        if query.topics.len() > MAX_EVENT_TOPICS {
+            return Err(VdrError::ClientInvalidTransaction(format!(
+                "Event query contains {} topics, but at most {} are supported",
+                query.topics.len(),
+                MAX_EVENT_TOPICS
+            )));
+        }
+
+        let mut topics: Vec<Option<Vec<H256>>> = Vec::with_capacity(MAX_EVENT_TOPICS);
+        for values in query.topics.iter() {
+            let values = match values {
+                Some(values) => Some(
+                    values
+                        .iter()
+                        .map(|value| {
+                            H256::from_str(value).map_err(|_| {
+                                VdrError::ClientInvalidTransaction(format!(
+                                    "Unable to convert event topic into H256 {:?}",
+                                    value
+                                ))
+                            })
+                        })
+                        .collect::<VdrResult<Vec<H256>>>()?,
+                ),
+                None => None,
+            };
+            topics.push(values);
+        }
+        topics.resize(MAX_EVENT_TOPICS, None);
+
+        let filter = FilterBuilder::default().address(addresses).topics(
+            topics[0].take(),
+            topics[1].take(),
+            topics[2].take(),
+            topics[3].take(),
+        );
+        Ok(filter)
+    }
+
+    fn build_query_filter(query: &EventQuery) -> VdrResult<Filter> {
+        let from_block = match query.from_block {
+            Some(ref block) => BlockNumber::Number(U64::from(block.value())),
+            None => BlockNumber::Earliest,
+        };
+
+        let to_block = match query.to_block {
+            Some(ref block) => BlockNumber::Number(U64::from(block.value())),
+            None => BlockNumber::Latest,
+        };
+
+        Ok(Self::build_filter(query)?
+            .from_block(from_block)
+            .to_block(to_block)
+            .build())
+    }
+
+    fn convert_batch_response(
+        request: &BatchRequest,
+        value: serde_json::Value,
+    ) -> VdrResult<BatchResponse> {
+        let response = match request {
+            BatchRequest::Call(_) => {
+                serde_json::from_value::<Bytes>(value).map(|bytes| BatchResponse::Call(bytes.0))
+            }
+            BatchRequest::Events(_) => serde_json::from_value::<Vec<Log>>(value).map(|logs| {
+                BatchResponse::Events(logs.into_iter().map(Self::convert_log).collect())
+            }),
+        };
+        response.map_err(|err| {
+            VdrError::ClientInvalidResponse(format!(
+                "Unable to parse batch response for request {:?}. Err: {:?}",
+                request, err
+            ))
+        })
+    }
+
+    /// Convert receipt of a mined transaction. Returns `None` if the transaction is still pending
+    fn convert_receipt(receipt: Web3TransactionReceipt) -> Option<TransactionReceipt> {
+        let block_number = receipt.block_number?.as_u64();
+        let block_hash = receipt.block_hash?.as_bytes().to_vec();
+        let status = match receipt.status.map(|status| status.as_u64()) {
+            Some(0) => TransactionStatus::Failed,
+            _ => TransactionStatus::Success,
+        };
+
+        Some(TransactionReceipt {
+            transaction_hash: receipt.transaction_hash.as_bytes().to_vec(),
+            status,
+            block_number,
+            block_hash,
+            gas_used: receipt.gas_used.unwrap_or_default().low_u64(),
+            contract_address: receipt
+                .contract_address
+                .map(|address| Address::from(format!("{:?}", address).as_str())),
+            revert_reason: receipt.revert_reason,
+            logs: receipt.logs.into_iter().map(Self::convert_log).collect(),
+            events: Vec::new(),
+        })
+    }
+
+    fn convert_log(log: Log) -> EventLog {
+        EventLog {
+            address: Some(Address::from(format!("{:?}", log.address).as_str())),
+            topics: log.topics,
+            data: log.data.0,
+            block: Block::from(log.block_number.unwrap_or_default().as_u64()),
+            transaction_hash: log.transaction_hash.map(|hash| hash.as_bytes().to_vec()),
+            log_index: log.log_index.map(|index| index.as_u64()),
+            block_hash: log.block_hash.map(|hash| hash.as_bytes().to_vec()),
+            removed: log.removed.unwrap_or_default(),
+        }
     }
 }
 
@@ -82,7 +322,7 @@ impl Client for Web3Client {
         let count = self
             .client
             .eth()
-            .transaction_count(account_address, None)
+            .transaction_count(account_address, Some(BlockNumber::Pending))
             .await?
             .as_u64();
 
@@ -96,22 +336,27 @@ impl Client for Web3Client {
             transaction
         );
 
-        let receipt = self
+        let transaction_hash = self
             .client
-            .send_raw_transaction_with_confirmation(
-                Bytes::from(transaction),
-                Duration::from_millis(POLL_INTERVAL),
-                NUMBER_TX_CONFIRMATIONS,
-            )
+            .eth()
+            .send_raw_transaction(Bytes::from(transaction))
             .await?;
 
-        // let tx_hash = self.client.eth().send_raw_transaction(Bytes::from(transaction)).await?;
-        // let receipt = loop {
-        //     if let Ok(Some(receipt)) = self.client.eth().transaction_receipt(tx_hash).await {
-        //         break receipt;
-        //     }
-        //     task::sleep(Duration::from_secs(1)).await;
-        // };
+        // The node accepted the transaction, so a failure while waiting for its confirmation
+        //  must not be reported as a node failure causing the transaction to be sent again
+        let receipt = wait_for_transaction_confirmation(
+            self.client.transport().clone(),
+            transaction_hash,
+            Duration::from_millis(POLL_INTERVAL),
+            NUMBER_TX_CONFIRMATIONS,
+        )
+        .await
+        .map_err(|err| {
+            VdrError::ClientTransactionTimeout(format!(
+                "Transaction {:?} is sent, but its confirmation failed: {}",
+                transaction_hash, err
+            ))
+        })?;
 
         if receipt.is_txn_reverted() {
             if let Some(revert_reason) = receipt.revert_reason {
@@ -128,6 +373,24 @@ impl Client for Web3Client {
         Ok(transaction_hash)
     }
 
+    async fn send_transaction(&self, transaction: &[u8]) -> VdrResult<Vec<u8>> {
+        trace!(
+            "Web3Client::send_transaction(transaction: {:?})",
+            transaction
+        );
+
+        let transaction_hash = self
+            .client
+            .eth()
+            .send_raw_transaction(Bytes::from(transaction))
+            .await?
+            .as_bytes()
+            .to_vec();
+
+        trace!("Web3Client::send_transaction() -> {:?}", transaction_hash);
+        Ok(transaction_hash)
+    }
+
     async fn call_transaction(&self, to: &str, transaction: &[u8]) -> VdrResult<Vec<u8>> {
         trace!(
             "Web3Client::call_transaction(to: {:?}, transaction: {:?})",
@@ -158,86 +421,198 @@ impl Client for Web3Client {
         Ok(response)
     }
 
-    async fn query_events(&self, query: &EventQuery) -> VdrResult<Vec<EventLog>> {
-        trace!("Web3Client::query_events(query: {:?})", query);
-
-        let address = H160::from_str(query.address.as_ref()).map_err(|_| {
-            VdrError::ClientInvalidTransaction(format!(
-                "Invalid transaction target address {:?}",
-                query.address
-            ))
-        })?;
-
-        let from_block = match query.from_block {
-            Some(ref block) => BlockNumber::Number(U64::from(block.value())),
-            None => BlockNumber::Earliest,
-        };
-
-        let to_block = match query.to_block {
-            Some(ref block) => BlockNumber::Number(U64::from(block.value())),
-            None => BlockNumber::Latest,
-        };
+    async fn simulate_transaction(
+        &self,
+        from: &Address,
+        to: &str,
+        transaction: &[u8],
+    ) -> VdrResult<Vec<u8>> {
+        trace!(
+            "Web3Client::simulate_transaction(from: {:?}, to: {:?}, transaction: {:?})",
+            from,
+            to,
+            transaction
+        );
 
-        let event_signature = match query.event_signature {
-            Some(ref event_signature) => Some(H256::from_str(event_signature).map_err(|_| {
+        let parse_address = |address: &str| {
+            EthAddress::from_str(address).map_err(|_| {
                 VdrError::ClientInvalidTransaction(format!(
-                    "Unable to convert event signature into H256 {:?}",
-                    event_signature
+                    "Invalid transaction address {:?}",
+                    address
                 ))
-            })?),
-            None => None,
+            })
         };
+        let request = CallRequest::builder()
+            .from(parse_address(from.as_ref())?)
+            .to(parse_address(to)?)
+            .data(Bytes(transaction.to_vec()))
+            .build();
+        let response = self
+            .client
+            .eth()
+            .call(request, Some(BlockId::Number(BlockNumber::Latest)))
+            .await?
+            .0
+            .to_vec();
+
+        trace!("Web3Client::simulate_transaction() -> {:?}", response);
+        Ok(response)
+    }
+
+    async fn estimate_gas(
+        &self,
+        from: Option<&Address>,
+        to: &str,
+        transaction: &[u8],
+    ) -> VdrResult<u64> {
+        trace!(
+            "Web3Client::estimate_gas(from: {:?}, to: {:?}, transaction: {:?})",
+            from,
+            to,
+            transaction
+        );
 
-        let event_filter = match query.event_filter {
-            Some(ref event_filter) => Some(H256::from_str(event_filter).map_err(|_| {
+        let parse_address = |address: &str| {
+            EthAddress::from_str(address).map_err(|_| {
                 VdrError::ClientInvalidTransaction(format!(
-                    "Unable to convert event filter into H256 {:?}",
-                    event_filter
+                    "Invalid transaction address {:?}",
+                    address
                 ))
-            })?),
-            None => None,
+            })
         };
-
-        let filter = FilterBuilder::default()
-            .address(vec![address])
-            .topics(
-                event_signature.map(|event_signature| vec![event_signature]),
-                event_filter.map(|event_filter| vec![event_filter]),
-                None,
-                None,
-            )
-            .from_block(from_block)
-            .to_block(to_block)
-            .build();
-
-        let logs = self
+        let mut request = CallRequest::builder()
+            .to(parse_address(to)?)
+            .data(Bytes(transaction.to_vec()));
+        if let Some(from) = from {
+            request = request.from(parse_address(from.as_ref())?);
+        }
+        let gas = self
             .client
             .eth()
-            .logs(filter)
-            .await
-            .map_err(|e| VdrError::GetTransactionError(e.to_string()))?;
-
-        let events: Vec<EventLog> = logs
-            .into_iter()
-            .map(|log| EventLog {
-                topics: log.topics,
-                data: log.data.0,
-                block: Block::from(log.block_number.unwrap_or_default().as_u64()),
-            })
-            .collect();
+            .estimate_gas(request.build(), None)
+            .await?
+            .low_u64();
+
+        trace!("Web3Client::estimate_gas() -> {:?}", gas);
+        Ok(gas)
+    }
+
+    async fn get_gas_price(&self) -> VdrResult<u64> {
+        trace!("Web3Client::get_gas_price()");
+
+        let gas_price = self.client.eth().gas_price().await?.low_u64();
+
+        trace!("Web3Client::get_gas_price() -> {:?}", gas_price);
+        Ok(gas_price)
+    }
+
+    async fn query_events(&self, query: &EventQuery) -> VdrResult<Vec<EventLog>> {
+        trace!("Web3Client::query_events(query: {:?})", query);
+
+        let filter = Self::build_query_filter(query)?;
+
+        let logs = self.client.eth().logs(filter).await.map_err(|err| {
+            Self::map_web3_error(err, |err| VdrError::GetTransactionError(err.to_string()))
+        })?;
+
+        let events: Vec<EventLog> = logs.into_iter().map(Self::convert_log).collect();
 
         trace!("Web3Client::query_events() -> {:?}", events);
         Ok(events)
     }
 
-    async fn get_receipt(&self, hash: &[u8]) -> VdrResult<String> {
-        trace!("Web3Client::get_receipt(hash: {:?})", hash);
+    async fn submit_batch(
+        &self,
+        requests: &[BatchRequest],
+    ) -> VdrResult<Vec<VdrResult<BatchResponse>>> {
+        trace!("Web3Client::submit_batch(requests: {:?})", requests);
+
+        if requests.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        // requests are queued into the batch on creation of the call futures.
+        // The futures are not awaited as all results are returned by `submit_batch` in the order of requests
+        let batch = Web3::new(Batch::new(self.client.transport().clone()));
+        for request in requests {
+            match request {
+                BatchRequest::Call(transaction) => {
+                    let address = EthAddress::from_str(transaction.to.as_ref()).map_err(|_| {
+                        VdrError::ClientInvalidTransaction(format!(
+                            "Invalid transaction target address {:?}",
+                            transaction.to
+                        ))
+                    })?;
+                    let request = CallRequest::builder()
+                        .to(address)
+                        .data(Bytes(transaction.data.to_vec()))
+                        .build();
+                    let _ = batch.eth().call(request, None);
+                }
+                BatchRequest::Events(query) => {
+                    let _ = batch.eth().logs(Self::build_query_filter(query)?);
+                }
+            }
+        }
+
+        let results = batch.transport().submit_batch().await?;
+        if results.len() != requests.len() {
+            return Err(VdrError::ClientInvalidResponse(format!(
+                "Batch response contains {} results for {} requests",
+                results.len(),
+                requests.len()
+            )));
+        }
+
+        let responses: Vec<VdrResult<BatchResponse>> = requests
+            .iter()
+            .zip(results)
+            .map(|(request, result)| match result {
+                Ok(value) => Self::convert_batch_response(request, value),
+                Err(err) => Err(err.into()),
+            })
+            .collect();
+
+        trace!("Web3Client::submit_batch() -> {:?}", responses);
+        Ok(responses)
+    }
+
+    #[cfg(not(feature = "wasm"))]
+    async fn subscribe_events(
+        &self,
+        query: &EventQuery,
+    ) -> VdrResult<EventStream<'static, EventLog>> {
+        trace!("Web3Client::subscribe_events(query: {:?})", query);
+
+        if Self::is_http(&self.node_address) {
+            return Err(VdrError::ClientInvalidState(
+                "Event subscriptions require WebSocket or IPC node connection".to_string(),
+            ));
+        }
+
+        let filter = Self::build_filter(query)?.build();
+
+        // every subscription uses its own connection, so it can be re-established independently
+        let transport = Self::connect_duplex_transport(&self.node_address).await?;
+        let subscription = Web3::new(transport)
+            .eth_subscribe()
+            .subscribe_logs(filter)
+            .await?;
+
+        let stream = subscription.map(|log| log.map(Self::convert_log).map_err(VdrError::from));
+
+        trace!("Web3Client::subscribe_events() -> subscribed");
+        Ok(Box::pin(stream))
+    }
+
+    async fn get_transaction_receipt(&self, hash: &[u8]) -> VdrResult<Option<TransactionReceipt>> {
+        trace!("Web3Client::get_transaction_receipt(hash: {:?})", hash);
 
         if hash.len() != 32 {
             let vdr_error =
                 VdrError::CommonInvalidData("Transaction hash length != 32 bytes".to_string());
 
-            warn!("Error: {} getting receipt", vdr_error,);
+            warn!("Error: {} getting transaction receipt", vdr_error);
 
             return Err(vdr_error);
         }
@@ -247,17 +622,9 @@ impl Client for Web3Client {
             .eth()
             .transaction_receipt(H256::from_slice(hash))
             .await?
-            .ok_or_else(|| {
-                let vdr_error =
-                    VdrError::ClientInvalidResponse("Missing transaction receipt".to_string());
-
-                warn!("Error: {} getting receipt", vdr_error,);
-
-                vdr_error
-            })
-            .map(|receipt| json!(receipt).to_string())?;
+            .and_then(Self::convert_receipt);
 
-        trace!("Web3Client::get_receipt() -> {:?}", receipt);
+        trace!("Web3Client::get_transaction_receipt() -> {:?}", receipt);
         Ok(receipt)
     }
 
@@ -274,9 +641,12 @@ impl Client for Web3Client {
                 number: block.number.unwrap().as_u64(),
                 timestamp: block.timestamp.as_u64(),
             }),
-            _ => Err(VdrError::ClientInvalidState(
+            Ok(None) => Err(VdrError::ClientInvalidState(
                 "Could not get current network block".to_string(),
             )),
+            Err(err) => Err(Self::map_web3_error(err, |_| {
+                VdrError::ClientInvalidState("Could not get current network block".to_string())
+            })),
         }
     }
 
@@ -292,8 +662,10 @@ impl Client for Web3Client {
             .eth()
             .transaction(transaction_id)
             .await
-            .map_err(|_| {
-                VdrError::GetTransactionError("Could not get transaction by hash".to_string())
+            .map_err(|err| {
+                Self::map_web3_error(err, |_| {
+                    VdrError::GetTransactionError("Could not get transaction by hash".to_string())
+                })
             })?;
 
         let transaction = transaction.map(|transaction| Transaction {
@@ -310,6 +682,30 @@ impl Client for Web3Client {
             data: transaction.input.0.to_vec(),
             signature: Default::default(),
             hash: Some(transaction.hash.as_bytes().to_vec()),
+            envelope_type: match transaction.transaction_type.map(|type_| type_.as_u64()) {
+                Some(1) => TransactionEnvelopeType::Eip2930,
+                Some(2) => TransactionEnvelopeType::Eip1559,
+                _ => TransactionEnvelopeType::Legacy,
+            },
+            gas_limit: Some(transaction.gas.low_u64()),
+            gas_price: transaction.gas_price.map(|value| value.low_u64()),
+            max_fee_per_gas: transaction.max_fee_per_gas.map(|value| value.low_u64()),
+            max_priority_fee_per_gas: transaction
+                .max_priority_fee_per_gas
+                .map(|value| value.low_u64()),
+            access_list: transaction
+                .access_list
+                .unwrap_or_default()
+                .into_iter()
+                .map(|item| AccessListItem {
+                    address: Address::from(format!("{:?}", item.address).as_str()),
+                    storage_keys: item
+                        .storage_keys
+                        .iter()
+                        .map(|key| key.as_bytes().to_vec())
+                        .collect(),
+                })
+                .collect(),
         });
 
         trace!("Web3Client::get_transaction() -> {:?}", transaction);
@@ -322,3 +718,18 @@ impl Debug for Web3Client {
         write!(f, r#"Web3Client {{ }}"#)
     }
 }
+
+#[cfg(test)]
+pub mod test {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case::unknown_scheme("htp://127.0.0.1:8545")]
+    #[case::relative_path("besu/node.ipc")]
+    fn create_client_with_unsupported_address_test(#[case] node_address: &str) {
+        let err = Web3Client::new(node_address).unwrap_err();
+
+        assert!(matches!(err, VdrError::CommonInvalidData(_)));
+    }
+}
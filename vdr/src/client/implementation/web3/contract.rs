@@ -3,44 +3,29 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::{
-    client::{implementation::web3::client::Web3Client, Contract},
+    client::Contract,
     error::{VdrError, VdrResult},
     types::ContractSpec,
     Address,
 };
 use std::fmt::{Debug, Formatter};
 
-use ethabi::{AbiError, Event};
+use ethabi::{AbiError, Address as EthAddress, Contract as EthabiContract, Event, Function};
 use log::warn;
 use log_derive::{logfn, logfn_inputs};
 use std::str::FromStr;
 
-#[cfg(not(feature = "wasm"))]
-use web3::{
-    contract::Contract as Web3ContractImpl,
-    ethabi::{Address as EthAddress, Function},
-    transports::Http,
-};
-#[cfg(feature = "wasm")]
-use web3_wasm::{
-    contract::Contract as Web3ContractImpl,
-    ethabi::{Address as EthAddress, Function},
-    transports::Http,
-};
-
+/// Contract deployed on the network.
+///   Contract object only holds the parsed ABI, so it does not depend on the client transport
 pub struct Web3Contract {
     address: Address,
-    contract: Web3ContractImpl<Http>,
+    contract: EthabiContract,
 }
 
 impl Web3Contract {
     #[logfn(Trace)]
     #[logfn_inputs(Trace)]
-    pub fn new(
-        web3_client: &Web3Client,
-        address: &str,
-        contract_spec: &ContractSpec,
-    ) -> VdrResult<Web3Contract> {
+    pub fn new(address: &str, contract_spec: &ContractSpec) -> VdrResult<Web3Contract> {
         let abi = serde_json::to_vec(&contract_spec.abi).map_err(|err| {
             let vdr_error = VdrError::CommonInvalidData(format!(
                 "Unable to parse contract ABI from specification. Err: {:?}",
@@ -51,7 +36,7 @@ impl Web3Contract {
 
             vdr_error
         })?;
-        let parsed_address = EthAddress::from_str(address).map_err(|err| {
+        EthAddress::from_str(address).map_err(|err| {
             let vdr_error = VdrError::CommonInvalidData(format!(
                 "Unable to parse contract address. Err: {:?}",
                 err.to_string()
@@ -61,8 +46,7 @@ impl Web3Contract {
 
             vdr_error
         })?;
-        let contract =
-            Web3ContractImpl::from_json(web3_client.eth(), parsed_address, abi.as_slice())?;
+        let contract = EthabiContract::load(abi.as_slice())?;
 
         Ok(Web3Contract {
             contract,
@@ -81,7 +65,7 @@ impl Contract for Web3Contract {
     #[logfn(Trace)]
     #[logfn_inputs(Trace)]
     fn function(&self, name: &str) -> VdrResult<&Function> {
-        self.contract.abi().function(name).map_err(|err| {
+        self.contract.function(name).map_err(|err| {
             let vdr_error = VdrError::from(err);
 
             warn!(
@@ -96,7 +80,7 @@ impl Contract for Web3Contract {
     #[logfn(Trace)]
     #[logfn_inputs(Trace)]
     fn event(&self, name: &str) -> VdrResult<&Event> {
-        self.contract.abi().event(name).map_err(|err| {
+        self.contract.event(name).map_err(|err| {
             let vdr_error = VdrError::from(err);
 
             warn!(
@@ -108,10 +92,22 @@ impl Contract for Web3Contract {
         })
     }
 
+    #[logfn(Trace)]
+    #[logfn_inputs(Trace)]
+    fn functions(&self) -> Vec<&Function> {
+        self.contract.functions().collect()
+    }
+
+    #[logfn(Trace)]
+    #[logfn_inputs(Trace)]
+    fn events(&self) -> Vec<&Event> {
+        self.contract.events().collect()
+    }
+
     #[logfn(Trace)]
     #[logfn_inputs(Trace)]
     fn errors(&self) -> Vec<&AbiError> {
-        self.contract.abi().errors().collect()
+        self.contract.errors().collect()
     }
 }
 
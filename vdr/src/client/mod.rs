@@ -2,24 +2,47 @@
 // https://www.dsr-corporation.com
 // SPDX-License-Identifier: Apache-2.0
 
+pub mod cache;
 pub mod client;
 pub mod constants;
+pub mod gas;
 pub mod implementation;
+pub mod nonce;
 pub mod quorum;
+pub mod subscription;
 
-use crate::{error::VdrResult, types::Address, BlockDetails, Transaction};
+use crate::{
+    error::{VdrError, VdrResult},
+    types::Address,
+    BlockDetails, Transaction,
+};
 use async_trait::async_trait;
 use ethabi::{AbiError, Event, Function};
 use std::fmt::Debug;
 
-pub use client::LedgerClient;
+pub use cache::{CacheConfig, CacheStorage, ResolutionCache};
+pub use client::{LedgerClient, LedgerClientBuilder};
 pub use constants::*;
-pub use quorum::{QuorumConfig, QuorumHandler};
+pub use gas::GasPolicy;
+pub use implementation::{
+    failover::{FailoverClient, NodeSelection},
+    offline::OfflineClient,
+};
+pub use nonce::NonceManager;
+pub use quorum::{
+    QuorumConfig, QuorumHandler, QuorumNodeReport, QuorumNodeStatus, QuorumReport, QuorumThreshold,
+};
 
-use crate::types::{EventLog, EventQuery};
+use crate::types::{BatchRequest, BatchResponse, EventLog, EventQuery, TransactionReceipt};
 #[cfg(test)]
 use mockall::automock;
 
+/// Stream of items received from the ledger
+#[cfg(not(feature = "wasm"))]
+pub type EventStream<'a, T> = futures::stream::BoxStream<'a, VdrResult<T>>;
+#[cfg(feature = "wasm")]
+pub type EventStream<'a, T> = futures::stream::LocalBoxStream<'a, VdrResult<T>>;
+
 #[cfg_attr(test, automock)]
 #[cfg_attr(not(feature = "wasm"), async_trait)]
 #[cfg_attr(feature = "wasm", async_trait(?Send))]
@@ -30,7 +53,7 @@ pub trait Client: Sync + Send + Debug {
     /// - `address` [Address] address of an account to get number of written transactions
     ///
     /// # Returns
-    /// number of transactions
+    /// number of transactions including the pending ones
     async fn get_transaction_count(&self, address: &Address) -> VdrResult<u64>;
 
     /// Submit transaction to the ledger
@@ -42,6 +65,20 @@ pub trait Client: Sync + Send + Debug {
     /// hash of a block in which transaction included
     async fn submit_transaction(&self, transaction: &[u8]) -> VdrResult<Vec<u8>>;
 
+    /// Send signed write transaction to the ledger without waiting for its inclusion into a block
+    ///
+    /// # Params
+    /// - `transaction` [Transaction] transaction to send
+    ///
+    /// # Returns
+    /// hash of the sent transaction
+    async fn send_transaction(&self, transaction: &[u8]) -> VdrResult<Vec<u8>> {
+        let _ = transaction;
+        Err(VdrError::ClientInvalidState(
+            "Sending transactions without confirmation is not supported by the client".to_string(),
+        ))
+    }
+
     /// Submit read transaction to the ledger
     ///
     /// # Params
@@ -51,6 +88,59 @@ pub trait Client: Sync + Send + Debug {
     /// result data of transaction execution
     async fn call_transaction(&self, to: &str, transaction: &[u8]) -> VdrResult<Vec<u8>>;
 
+    /// Execute write transaction data as a call from the sender account at the latest block
+    ///     without submitting a transaction to the ledger
+    ///
+    /// # Params
+    /// - `from` [Address] transaction sender account address
+    /// - `to` transaction recipient address
+    /// - `transaction` transaction data
+    ///
+    /// # Returns
+    /// result data of transaction execution
+    async fn simulate_transaction(
+        &self,
+        from: &Address,
+        to: &str,
+        transaction: &[u8],
+    ) -> VdrResult<Vec<u8>> {
+        let _ = (from, to, transaction);
+        Err(VdrError::ClientInvalidState(
+            "Transaction simulation is not supported by the client".to_string(),
+        ))
+    }
+
+    /// Estimate gas required for the transaction execution
+    ///
+    /// # Params
+    /// - `from` [Address] transaction sender account address
+    /// - `to` transaction recipient address
+    /// - `transaction` transaction data
+    ///
+    /// # Returns
+    /// estimated amount of gas
+    async fn estimate_gas(
+        &self,
+        from: Option<&Address>,
+        to: &str,
+        transaction: &[u8],
+    ) -> VdrResult<u64> {
+        let _ = (from, to, transaction);
+        Err(VdrError::ClientInvalidState(
+            "Gas estimation is not supported by the client".to_string(),
+        ))
+    }
+
+    /// Get current gas price of the network
+    ///
+    /// # Returns
+    /// gas price in wei
+    async fn get_gas_price(&self) -> VdrResult<u64> {
+        Err(VdrError::ClientInvalidState(
+            "Gas price request is not supported by the client".to_string(),
+        ))
+    }
+
     /// Send a prepared query for retrieving log events on the ledger
     ///
     /// #Params
@@ -60,14 +150,64 @@ pub trait Client: Sync + Send + Debug {
     ///   logs - list of received events
     async fn query_events(&self, query: &EventQuery) -> VdrResult<Vec<EventLog>>;
 
-    /// Get the receipt for the given block hash
+    /// Send multiple read requests to the ledger
+    ///     By default requests are sent one by one, clients supporting JSON-RPC batches send them at once
+    ///
+    /// #Params
+    /// - `requests` list of read transactions and events queries to send
+    ///
+    /// #Returns
+    ///   responses - results of requests execution in the order of requests
+    async fn submit_batch(
+        &self,
+        requests: &[BatchRequest],
+    ) -> VdrResult<Vec<VdrResult<BatchResponse>>> {
+        let mut responses = Vec::with_capacity(requests.len());
+        for request in requests {
+            let response = match request {
+                BatchRequest::Call(transaction) => self
+                    .call_transaction(transaction.to.as_ref(), &transaction.data)
+                    .await
+                    .map(BatchResponse::Call),
+                BatchRequest::Events(query) => {
+                    self.query_events(query).await.map(BatchResponse::Events)
+                }
+            };
+            responses.push(response);
+        }
+        Ok(responses)
+    }
+
+    /// Subscribe to log events matching to the query (`from_block` and `to_block` are ignored)
+    ///
+    /// #Params
+    /// - `query` [EventQuery] query defining events to subscribe
+    ///
+    /// #Returns
+    ///   stream - stream of events received from the ledger. Stream ends when the connection is lost
+    async fn subscribe_events(
+        &self,
+        query: &EventQuery,
+    ) -> VdrResult<EventStream<'static, EventLog>> {
+        let _ = query;
+        Err(VdrError::ClientInvalidState(
+            "Event subscriptions are not supported by the client".to_string(),
+        ))
+    }
+
+    /// Get the receipt of the given transaction
     ///
     /// # Params
-    /// - `hash` hash of a block to get the receipt
+    /// - `hash` hash of a transaction to get the receipt
     ///
     /// # Returns
-    /// receipt as JSON string for the requested block
-    async fn get_receipt(&self, hash: &[u8]) -> VdrResult<String>;
+    /// receipt of the transaction or `None` if the transaction is not included into a block yet
+    async fn get_transaction_receipt(&self, hash: &[u8]) -> VdrResult<Option<TransactionReceipt>> {
+        let _ = hash;
+        Err(VdrError::ClientInvalidState(
+            "Transaction receipt request is not supported by the client".to_string(),
+        ))
+    }
 
     /// Get details for the given block
     ///
@@ -110,6 +250,22 @@ pub trait Contract: Sync + Send + Debug {
     /// Contract event
     fn event(&self, name: &str) -> VdrResult<&Event>;
 
+    /// Get the contract functions
+    ///
+    /// # Returns
+    /// Contract functions. Transactions of contracts not providing the list cannot be decoded
+    fn functions(&self) -> Vec<&Function> {
+        Vec::new()
+    }
+
+    /// Get the contract events
+    ///
+    /// # Returns
+    /// Contract events. Events of contracts not providing the list are not decoded in transaction receipts
+    fn events(&self) -> Vec<&Event> {
+        Vec::new()
+    }
+
     /// Get the contract errors
     ///
     /// # Returns
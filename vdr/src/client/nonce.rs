@@ -0,0 +1,323 @@
+// Copyright (c) 2024 DSR Corporation, Denver, Colorado.
+// https://www.dsr-corporation.com
+// SPDX-License-Identifier: Apache-2.0
+
+use std::{
+    collections::{BTreeSet, HashMap},
+    sync::{Mutex, MutexGuard},
+};
+
+use log::trace;
+use log_derive::{logfn, logfn_inputs};
+
+use crate::{
+    error::{VdrError, VdrResult},
+    types::Address,
+    LedgerClient,
+};
+
+/// Manager of the nonces used for write transactions of the client accounts.
+///
+/// Nonces are reserved atomically, so transactions built concurrently for the same account get sequential nonces.
+/// On every reservation the state is reconciled with the number of account transactions including the pending ones,
+/// so transactions sent by other clients are taken into account.
+/// Nonces of transactions failed to be submitted are released and reused first to fill the gaps.
+#[derive(Debug, Default)]
+pub struct NonceManager {
+    accounts: Mutex<HashMap<String, AccountNonces>>,
+}
+
+#[derive(Debug, Default)]
+struct AccountNonces {
+    /// Next nonce which has never been reserved
+    next: u64,
+    /// Released nonces lower than `next` which must be reused
+    released: BTreeSet<u64>,
+}
+
+impl NonceManager {
+    pub fn new() -> NonceManager {
+        NonceManager::default()
+    }
+
+    /// Reserve the nonce for the next transaction of the account
+    #[logfn(Trace)]
+    #[logfn_inputs(Trace)]
+    pub(crate) async fn reserve(&self, client: &LedgerClient, address: &Address) -> VdrResult<u64> {
+        let pending_count = client.get_transaction_count(address).await?;
+        Ok(self.reserve_after(address, pending_count))
+    }
+
+    /// Reserve the nonce for the next transaction of the account without requesting the ledger.
+    ///     The next nonce of the account must be set explicitly with [NonceManager::set_next]
+    #[logfn(Trace)]
+    #[logfn_inputs(Trace)]
+    pub(crate) fn reserve_offline(&self, address: &Address) -> VdrResult<u64> {
+        if !self.accounts().contains_key(&Self::key(address)) {
+            return Err(VdrError::ClientInvalidState(format!(
+                "Nonce of account {} must be set explicitly in the offline mode",
+                address.as_ref()
+            )));
+        }
+        Ok(self.reserve_after(address, 0))
+    }
+
+    /// Set the nonce to use for the next transaction of the account.
+    ///     Previously reserved and released nonces of the account are forgotten
+    ///
+    /// # Params
+    ///  - `address`: [Address] - account address
+    ///  - `nonce`: [u64] - nonce of the next transaction (number of the account transactions sent to the ledger)
+    #[logfn(Trace)]
+    #[logfn_inputs(Trace)]
+    pub fn set_next(&self, address: &Address, nonce: u64) {
+        self.accounts().insert(
+            Self::key(address),
+            AccountNonces {
+                next: nonce,
+                released: BTreeSet::new(),
+            },
+        );
+    }
+
+    /// Return the reserved nonce back, so it will be used for the next transaction of the account.
+    ///     Should be called if the transaction built with the nonce is not going to be submitted.
+    ///     Nonces already used by the ledger are ignored on the next reservation.
+    ///
+    /// # Params
+    ///  - `address`: [Address] - account address the nonce was reserved for
+    ///  - `nonce`: [u64] - released nonce
+    #[logfn(Trace)]
+    #[logfn_inputs(Trace)]
+    pub fn release(&self, address: &Address, nonce: u64) {
+        let mut accounts = self.accounts();
+        let account = match accounts.get_mut(&Self::key(address)) {
+            Some(account) if nonce < account.next => account,
+            _ => return,
+        };
+
+        account.released.insert(nonce);
+        // shrink the reserved range instead of keeping released nonces at its end
+        while account.next > 0 && account.released.remove(&(account.next - 1)) {
+            account.next -= 1;
+        }
+    }
+
+    /// Forget the tracked nonces of the account, so the next nonce is requested from the ledger
+    ///
+    /// # Params
+    ///  - `address`: [Address] - account address
+    #[logfn(Trace)]
+    #[logfn_inputs(Trace)]
+    pub fn reset(&self, address: &Address) {
+        self.accounts().remove(&Self::key(address));
+    }
+
+    fn reserve_after(&self, address: &Address, pending_count: u64) -> u64 {
+        let mut accounts = self.accounts();
+        let account = accounts.entry(Self::key(address)).or_default();
+
+        // nonces lower than the number of account transactions are already used
+        account.released = account.released.split_off(&pending_count);
+        account.next = account.next.max(pending_count);
+
+        let nonce = match account.released.iter().next().copied() {
+            Some(nonce) => {
+                account.released.remove(&nonce);
+                nonce
+            }
+            None => {
+                account.next += 1;
+                account.next - 1
+            }
+        };
+
+        trace!(
+            "NonceManager: reserved nonce {} for account {:?}",
+            nonce,
+            address
+        );
+        nonce
+    }
+
+    fn accounts(&self) -> MutexGuard<'_, HashMap<String, AccountNonces>> {
+        self.accounts
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    fn key(address: &Address) -> String {
+        address.as_ref().to_lowercase()
+    }
+}
+
+#[cfg(test)]
+pub mod test {
+    use super::*;
+    use crate::{
+        client::{
+            client::test::{contracts, TRUSTEE_ACCOUNT},
+            MockClient,
+        },
+        contracts::network::validator_control::{
+            build_add_validator_transaction, test::VALIDATOR_ADDRESS,
+        },
+        types::transaction::test::write_transaction,
+        GasPolicy, LedgerClientBuilder, SignatureData, Transaction, VdrError,
+    };
+    use futures::future::join_all;
+    use std::sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc,
+    };
+
+    const PENDING_COUNT: u64 = 5;
+
+    fn client(pending_count: Arc<AtomicU64>) -> LedgerClient {
+        let mut client = MockClient::new();
+        client
+            .expect_get_transaction_count()
+            .returning(move |_| Ok(pending_count.load(Ordering::SeqCst)));
+        client.expect_submit_transaction().returning(|_| {
+            Err(VdrError::ClientUnexpectedError(
+                "Transaction rejected".to_string(),
+            ))
+        });
+        LedgerClientBuilder::new()
+            .set_client(Box::new(client))
+            .set_nonce_management(true)
+            .build()
+            .unwrap()
+    }
+
+    async fn reserve(client: &LedgerClient) -> u64 {
+        client
+            .nonce_manager()
+            .unwrap()
+            .reserve(client, &TRUSTEE_ACCOUNT)
+            .await
+            .unwrap()
+    }
+
+    #[async_std::test]
+    async fn reserve_concurrent_nonces_test() {
+        let client = client(Arc::new(AtomicU64::new(PENDING_COUNT)));
+
+        let mut nonces = join_all((0..10).map(|_| reserve(&client))).await;
+        nonces.sort();
+
+        assert_eq!(
+            (PENDING_COUNT..PENDING_COUNT + 10).collect::<Vec<u64>>(),
+            nonces
+        );
+    }
+
+    #[async_std::test]
+    async fn reuse_released_nonce_test() {
+        let client = client(Arc::new(AtomicU64::new(PENDING_COUNT)));
+        let nonce_manager = client.nonce_manager().unwrap();
+        for _ in 0..3 {
+            reserve(&client).await;
+        }
+
+        nonce_manager.release(&TRUSTEE_ACCOUNT, PENDING_COUNT + 1);
+
+        assert_eq!(PENDING_COUNT + 1, reserve(&client).await);
+        assert_eq!(PENDING_COUNT + 3, reserve(&client).await);
+    }
+
+    #[async_std::test]
+    async fn release_last_nonce_test() {
+        let client = client(Arc::new(AtomicU64::new(PENDING_COUNT)));
+        let nonce_manager = client.nonce_manager().unwrap();
+        reserve(&client).await;
+        reserve(&client).await;
+
+        nonce_manager.release(&TRUSTEE_ACCOUNT, PENDING_COUNT + 1);
+        nonce_manager.release(&TRUSTEE_ACCOUNT, PENDING_COUNT);
+
+        assert_eq!(PENDING_COUNT, reserve(&client).await);
+        assert_eq!(PENDING_COUNT + 1, reserve(&client).await);
+    }
+
+    #[async_std::test]
+    async fn reconcile_with_pending_count_test() {
+        let pending_count = Arc::new(AtomicU64::new(PENDING_COUNT));
+        let client = client(pending_count.clone());
+        let nonce_manager = client.nonce_manager().unwrap();
+        for _ in 0..3 {
+            reserve(&client).await;
+        }
+        nonce_manager.release(&TRUSTEE_ACCOUNT, PENDING_COUNT);
+
+        // transactions were sent by another client or released nonce was used
+        pending_count.store(PENDING_COUNT + 10, Ordering::SeqCst);
+
+        assert_eq!(PENDING_COUNT + 10, reserve(&client).await);
+    }
+
+    #[async_std::test]
+    async fn reset_account_test() {
+        let client = client(Arc::new(AtomicU64::new(PENDING_COUNT)));
+        reserve(&client).await;
+        reserve(&client).await;
+
+        client.nonce_manager().unwrap().reset(&TRUSTEE_ACCOUNT);
+
+        assert_eq!(PENDING_COUNT, reserve(&client).await);
+    }
+
+    #[async_std::test]
+    async fn release_nonce_of_failed_submission_test() {
+        let client = client(Arc::new(AtomicU64::new(PENDING_COUNT)));
+        let nonce = reserve(&client).await;
+        let mut transaction = Transaction {
+            nonce: Some(nonce),
+            ..write_transaction()
+        };
+        transaction.set_signature(SignatureData {
+            recovery_id: 1,
+            signature: vec![1; 64],
+        });
+
+        client.submit_transaction(&transaction).await.unwrap_err();
+
+        assert_eq!(nonce, reserve(&client).await);
+    }
+
+    #[async_std::test]
+    async fn keep_nonce_of_failed_gas_estimation_test() {
+        let mut client = MockClient::new();
+        client
+            .expect_get_transaction_count()
+            .returning(|_| Ok(PENDING_COUNT));
+        client.expect_estimate_gas().times(1).returning(|_, _, _| {
+            Err(VdrError::ClientUnexpectedError(
+                "Gas estimation failed".to_string(),
+            ))
+        });
+        client
+            .expect_estimate_gas()
+            .returning(|_, _, _| Ok(100_000));
+        let client = LedgerClientBuilder::new()
+            .set_client(Box::new(client))
+            .set_contract_configs(&contracts())
+            .set_gas_policy(&GasPolicy {
+                estimate_gas_limit: true,
+                ..GasPolicy::default()
+            })
+            .set_nonce_management(true)
+            .build()
+            .unwrap();
+
+        build_add_validator_transaction(&client, &TRUSTEE_ACCOUNT, &VALIDATOR_ADDRESS)
+            .await
+            .unwrap_err();
+        let transaction =
+            build_add_validator_transaction(&client, &TRUSTEE_ACCOUNT, &VALIDATOR_ADDRESS)
+                .await
+                .unwrap();
+
+        assert_eq!(Some(PENDING_COUNT), transaction.nonce);
+    }
+}
@@ -13,14 +13,16 @@ use log::trace;
 use log_derive::{logfn, logfn_inputs};
 use serde_derive::{Deserialize, Serialize};
 use std::{
-    fmt::{Debug, Formatter},
+    collections::HashMap,
+    fmt::{Debug, Display, Formatter},
     sync::Arc,
     time::Duration,
 };
 
 use crate::{
-    client::implementation::web3::client::Web3Client, Client, Transaction, TransactionType,
-    VdrError, VdrResult,
+    client::implementation::web3::client::Web3Client,
+    types::{BatchRequest, BatchResponse},
+    Client, EventLog, EventQuery, Transaction, TransactionType, VdrError, VdrResult,
 };
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -29,14 +31,143 @@ pub struct QuorumConfig {
     pub request_retries: Option<u8>,
     pub request_timeout: Option<u64>,
     pub retry_interval: Option<u64>,
+    /// Weight of nodes agreement required to reach the quorum. `total_weight / 3 + 1` if not set
+    pub threshold: Option<QuorumThreshold>,
+    /// Weights of the nodes (by node address). Nodes missing in the map have weight `1`
+    pub weights: Option<HashMap<String, u64>>,
+}
+
+/// Threshold of the nodes agreement required to reach the quorum
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub enum QuorumThreshold {
+    /// Absolute weight of agreed nodes
+    Count(u64),
+    /// Fraction (0, 1] of the total nodes weight
+    Fraction(f64),
+    /// All nodes must agree
+    All,
+}
+
+impl QuorumThreshold {
+    fn required_weight(&self, total_weight: u64) -> u64 {
+        match self {
+            QuorumThreshold::Count(count) => *count,
+            QuorumThreshold::Fraction(fraction) => {
+                ((total_weight as f64 * fraction).ceil() as u64).max(1)
+            }
+            QuorumThreshold::All => total_weight,
+        }
+    }
+}
+
+/// Detailed result of the quorum check
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct QuorumReport {
+    /// Whether the quorum was reached
+    pub reached: bool,
+    /// Weight of agreed nodes required to reach the quorum
+    pub required_weight: u64,
+    /// Weight of nodes returned the expected result
+    pub agreed_weight: u64,
+    /// Result of every quorum node
+    pub nodes: Vec<QuorumNodeReport>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct QuorumNodeReport {
+    pub node: String,
+    pub weight: u64,
+    pub status: QuorumNodeStatus,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub enum QuorumNodeStatus {
+    /// Node returned the expected result
+    Agreed { response: String },
+    /// Node returned a different result
+    Disagreed { response: String },
+    /// All requests to the node timed out
+    TimedOut,
+    /// Requests to the node failed
+    Errored { error: String },
+    /// Quorum was reached before the node responded
+    Pending,
+}
+
+impl QuorumReport {
+    /// Nodes returned the expected result
+    pub fn agreed(&self) -> Vec<&QuorumNodeReport> {
+        self.filter(|status| matches!(status, QuorumNodeStatus::Agreed { .. }))
+    }
+
+    /// Nodes returned a different result
+    pub fn disagreed(&self) -> Vec<&QuorumNodeReport> {
+        self.filter(|status| matches!(status, QuorumNodeStatus::Disagreed { .. }))
+    }
+
+    /// Nodes not responded in time
+    pub fn timed_out(&self) -> Vec<&QuorumNodeReport> {
+        self.filter(|status| matches!(status, QuorumNodeStatus::TimedOut))
+    }
+
+    /// Nodes failed to process the request
+    pub fn errored(&self) -> Vec<&QuorumNodeReport> {
+        self.filter(|status| matches!(status, QuorumNodeStatus::Errored { .. }))
+    }
+
+    fn filter(&self, predicate: impl Fn(&QuorumNodeStatus) -> bool) -> Vec<&QuorumNodeReport> {
+        self.nodes
+            .iter()
+            .filter(|node| predicate(&node.status))
+            .collect()
+    }
+}
+
+impl Display for QuorumReport {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "agreed weight {} of required {}",
+            self.agreed_weight, self.required_weight
+        )?;
+        for node in self.nodes.iter() {
+            write!(f, "; {} (weight {}): ", node.node, node.weight)?;
+            match &node.status {
+                QuorumNodeStatus::Agreed { response } => write!(f, "agreed with {}", response)?,
+                QuorumNodeStatus::Disagreed { response } => {
+                    write!(f, "disagreed with {}", response)?
+                }
+                QuorumNodeStatus::TimedOut => write!(f, "timed out")?,
+                QuorumNodeStatus::Errored { error } => write!(f, "errored with {}", error)?,
+                QuorumNodeStatus::Pending => write!(f, "pending")?,
+            }
+        }
+        Ok(())
+    }
 }
 
 const DEFAULT_REQUEST_RETRIES: u8 = 4;
 const DEFAULT_REQUEST_TIMEOUT: u64 = 2000;
 const DEFAULT_RETRY_INTERVAL: u64 = 500;
+const DEFAULT_NODE_WEIGHT: u64 = 1;
+
+struct QuorumNode {
+    address: String,
+    weight: u64,
+    client: Arc<Box<dyn Client>>,
+}
+
+// Result of the requests sent to a quorum node
+#[derive(Debug)]
+enum QuorumNodeResponse<T> {
+    Received(T),
+    TimedOut,
+    Errored(String),
+}
 
 pub struct QuorumHandler {
-    clients: Vec<Arc<Box<dyn Client>>>,
+    nodes: Vec<QuorumNode>,
+    threshold: Option<QuorumThreshold>,
     request_retries: u8,
     request_timeout: Duration,
     retry_interval: Duration,
@@ -46,17 +177,33 @@ impl QuorumHandler {
     #[logfn(Info)]
     #[logfn_inputs(Debug)]
     pub fn new(config: QuorumConfig) -> VdrResult<QuorumHandler> {
-        let clients = config
+        let weights = config.weights.unwrap_or_default();
+        if let Some(unknown_node) = weights.keys().find(|node| !config.nodes.contains(node)) {
+            return Err(VdrError::CommonInvalidData(format!(
+                "Weight is set for unknown quorum node: {}",
+                unknown_node
+            )));
+        }
+
+        let nodes = config
             .nodes
             .iter()
             .map(|node_address| {
                 let client: Box<dyn Client> = Box::new(Web3Client::new(node_address)?);
-                Ok(Arc::new(client))
+                Ok(QuorumNode {
+                    address: node_address.to_string(),
+                    weight: weights
+                        .get(node_address)
+                        .copied()
+                        .unwrap_or(DEFAULT_NODE_WEIGHT),
+                    client: Arc::new(client),
+                })
             })
             .collect::<Result<Vec<_>, VdrError>>()?;
 
         let handler = QuorumHandler {
-            clients,
+            nodes,
+            threshold: config.threshold,
             request_retries: config.request_retries.unwrap_or(DEFAULT_REQUEST_RETRIES),
             request_timeout: Duration::from_millis(
                 config.request_timeout.unwrap_or(DEFAULT_REQUEST_TIMEOUT),
@@ -65,14 +212,40 @@ impl QuorumHandler {
                 config.retry_interval.unwrap_or(DEFAULT_RETRY_INTERVAL),
             ),
         };
+
+        let total_weight = handler.total_weight();
+        let required_weight = handler.required_weight();
+        let invalid_fraction = matches!(
+            handler.threshold,
+            Some(QuorumThreshold::Fraction(fraction)) if !(fraction > 0.0 && fraction <= 1.0)
+        );
+        if invalid_fraction || required_weight == 0 || required_weight > total_weight {
+            return Err(VdrError::CommonInvalidData(format!(
+                "Quorum threshold {:?} is unreachable for total nodes weight {}",
+                handler.threshold, total_weight
+            )));
+        }
         Ok(handler)
     }
 
+    fn total_weight(&self) -> u64 {
+        self.nodes.iter().map(|node| node.weight).sum()
+    }
+
+    fn required_weight(&self) -> u64 {
+        let total_weight = self.total_weight();
+        match &self.threshold {
+            Some(threshold) => threshold.required_weight(total_weight),
+            None => total_weight / 3 + 1,
+        }
+    }
+
     #[logfn(Info)]
     #[logfn_inputs(Debug)]
     #[allow(clippy::too_many_arguments)]
     async fn send_transaction_with_retries(
-        mut sender: Sender<Vec<u8>>,
+        mut sender: Sender<(usize, QuorumNodeResponse<Vec<u8>>)>,
+        index: usize,
         client: Arc<Box<dyn Client>>,
         type_: TransactionType,
         to: String,
@@ -81,19 +254,24 @@ impl QuorumHandler {
         request_timeout: Duration,
         retry_interval: Duration,
     ) {
+        let mut response = QuorumNodeResponse::Errored("No requests were sent".to_string());
         for _ in 1..request_retries {
             match type_ {
                 TransactionType::Write => {
                     let future = client.get_transaction(&data);
                     match async_std::future::timeout(request_timeout, future).await {
                         Ok(Ok(Some(transaction))) if transaction.hash.is_some() => {
-                            let hash = transaction.hash.unwrap();
-                            if sender.try_send(hash).is_err() {
-                                trace!("Receiver is closed for sender: {:?}", sender);
-                            }
+                            response = QuorumNodeResponse::Received(transaction.hash.unwrap());
                             break;
                         }
-                        _ => {
+                        result => {
+                            response = match result {
+                                Ok(Ok(_)) => {
+                                    QuorumNodeResponse::Errored("Transaction not found".to_string())
+                                }
+                                Ok(Err(err)) => QuorumNodeResponse::Errored(err.to_string()),
+                                Err(_) => QuorumNodeResponse::TimedOut,
+                            };
                             trace!(
                                 "eth_getTransaction not succeed for transaction_hash: {:?}. retry",
                                 data
@@ -106,12 +284,14 @@ impl QuorumHandler {
                     let future = client.call_transaction(&to, &data);
                     match async_std::future::timeout(request_timeout, future).await {
                         Ok(Ok(transaction)) => {
-                            if sender.try_send(transaction).is_err() {
-                                trace!("Receiver is closed for sender: {:?}", sender);
-                            }
+                            response = QuorumNodeResponse::Received(transaction);
                             break;
                         }
-                        _ => {
+                        result => {
+                            response = match result {
+                                Ok(Err(err)) => QuorumNodeResponse::Errored(err.to_string()),
+                                _ => QuorumNodeResponse::TimedOut,
+                            };
                             trace!(
                                 "call_transaction not succeed for transaction_hash: {:?}. retry",
                                 data
@@ -122,44 +302,220 @@ impl QuorumHandler {
                 }
             };
         }
+        if sender.try_send((index, response)).is_err() {
+            trace!("Receiver is closed for sender: {:?}", sender);
+        }
+    }
+
+    #[logfn(Info)]
+    #[logfn_inputs(Debug)]
+    async fn send_query_with_retries(
+        mut sender: Sender<(usize, QuorumNodeResponse<Vec<EventLog>>)>,
+        index: usize,
+        client: Arc<Box<dyn Client>>,
+        query: EventQuery,
+        request_retries: u8,
+        request_timeout: Duration,
+        retry_interval: Duration,
+    ) {
+        let mut response = QuorumNodeResponse::Errored("No requests were sent".to_string());
+        for _ in 1..request_retries {
+            let future = client.query_events(&query);
+            match async_std::future::timeout(request_timeout, future).await {
+                Ok(Ok(events)) => {
+                    response = QuorumNodeResponse::Received(events);
+                    break;
+                }
+                result => {
+                    response = match result {
+                        Ok(Err(err)) => QuorumNodeResponse::Errored(err.to_string()),
+                        _ => QuorumNodeResponse::TimedOut,
+                    };
+                    trace!("query_events not succeed for query: {:?}. retry", query);
+                    async_std::task::sleep(retry_interval).await;
+                }
+            }
+        }
+        if sender.try_send((index, response)).is_err() {
+            trace!("Receiver is closed for sender: {:?}", sender);
+        }
+    }
+
+    #[logfn(Info)]
+    #[logfn_inputs(Debug)]
+    async fn send_batch_with_retries(
+        mut sender: Sender<(usize, QuorumNodeResponse<Vec<VdrResult<BatchResponse>>>)>,
+        index: usize,
+        client: Arc<Box<dyn Client>>,
+        requests: Vec<BatchRequest>,
+        request_retries: u8,
+        request_timeout: Duration,
+        retry_interval: Duration,
+    ) {
+        let mut response = QuorumNodeResponse::Errored("No requests were sent".to_string());
+        for _ in 1..request_retries {
+            let future = client.submit_batch(&requests);
+            match async_std::future::timeout(request_timeout, future).await {
+                Ok(Ok(results)) => {
+                    response = QuorumNodeResponse::Received(results);
+                    break;
+                }
+                result => {
+                    response = match result {
+                        Ok(Err(err)) => QuorumNodeResponse::Errored(err.to_string()),
+                        _ => QuorumNodeResponse::TimedOut,
+                    };
+                    trace!(
+                        "submit_batch not succeed for requests: {:?}. retry",
+                        requests
+                    );
+                    async_std::task::sleep(retry_interval).await;
+                }
+            }
+        }
+        if sender.try_send((index, response)).is_err() {
+            trace!("Receiver is closed for sender: {:?}", sender);
+        }
     }
 
     #[logfn(Info)]
     #[logfn_inputs(Debug)]
-    async fn wait_for_quorum(
+    async fn wait_for_quorum<T, E>(
         &self,
-        mut receiver: Receiver<Vec<u8>>,
-        expected_result: &[u8],
-    ) -> bool {
-        let approvals_needed = self.clients.len() / 3 + 1;
-        let mut approvals_counter = 0;
-        let mut quorum_reached = false;
+        mut receiver: Receiver<(usize, QuorumNodeResponse<T>)>,
+        expected_result: &E,
+    ) -> QuorumReport
+    where
+        T: PartialEq<E> + Debug,
+        E: Debug + ?Sized,
+    {
+        let required_weight = self.required_weight();
+        let mut agreed_weight = 0;
+        let mut statuses: Vec<QuorumNodeStatus> = vec![QuorumNodeStatus::Pending; self.nodes.len()];
 
-        while let Some(result) = receiver.next().await {
-            if result == expected_result {
-                approvals_counter += 1;
+        while let Some((index, response)) = receiver.next().await {
+            let weight = self.nodes[index].weight;
 
-                quorum_reached = approvals_counter >= approvals_needed;
-                if quorum_reached {
-                    break;
+            statuses[index] = match response {
+                QuorumNodeResponse::Received(result) if result == *expected_result => {
+                    agreed_weight += weight;
+                    QuorumNodeStatus::Agreed {
+                        response: format!("{:?}", result),
+                    }
                 }
+                QuorumNodeResponse::Received(result) => QuorumNodeStatus::Disagreed {
+                    response: format!("{:?}", result),
+                },
+                QuorumNodeResponse::TimedOut => QuorumNodeStatus::TimedOut,
+                QuorumNodeResponse::Errored(error) => QuorumNodeStatus::Errored { error },
+            };
+
+            if agreed_weight >= required_weight {
+                break;
             }
         }
 
-        quorum_reached
+        self.build_report(agreed_weight, statuses)
     }
 
+    // Wait for the batch results of the quorum nodes and compare them with the expected ones position by position.
+    //  Failed expected results are not checked
     #[logfn(Info)]
     #[logfn_inputs(Debug)]
-    pub async fn check(
+    async fn wait_for_batch_quorum(
         &self,
-        transaction: &Transaction,
-        expected_result: &[u8],
-    ) -> VdrResult<bool> {
-        let clients_count = self.clients.len();
-        let (sender, receiver) = mpsc::channel::<Vec<u8>>(clients_count);
+        mut receiver: Receiver<(usize, QuorumNodeResponse<Vec<VdrResult<BatchResponse>>>)>,
+        expected_results: &[VdrResult<BatchResponse>],
+    ) -> Vec<QuorumReport> {
+        let required_weight = self.required_weight();
+        let mut agreed_weights: Vec<u64> = vec![0; expected_results.len()];
+        let mut statuses: Vec<Vec<QuorumNodeStatus>> =
+            vec![vec![QuorumNodeStatus::Pending; self.nodes.len()]; expected_results.len()];
+
+        while let Some((index, response)) = receiver.next().await {
+            let weight = self.nodes[index].weight;
+
+            for (position, expected_result) in expected_results.iter().enumerate() {
+                statuses[position][index] = match &response {
+                    QuorumNodeResponse::Received(results) => {
+                        match (results.get(position), expected_result) {
+                            (Some(Ok(result)), Ok(expected_result))
+                                if result == expected_result =>
+                            {
+                                agreed_weights[position] += weight;
+                                QuorumNodeStatus::Agreed {
+                                    response: format!("{:?}", result),
+                                }
+                            }
+                            (Some(Ok(result)), _) => QuorumNodeStatus::Disagreed {
+                                response: format!("{:?}", result),
+                            },
+                            (Some(Err(err)), _) => QuorumNodeStatus::Errored {
+                                error: err.to_string(),
+                            },
+                            (None, _) => QuorumNodeStatus::Errored {
+                                error: "Missing batch response".to_string(),
+                            },
+                        }
+                    }
+                    QuorumNodeResponse::TimedOut => QuorumNodeStatus::TimedOut,
+                    QuorumNodeResponse::Errored(error) => QuorumNodeStatus::Errored {
+                        error: error.to_string(),
+                    },
+                };
+            }
+
+            let reached = expected_results.iter().zip(agreed_weights.iter()).all(
+                |(expected_result, agreed_weight)| {
+                    expected_result.is_err() || *agreed_weight >= required_weight
+                },
+            );
+            if reached {
+                break;
+            }
+        }
+
+        agreed_weights
+            .into_iter()
+            .zip(statuses)
+            .map(|(agreed_weight, statuses)| self.build_report(agreed_weight, statuses))
+            .collect()
+    }
+
+    fn build_report(&self, agreed_weight: u64, statuses: Vec<QuorumNodeStatus>) -> QuorumReport {
+        let required_weight = self.required_weight();
+        QuorumReport {
+            reached: agreed_weight >= required_weight,
+            required_weight,
+            agreed_weight,
+            nodes: self
+                .nodes
+                .iter()
+                .zip(statuses)
+                .map(|(node, status)| QuorumNodeReport {
+                    node: node.address.clone(),
+                    weight: node.weight,
+                    status,
+                })
+                .collect(),
+        }
+    }
 
-        for client in self.clients.iter() {
+    /// Request the transaction result from the quorum nodes and compare it with the expected one.
+    ///
+    /// # Params
+    ///  - `transaction`: [Transaction] - submitted transaction
+    ///  - `expected_result`: [u8] - transaction result: response bytes for Read or hash for Write transactions
+    ///
+    /// # Returns
+    ///  report: [QuorumReport] - detailed results of the quorum nodes
+    #[logfn(Info)]
+    #[logfn_inputs(Debug)]
+    pub async fn report(&self, transaction: &Transaction, expected_result: &[u8]) -> QuorumReport {
+        let (sender, receiver) =
+            mpsc::channel::<(usize, QuorumNodeResponse<Vec<u8>>)>(self.nodes.len());
+
+        for (index, node) in self.nodes.iter().enumerate() {
             let type_ = transaction.type_.clone();
             let to = transaction.to.clone();
             let transaction_data = match transaction.type_ {
@@ -170,12 +526,13 @@ impl QuorumHandler {
             #[cfg(feature = "wasm")]
             {
                 let sender = sender.clone();
-                let client = client.clone();
+                let client = node.client.clone();
                 let request_retries = self.request_retries;
                 let request_timeout = self.request_timeout;
                 let retry_interval = self.retry_interval;
                 async_std::task::block_on(QuorumHandler::send_transaction_with_retries(
                     sender,
+                    index,
                     client,
                     type_,
                     to.to_string(),
@@ -190,7 +547,8 @@ impl QuorumHandler {
             {
                 async_std::task::spawn(QuorumHandler::send_transaction_with_retries(
                     sender.clone(),
-                    client.clone(),
+                    index,
+                    node.client.clone(),
                     type_,
                     to.to_string(),
                     transaction_data,
@@ -203,16 +561,154 @@ impl QuorumHandler {
 
         drop(sender);
 
-        let quorum_reached = self.wait_for_quorum(receiver, expected_result).await;
-        if quorum_reached {
-            Ok(quorum_reached)
+        self.wait_for_quorum(receiver, expected_result).await
+    }
+
+    /// Check that the quorum nodes agree with the transaction result.
+    ///
+    /// # Params
+    ///  - `transaction`: [Transaction] - submitted transaction
+    ///  - `expected_result`: [u8] - transaction result: response bytes for Read or hash for Write transactions
+    ///
+    /// # Returns
+    ///  report: [QuorumReport] - detailed results of the quorum nodes.
+    ///    [VdrError::QuorumNotReached] error containing the report is returned if the quorum is not reached
+    #[logfn(Info)]
+    #[logfn_inputs(Debug)]
+    pub async fn check(
+        &self,
+        transaction: &Transaction,
+        expected_result: &[u8],
+    ) -> VdrResult<QuorumReport> {
+        let report = self.report(transaction, expected_result).await;
+        if report.reached {
+            Ok(report)
         } else {
-            Err(VdrError::QuorumNotReached(format!(
-                "Quorum not reached for transaction: {:?}",
-                transaction
-            )))
+            Err(VdrError::QuorumNotReached(
+                format!("Quorum not reached for transaction: {:?}", transaction),
+                report,
+            ))
         }
     }
+
+    /// Request the events from the quorum nodes and compare them with the expected ones.
+    ///   Query must have an explicit block range, so that all nodes return the same log set
+    #[logfn(Info)]
+    #[logfn_inputs(Debug)]
+    pub async fn report_events(
+        &self,
+        query: &EventQuery,
+        expected_result: &[EventLog],
+    ) -> QuorumReport {
+        let (sender, receiver) =
+            mpsc::channel::<(usize, QuorumNodeResponse<Vec<EventLog>>)>(self.nodes.len());
+
+        for (index, node) in self.nodes.iter().enumerate() {
+            #[cfg(feature = "wasm")]
+            {
+                async_std::task::block_on(QuorumHandler::send_query_with_retries(
+                    sender.clone(),
+                    index,
+                    node.client.clone(),
+                    query.clone(),
+                    self.request_retries,
+                    self.request_timeout,
+                    self.retry_interval,
+                ));
+            }
+
+            #[cfg(not(feature = "wasm"))]
+            {
+                async_std::task::spawn(QuorumHandler::send_query_with_retries(
+                    sender.clone(),
+                    index,
+                    node.client.clone(),
+                    query.clone(),
+                    self.request_retries,
+                    self.request_timeout,
+                    self.retry_interval,
+                ));
+            }
+        }
+
+        drop(sender);
+
+        self.wait_for_quorum(receiver, expected_result).await
+    }
+
+    /// Check that the events returned by the quorum nodes for the query match the expected ones.
+    ///   Query must have an explicit block range, so that all nodes return the same log set
+    #[logfn(Info)]
+    #[logfn_inputs(Debug)]
+    pub async fn check_events(
+        &self,
+        query: &EventQuery,
+        expected_result: &[EventLog],
+    ) -> VdrResult<QuorumReport> {
+        let report = self.report_events(query, expected_result).await;
+        if report.reached {
+            Ok(report)
+        } else {
+            Err(VdrError::QuorumNotReached(
+                format!("Quorum not reached for events query: {:?}", query),
+                report,
+            ))
+        }
+    }
+
+    /// Send the batch to every quorum node once and compare the node results with the expected ones
+    ///   by the request position. Events queries must have an explicit block range
+    ///
+    /// # Params
+    ///  - `requests`: [BatchRequest] - submitted batch requests
+    ///  - `expected_results`: results of the batch requests execution in the order of requests
+    ///
+    /// # Returns
+    ///  reports: [QuorumReport] - detailed results of the quorum nodes for every request
+    #[logfn(Info)]
+    #[logfn_inputs(Debug)]
+    pub async fn report_batch(
+        &self,
+        requests: &[BatchRequest],
+        expected_results: &[VdrResult<BatchResponse>],
+    ) -> Vec<QuorumReport> {
+        let (sender, receiver) = mpsc::channel::<(
+            usize,
+            QuorumNodeResponse<Vec<VdrResult<BatchResponse>>>,
+        )>(self.nodes.len());
+
+        for (index, node) in self.nodes.iter().enumerate() {
+            #[cfg(feature = "wasm")]
+            {
+                async_std::task::block_on(QuorumHandler::send_batch_with_retries(
+                    sender.clone(),
+                    index,
+                    node.client.clone(),
+                    requests.to_vec(),
+                    self.request_retries,
+                    self.request_timeout,
+                    self.retry_interval,
+                ));
+            }
+
+            #[cfg(not(feature = "wasm"))]
+            {
+                async_std::task::spawn(QuorumHandler::send_batch_with_retries(
+                    sender.clone(),
+                    index,
+                    node.client.clone(),
+                    requests.to_vec(),
+                    self.request_retries,
+                    self.request_timeout,
+                    self.retry_interval,
+                ));
+            }
+        }
+
+        drop(sender);
+
+        self.wait_for_batch_quorum(receiver, expected_results).await
+    }
 }
 
 impl Debug for QuorumHandler {
@@ -220,11 +716,20 @@ impl Debug for QuorumHandler {
         write!(
             f,
             r#"QuorumHandler {{
+            nodes: {:?},
+            threshold: {:?},
             request_retries: {},
             request_timeout: {:?},
             retry_interval: {:?}
         }}"#,
-            self.request_retries, self.request_timeout, self.retry_interval
+            self.nodes
+                .iter()
+                .map(|node| (node.address.as_str(), node.weight))
+                .collect::<Vec<_>>(),
+            self.threshold,
+            self.request_retries,
+            self.request_timeout,
+            self.retry_interval
         )
     }
 }
@@ -247,6 +752,8 @@ pub mod test {
                 request_retries: Some(DEFAULT_REQUEST_RETRIES),
                 request_timeout: Some(DEFAULT_REQUEST_TIMEOUT),
                 retry_interval: Some(DEFAULT_RETRY_INTERVAL),
+                threshold: None,
+                weights: None,
             }
         }
     }
@@ -254,7 +761,8 @@ pub mod test {
     impl Default for QuorumHandler {
         fn default() -> Self {
             QuorumHandler {
-                clients: vec![],
+                nodes: vec![],
+                threshold: None,
                 request_retries: DEFAULT_REQUEST_RETRIES,
                 request_timeout: Duration::from_millis(DEFAULT_REQUEST_TIMEOUT),
                 retry_interval: Duration::from_millis(DEFAULT_RETRY_INTERVAL),
@@ -262,6 +770,18 @@ pub mod test {
         }
     }
 
+    fn quorum_nodes(clients: Vec<Arc<Box<dyn Client>>>) -> Vec<QuorumNode> {
+        clients
+            .into_iter()
+            .enumerate()
+            .map(|(index, client)| QuorumNode {
+                address: format!("node{}", index + 1),
+                weight: DEFAULT_NODE_WEIGHT,
+                client,
+            })
+            .collect()
+    }
+
     const TIMEOUT_TIME: u64 = 1000;
     const RETRIES: u8 = 5;
 
@@ -332,10 +852,16 @@ pub mod test {
             let client1 = mock_client(&TXN_HASH, Some(WRITE_TRANSACTION.clone()));
             let client2 = mock_client(&TXN_HASH, Some(WRITE_TRANSACTION.clone()));
             let quorum = QuorumHandler {
-                clients: vec![client1, client2],
+                nodes: quorum_nodes(vec![client1, client2]),
                 ..QuorumHandler::default()
             };
-            assert!(quorum.check(&WRITE_TRANSACTION, &TXN_HASH).await.unwrap());
+            assert!(
+                quorum
+                    .check(&WRITE_TRANSACTION, &TXN_HASH)
+                    .await
+                    .unwrap()
+                    .reached
+            );
         }
 
         #[async_std::test]
@@ -343,7 +869,7 @@ pub mod test {
             let client1 = mock_client(&TXN_HASH, None);
             let client2 = mock_client_sleep_before_return(&TXN_HASH, None, TIMEOUT_TIME + 3000);
             let quorum = QuorumHandler {
-                clients: vec![client1, client2],
+                nodes: quorum_nodes(vec![client1, client2]),
                 request_timeout: Duration::from_millis(TIMEOUT_TIME),
                 ..QuorumHandler::default()
             };
@@ -372,7 +898,7 @@ pub mod test {
                 }),
             );
             let quorum = QuorumHandler {
-                clients: vec![client1, client2, client3],
+                nodes: quorum_nodes(vec![client1, client2, client3]),
                 ..QuorumHandler::default()
             };
 
@@ -387,11 +913,17 @@ pub mod test {
             let client1 = mock_client_retries(&TXN_HASH, Some(WRITE_TRANSACTION.clone()), RETRIES);
             let client2 = mock_client(&TXN_HASH, Some(WRITE_TRANSACTION.clone()));
             let quorum = QuorumHandler {
-                clients: vec![client1, client2],
+                nodes: quorum_nodes(vec![client1, client2]),
                 request_retries: RETRIES,
                 ..QuorumHandler::default()
             };
-            assert!(quorum.check(&WRITE_TRANSACTION, &TXN_HASH).await.unwrap());
+            assert!(
+                quorum
+                    .check(&WRITE_TRANSACTION, &TXN_HASH)
+                    .await
+                    .unwrap()
+                    .reached
+            );
         }
     }
 
@@ -474,10 +1006,16 @@ pub mod test {
             let client1 = mock_client(READ_TRANSACTION.clone(), Ok(RESPONSE.clone()));
             let client2 = mock_client(READ_TRANSACTION.clone(), Ok(RESPONSE.clone()));
             let quorum = QuorumHandler {
-                clients: vec![client1, client2],
+                nodes: quorum_nodes(vec![client1, client2]),
                 ..QuorumHandler::default()
             };
-            assert!(quorum.check(&READ_TRANSACTION, &RESPONSE).await.unwrap());
+            assert!(
+                quorum
+                    .check(&READ_TRANSACTION, &RESPONSE)
+                    .await
+                    .unwrap()
+                    .reached
+            );
         }
 
         #[async_std::test]
@@ -492,7 +1030,7 @@ pub mod test {
                 TIMEOUT_TIME + 3000,
             );
             let quorum = QuorumHandler {
-                clients: vec![client1, client2],
+                nodes: quorum_nodes(vec![client1, client2]),
                 request_timeout: Duration::from_millis(TIMEOUT_TIME),
                 ..QuorumHandler::default()
             };
@@ -509,7 +1047,7 @@ pub mod test {
             let client2 = mock_client(READ_TRANSACTION.clone(), Ok(vec![1, 1, 1, 2]));
             let client3 = mock_client(READ_TRANSACTION.clone(), Ok(vec![1, 1, 1, 3]));
             let quorum = QuorumHandler {
-                clients: vec![client1, client2, client3],
+                nodes: quorum_nodes(vec![client1, client2, client3]),
                 ..QuorumHandler::default()
             };
             let _err = quorum
@@ -524,11 +1062,271 @@ pub mod test {
                 mock_client_retries(READ_TRANSACTION.clone(), Ok(RESPONSE.clone()), RETRIES);
             let client2 = mock_client(READ_TRANSACTION.clone(), Ok(RESPONSE.clone()));
             let quorum = QuorumHandler {
-                clients: vec![client1, client2],
+                nodes: quorum_nodes(vec![client1, client2]),
                 request_retries: RETRIES,
                 ..QuorumHandler::default()
             };
-            assert!(quorum.check(&READ_TRANSACTION, &RESPONSE).await.unwrap());
+            assert!(
+                quorum
+                    .check(&READ_TRANSACTION, &RESPONSE)
+                    .await
+                    .unwrap()
+                    .reached
+            );
+        }
+    }
+
+    #[cfg(test)]
+    mod events_quorum_test {
+        use super::*;
+        use crate::{Address, Block};
+        use ethereum_types::H256;
+
+        static QUERY: Lazy<EventQuery> = Lazy::new(|| EventQuery {
+            addresses: vec![Address::from("0xf0e2db6c8dc6c681bb5d6ad121a107f300e9b2b5")],
+            from_block: Some(Block::from(1)),
+            to_block: Some(Block::from(10)),
+            topics: vec![],
+        });
+
+        static EVENTS: Lazy<Vec<EventLog>> = Lazy::new(|| {
+            vec![EventLog::new(
+                vec![H256::from_low_u64_be(1).as_bytes().to_vec()],
+                vec![1, 1, 1, 1],
+                5,
+            )]
+        });
+
+        fn mock_client(expected_output: VdrResult<Vec<EventLog>>) -> Arc<Box<dyn Client>> {
+            let mut mock_client = MockClient::new();
+            mock_client
+                .expect_query_events()
+                .with(eq(QUERY.clone()))
+                .returning(move |_| expected_output.clone());
+
+            Arc::new(Box::new(mock_client))
+        }
+
+        #[async_std::test]
+        async fn test_quorum_check_positive_case() {
+            let client1 = mock_client(Ok(EVENTS.clone()));
+            let client2 = mock_client(Ok(EVENTS.clone()));
+            let quorum = QuorumHandler {
+                nodes: quorum_nodes(vec![client1, client2]),
+                ..QuorumHandler::default()
+            };
+            assert!(quorum.check_events(&QUERY, &EVENTS).await.unwrap().reached);
+        }
+
+        #[async_std::test]
+        async fn test_quorum_check_not_reached() {
+            let client1 = mock_client(Ok(EVENTS.clone()));
+            let client2 = mock_client(Ok(vec![]));
+            let client3 = mock_client(Ok(vec![EventLog {
+                block: Block::from(6),
+                ..EVENTS[0].clone()
+            }]));
+            let quorum = QuorumHandler {
+                nodes: quorum_nodes(vec![client1, client2, client3]),
+                ..QuorumHandler::default()
+            };
+            let err = quorum.check_events(&QUERY, &EVENTS).await.unwrap_err();
+            match err {
+                VdrError::QuorumNotReached(_, report) => {
+                    assert!(!report.reached);
+                    assert_eq!(1, report.agreed_weight);
+                    assert_eq!(2, report.disagreed().len());
+                }
+                err => panic!("Unexpected error: {:?}", err),
+            }
+        }
+    }
+
+    #[cfg(test)]
+    mod batch_quorum_test {
+        use super::*;
+        use crate::{Address, Block};
+        use ethereum_types::H256;
+
+        static REQUESTS: Lazy<Vec<BatchRequest>> = Lazy::new(|| {
+            vec![
+                BatchRequest::Call(Transaction {
+                    type_: TransactionType::Read,
+                    ..Transaction::default()
+                }),
+                BatchRequest::Events(EventQuery {
+                    addresses: vec![Address::from("0xf0e2db6c8dc6c681bb5d6ad121a107f300e9b2b5")],
+                    from_block: Some(Block::from(1)),
+                    to_block: Some(Block::from(10)),
+                    topics: vec![],
+                }),
+            ]
+        });
+
+        static EVENTS: Lazy<Vec<EventLog>> = Lazy::new(|| {
+            vec![EventLog::new(
+                vec![H256::from_low_u64_be(1).as_bytes().to_vec()],
+                vec![1, 1, 1, 1],
+                5,
+            )]
+        });
+
+        fn mock_client(call: Vec<u8>, events: Vec<EventLog>) -> Arc<Box<dyn Client>> {
+            let mut mock_client = MockClient::new();
+            mock_client
+                .expect_submit_batch()
+                .with(eq(REQUESTS.clone()))
+                .times(1)
+                .returning(move |_| {
+                    Ok(vec![
+                        Ok(BatchResponse::Call(call.clone())),
+                        Ok(BatchResponse::Events(events.clone())),
+                    ])
+                });
+
+            Arc::new(Box::new(mock_client))
+        }
+
+        #[async_std::test]
+        async fn test_quorum_report_batch() {
+            let client1 = mock_client(vec![1], EVENTS.clone());
+            let client2 = mock_client(vec![1], vec![]);
+            let client3 = mock_client(vec![2], vec![]);
+            let quorum = QuorumHandler {
+                nodes: quorum_nodes(vec![client1, client2, client3]),
+                ..QuorumHandler::default()
+            };
+            let expected_results = vec![
+                Ok(BatchResponse::Call(vec![1])),
+                Ok(BatchResponse::Events(EVENTS.clone())),
+            ];
+
+            let reports = quorum.report_batch(&REQUESTS, &expected_results).await;
+
+            assert_eq!(2, reports.len());
+            assert!(reports[0].reached);
+            assert_eq!(2, reports[0].agreed_weight);
+            assert!(!reports[1].reached);
+            assert_eq!(1, reports[1].agreed_weight);
+            assert_eq!(2, reports[1].disagreed().len());
+        }
+    }
+
+    #[cfg(test)]
+    mod quorum_policy_test {
+        use super::*;
+        use rstest::rstest;
+
+        static READ_TRANSACTION: Lazy<Transaction> = Lazy::new(|| Transaction {
+            type_: TransactionType::Read,
+            ..Transaction::default()
+        });
+
+        static RESPONSE: Lazy<Vec<u8>> = Lazy::new(|| vec![1, 1, 1, 1]);
+
+        fn mock_client(expected_output: VdrResult<Vec<u8>>) -> Arc<Box<dyn Client>> {
+            let mut mock_client = MockClient::new();
+            mock_client
+                .expect_call_transaction()
+                .returning(move |_, _| expected_output.clone());
+
+            Arc::new(Box::new(mock_client))
+        }
+
+        fn quorum(threshold: Option<QuorumThreshold>, weights: &[u64]) -> QuorumHandler {
+            let clients = vec![
+                mock_client(Ok(RESPONSE.clone())),
+                mock_client(Ok(vec![1, 1, 1, 2])),
+                mock_client(Err(VdrError::ClientNodeUnreachable)),
+            ];
+            let mut nodes = quorum_nodes(clients);
+            for (node, weight) in nodes.iter_mut().zip(weights) {
+                node.weight = *weight;
+            }
+            QuorumHandler {
+                nodes,
+                threshold,
+                request_retries: 2,
+                retry_interval: Duration::from_millis(10),
+                ..QuorumHandler::default()
+            }
+        }
+
+        #[rstest]
+        #[case::default_threshold(None, &[1, 1, 1], false)]
+        #[case::default_threshold_weighted(None, &[2, 1, 1], true)]
+        #[case::single_node(Some(QuorumThreshold::Count(1)), &[1, 1, 1], true)]
+        #[case::absolute_count(Some(QuorumThreshold::Count(2)), &[1, 1, 1], false)]
+        #[case::fraction(Some(QuorumThreshold::Fraction(0.5)), &[1, 1, 1], false)]
+        #[case::all(Some(QuorumThreshold::All), &[1, 1, 1], false)]
+        #[case::weighted_node(Some(QuorumThreshold::Count(3)), &[3, 1, 1], true)]
+        #[case::weighted_fraction(Some(QuorumThreshold::Fraction(0.5)), &[2, 1, 1], true)]
+        #[async_std::test]
+        async fn test_quorum_threshold(
+            #[case] threshold: Option<QuorumThreshold>,
+            #[case] weights: &[u64],
+            #[case] expected_reached: bool,
+        ) {
+            let quorum = quorum(threshold, weights);
+            let report = quorum.report(&READ_TRANSACTION, &RESPONSE).await;
+            assert_eq!(expected_reached, report.reached);
+            assert_eq!(
+                expected_reached,
+                quorum.check(&READ_TRANSACTION, &RESPONSE).await.is_ok()
+            );
+        }
+
+        #[async_std::test]
+        async fn test_quorum_report() {
+            let quorum = quorum(Some(QuorumThreshold::All), &[1, 1, 1]);
+            let report = quorum.report(&READ_TRANSACTION, &RESPONSE).await;
+
+            assert!(!report.reached);
+            assert_eq!(3, report.required_weight);
+            assert_eq!(1, report.agreed_weight);
+            assert_eq!(
+                QuorumNodeStatus::Agreed {
+                    response: format!("{:?}", RESPONSE.clone())
+                },
+                report.nodes[0].status
+            );
+            assert_eq!(
+                QuorumNodeStatus::Disagreed {
+                    response: format!("{:?}", vec![1, 1, 1, 2])
+                },
+                report.nodes[1].status
+            );
+            assert_eq!(
+                QuorumNodeStatus::Errored {
+                    error: VdrError::ClientNodeUnreachable.to_string()
+                },
+                report.nodes[2].status
+            );
+            assert_eq!(1, report.agreed().len());
+            assert_eq!(1, report.disagreed().len());
+            assert_eq!(1, report.errored().len());
+            assert!(report.timed_out().is_empty());
+        }
+
+        #[rstest]
+        #[case::count_exceeds_total_weight(Some(QuorumThreshold::Count(5)), None)]
+        #[case::zero_count(Some(QuorumThreshold::Count(0)), None)]
+        #[case::invalid_fraction(Some(QuorumThreshold::Fraction(1.5)), None)]
+        #[case::unknown_weighted_node(
+            None,
+            Some(HashMap::from([("http://127.0.0.1:9999".to_string(), 2)]))
+        )]
+        fn test_invalid_quorum_config(
+            #[case] threshold: Option<QuorumThreshold>,
+            #[case] weights: Option<HashMap<String, u64>>,
+        ) {
+            let config = QuorumConfig {
+                threshold,
+                weights,
+                ..QuorumConfig::default()
+            };
+            let err = QuorumHandler::new(config).unwrap_err();
+            assert!(matches!(err, VdrError::CommonInvalidData(_)));
         }
     }
 }
@@ -0,0 +1,252 @@
+// Copyright (c) 2024 DSR Corporation, Denver, Colorado.
+// https://www.dsr-corporation.com
+// SPDX-License-Identifier: Apache-2.0
+
+use std::{collections::VecDeque, time::Duration};
+
+use futures::StreamExt;
+use log::{trace, warn};
+
+use crate::{
+    client::EventStream,
+    types::{Block, EventLog, EventQuery},
+    LedgerClient, VdrResult,
+};
+
+/// Interval between `eth_getLogs` requests when the connected node does not support subscriptions
+pub const EVENT_POLL_INTERVAL: u64 = 2000;
+
+/// State of a live event subscription.
+///
+/// Events are delivered from the `eth_subscribe` stream when the client supports it (WebSocket/IPC)
+/// and from periodic `eth_getLogs` requests otherwise.
+/// When the subscription stream ends (connection lost), the subscription is re-established and
+/// all events emitted since the last seen block are queried, so no events are missed.
+struct EventSubscription<'a> {
+    client: &'a LedgerClient,
+    query: EventQuery,
+    poll_interval: Duration,
+    // next block to request missed events from
+    cursor: Option<u64>,
+    // events already delivered from the `cursor` block
+    seen: Vec<EventLog>,
+    pending: VecDeque<VdrResult<EventLog>>,
+    subscription: Option<EventStream<'a, EventLog>>,
+    polling: bool,
+}
+
+impl<'a> EventSubscription<'a> {
+    fn new(client: &'a LedgerClient, query: EventQuery, poll_interval: Duration) -> Self {
+        EventSubscription {
+            client,
+            cursor: query.from_block.as_ref().map(Block::value),
+            query,
+            poll_interval,
+            seen: Vec::new(),
+            pending: VecDeque::new(),
+            subscription: None,
+            polling: false,
+        }
+    }
+
+    // Check that the event has not been delivered yet and move the cursor
+    fn accept(&mut self, log: &EventLog) -> bool {
+        let block = log.block.value();
+        match self.cursor {
+            Some(cursor) if block < cursor => return false,
+            Some(cursor) if block == cursor => {
+                if self.seen.contains(log) {
+                    return false;
+                }
+            }
+            _ => {
+                self.cursor = Some(block);
+                self.seen.clear();
+            }
+        }
+        self.seen.push(log.clone());
+        true
+    }
+
+    // (Re)establish the subscription and query the events missed since the last seen block
+    async fn resume(&mut self) {
+        if self.polling {
+            async_std::task::sleep(self.poll_interval).await;
+        }
+
+        // subscribe first so that events emitted during the catch up query are not lost
+        match self.client.open_event_subscription(&self.query).await {
+            Ok(subscription) => {
+                trace!("Subscribed to events for query: {:?}", self.query);
+                self.subscription = Some(subscription);
+                self.polling = false;
+            }
+            Err(err) => {
+                trace!(
+                    "Unable to subscribe to events: {}. Falling back to polling",
+                    err
+                );
+                self.polling = true;
+            }
+        }
+
+        let latest_block = match self.client.get_block(None).await {
+            Ok(block) => block.number,
+            Err(err) => {
+                warn!("Error: {} during getting the latest block", err);
+                self.pending.push_back(Err(err));
+                return;
+            }
+        };
+
+        let from_block = match self.cursor {
+            Some(cursor) if cursor <= latest_block => cursor,
+            Some(_) => return,
+            None => {
+                // subscription without start block delivers new events only
+                self.cursor = Some(latest_block + 1);
+                return;
+            }
+        };
+
+        let query = EventQuery {
+            from_block: Some(Block::from(from_block)),
+            to_block: Some(Block::from(latest_block)),
+            ..self.query.clone()
+        };
+        match self.client.query_events(&query).await {
+            Ok(logs) => {
+                for log in logs {
+                    if self.accept(&log) {
+                        self.pending.push_back(Ok(log));
+                    }
+                }
+                // all events up to the latest block are delivered
+                self.cursor = Some(latest_block + 1);
+                self.seen.clear();
+            }
+            Err(err) => {
+                warn!("Error: {} during querying missed events", err);
+                self.pending.push_back(Err(err));
+            }
+        }
+    }
+
+    async fn next(&mut self) -> VdrResult<EventLog> {
+        loop {
+            if let Some(event) = self.pending.pop_front() {
+                return event;
+            }
+
+            match self.subscription.as_mut() {
+                Some(subscription) => match subscription.next().await {
+                    Some(Ok(log)) => {
+                        if self.accept(&log) {
+                            return Ok(log);
+                        }
+                    }
+                    Some(Err(err)) => {
+                        warn!("Error: {} received from event subscription", err);
+                        self.subscription = None;
+                    }
+                    None => {
+                        trace!("Event subscription closed. Reconnecting");
+                        self.subscription = None;
+                    }
+                },
+                None => self.resume().await,
+            }
+        }
+    }
+}
+
+/// Build a stream of events matching to the query
+pub(crate) fn subscribe<'a>(
+    client: &'a LedgerClient,
+    query: EventQuery,
+    poll_interval: Duration,
+) -> EventStream<'a, EventLog> {
+    let subscription = EventSubscription::new(client, query, poll_interval);
+    let stream = futures::stream::unfold(subscription, |mut subscription| async move {
+        let event = subscription.next().await;
+        Some((event, subscription))
+    });
+    Box::pin(stream)
+}
+
+#[cfg(test)]
+pub mod test {
+    use super::*;
+    use crate::{
+        client::{client::test::mock_custom_client, MockClient},
+        BlockDetails, VdrError,
+    };
+    use ethereum_types::H256;
+
+    const LATEST_BLOCK: u64 = 10;
+
+    fn event_log(block: u64) -> EventLog {
+        EventLog::new(
+            vec![H256::from_low_u64_be(block).as_bytes().to_vec()],
+            vec![1, 2, 3],
+            block,
+        )
+    }
+
+    fn event_query(from_block: Option<u64>) -> EventQuery {
+        EventQuery {
+            addresses: vec![],
+            from_block: from_block.map(Block::from),
+            to_block: None,
+            topics: vec![],
+        }
+    }
+
+    fn mock_client() -> MockClient {
+        let mut client = MockClient::new();
+        client.expect_get_block().returning(|_| {
+            Ok(BlockDetails {
+                number: LATEST_BLOCK,
+                timestamp: 0,
+            })
+        });
+        client
+    }
+
+    #[async_std::test]
+    async fn subscribe_events_polling_fallback_test() {
+        let mut client = mock_client();
+        client.expect_subscribe_events().returning(|_| {
+            Err(VdrError::ClientInvalidState(
+                "Event subscriptions are not supported by the client".to_string(),
+            ))
+        });
+        client
+            .expect_query_events()
+            .returning(|_| Ok(vec![event_log(3), event_log(5)]));
+        let client = mock_custom_client(Box::new(client));
+
+        let mut stream = client.subscribe_events(&event_query(Some(1)));
+
+        assert_eq!(event_log(3), stream.next().await.unwrap().unwrap());
+        assert_eq!(event_log(5), stream.next().await.unwrap().unwrap());
+    }
+
+    #[async_std::test]
+    async fn subscribe_events_skips_already_delivered_events_test() {
+        let mut client = mock_client();
+        client.expect_subscribe_events().returning(|_| {
+            let events = vec![Ok(event_log(5)), Ok(event_log(12)), Ok(event_log(12))];
+            Ok(Box::pin(futures::stream::iter(events)) as EventStream<'static, EventLog>)
+        });
+        client
+            .expect_query_events()
+            .returning(|_| Ok(vec![event_log(5)]));
+        let client = mock_custom_client(Box::new(client));
+
+        let mut stream = client.subscribe_events(&event_query(Some(1)));
+
+        assert_eq!(event_log(5), stream.next().await.unwrap().unwrap());
+        assert_eq!(event_log(12), stream.next().await.unwrap().unwrap());
+    }
+}
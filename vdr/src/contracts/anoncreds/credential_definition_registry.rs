@@ -5,7 +5,7 @@
 use log_derive::{logfn, logfn_inputs};
 
 use crate::{
-    client::LedgerClient,
+    client::{cache::CacheKey, LedgerClient},
     contracts::anoncreds::types::{
         credential_definition::{CredentialDefinition, CredentialDefinitionRecord},
         credential_definition_id::{CredentialDefinitionId, ParsedCredentialDefinitionId},
@@ -141,6 +141,42 @@ pub async fn resolve_credential_definition(
     client: &LedgerClient,
     id: &CredentialDefinitionId,
 ) -> VdrResult<CredentialDefinition> {
+    if let Some(cred_def) = get_cached_credential_definition(client, id)? {
+        return Ok(cred_def);
+    }
+
+    let transaction = build_resolve_credential_definition_transaction(client, id).await?;
+    let response = client.submit_transaction(&transaction).await?;
+    process_resolve_credential_definition_response(client, id, &response)
+}
+
+/// Single step function to resolve multiple Credential Definitions with a single batch request to the ledger
+///
+/// # Params
+/// - `client`: [LedgerClient] - client connected to the network where contract will be executed
+/// - `ids`: [Vec] - list of ids of credential definitions to resolve
+///
+/// # Returns
+///   credential_definitions: [Vec] - results of Credential Definitions resolution in the order of requested ids
+pub async fn resolve_credential_definitions(
+    client: &LedgerClient,
+    ids: &[CredentialDefinitionId],
+) -> VdrResult<Vec<VdrResult<CredentialDefinition>>> {
+    client
+        .resolve_batch(
+            ids,
+            |id| get_cached_credential_definition(client, id),
+            |id| build_resolve_credential_definition_transaction(client, id),
+            |id, response| process_resolve_credential_definition_response(client, id, response),
+        )
+        .await
+}
+
+/// Validate the requested Credential Definition id and get the Credential Definition from the client cache if it is enabled
+fn get_cached_credential_definition(
+    client: &LedgerClient,
+    id: &CredentialDefinitionId,
+) -> VdrResult<Option<CredentialDefinition>> {
     let parsed_id = ParsedCredentialDefinitionId::try_from(id)?;
     match (parsed_id.network.as_ref(), client.network()) {
         (Some(schema_network), Some(client_network)) if schema_network != client_network => {
@@ -149,15 +185,24 @@ pub async fn resolve_credential_definition(
         _ => {}
     };
 
-    let transaction = build_resolve_credential_definition_transaction(client, id).await?;
-    let response = client.submit_transaction(&transaction).await?;
+    Ok(client
+        .cache()
+        .and_then(|cache| cache.get(&CacheKey::CredentialDefinition(id))))
+}
+
+/// Parse and validate the Credential Definition returned by the ledger and put it into the client cache
+fn process_resolve_credential_definition_response(
+    client: &LedgerClient,
+    id: &CredentialDefinitionId,
+    response: &[u8],
+) -> VdrResult<CredentialDefinition> {
     if response.is_empty() {
         return Err(VdrError::ClientInvalidResponse(format!(
             "Credential Definition not found for id: {:?}",
             id
         )));
     }
-    let cred_def_record = parse_resolve_credential_definition_result(client, &response)?;
+    let cred_def_record = parse_resolve_credential_definition_result(client, response)?;
 
     let cred_def_id = cred_def_record.credential_definition.id();
     if &cred_def_id != id {
@@ -168,6 +213,12 @@ pub async fn resolve_credential_definition(
         )));
     }
 
+    if let Some(cache) = client.cache() {
+        cache.set(
+            &CacheKey::CredentialDefinition(id),
+            &cred_def_record.credential_definition,
+        );
+    }
     Ok(cred_def_record.credential_definition)
 }
 
@@ -246,6 +297,7 @@ pub mod test {
                 ],
                 signature: None,
                 hash: None,
+                ..Transaction::default()
             };
             assert_eq!(expected_transaction, transaction);
         }
@@ -306,6 +358,7 @@ pub mod test {
                 ],
                 signature: None,
                 hash: None,
+                ..Transaction::default()
             };
             assert_eq!(expected_transaction, transaction);
         }
@@ -5,11 +5,12 @@
 use std::collections::HashSet;
 
 use chrono::Utc;
+use futures::StreamExt;
 use log::debug;
 use log_derive::{logfn, logfn_inputs};
 
 use crate::{
-    client::LedgerClient,
+    client::{cache::CacheKey, EventStream, LedgerClient},
     contracts::anoncreds::types::{
         revocation_registry_definition::RevocationRegistryDefinition,
         revocation_registry_definition_id::RevocationRegistryDefinitionId,
@@ -345,6 +346,44 @@ pub async fn resolve_revocation_registry_definition(
     client: &LedgerClient,
     id: &RevocationRegistryDefinitionId,
 ) -> VdrResult<RevocationRegistryDefinition> {
+    if let Some(rev_reg_def) = get_cached_revocation_registry_definition(client, id)? {
+        return Ok(rev_reg_def);
+    }
+
+    let transaction = build_resolve_revocation_registry_definition_transaction(client, id).await?;
+    let response = client.submit_transaction(&transaction).await?;
+    process_resolve_revocation_registry_definition_response(client, id, &response)
+}
+
+/// Single step function to resolve multiple Revocation Registry Definitions with a single batch request to the ledger
+///
+/// # Params
+/// - `client`: [LedgerClient] - client connected to the network where contract will be executed
+/// - `ids`: [Vec] - list of ids of Revocation Registry Definitions to resolve
+///
+/// # Returns
+///   revocation_registry_definitions: [Vec] - results of Revocation Registry Definitions resolution in the order of requested ids
+pub async fn resolve_revocation_registry_definitions(
+    client: &LedgerClient,
+    ids: &[RevocationRegistryDefinitionId],
+) -> VdrResult<Vec<VdrResult<RevocationRegistryDefinition>>> {
+    client
+        .resolve_batch(
+            ids,
+            |id| get_cached_revocation_registry_definition(client, id),
+            |id| build_resolve_revocation_registry_definition_transaction(client, id),
+            |id, response| {
+                process_resolve_revocation_registry_definition_response(client, id, response)
+            },
+        )
+        .await
+}
+
+/// Validate the requested Revocation Registry Definition id and get the definition from the client cache if it is enabled
+fn get_cached_revocation_registry_definition(
+    client: &LedgerClient,
+    id: &RevocationRegistryDefinitionId,
+) -> VdrResult<Option<RevocationRegistryDefinition>> {
     let parsed_id = ParsedRevocationRegistryDefinitionId::try_from(id)?;
     match (parsed_id.network.as_ref(), client.network()) {
         (Some(schema_network), Some(client_network)) if schema_network != client_network => {
@@ -353,16 +392,24 @@ pub async fn resolve_revocation_registry_definition(
         _ => {}
     };
 
-    let transaction = build_resolve_revocation_registry_definition_transaction(client, id).await?;
-    let response = client.submit_transaction(&transaction).await?;
+    Ok(client
+        .cache()
+        .and_then(|cache| cache.get(&CacheKey::RevocationRegistryDefinition(id))))
+}
+
+/// Parse and validate the Revocation Registry Definition returned by the ledger and put it into the client cache
+fn process_resolve_revocation_registry_definition_response(
+    client: &LedgerClient,
+    id: &RevocationRegistryDefinitionId,
+    response: &[u8],
+) -> VdrResult<RevocationRegistryDefinition> {
     if response.is_empty() {
         return Err(VdrError::ClientInvalidResponse(format!(
             "Revocation Registry Definition not found for id: {:?}",
             id
         )));
     }
-    let rev_reg_def_record =
-        parse_resolve_revocation_registry_definition_result(client, &response)?;
+    let rev_reg_def_record = parse_resolve_revocation_registry_definition_result(client, response)?;
 
     let rev_reg_def_id = rev_reg_def_record.revocation_registry_definition.id();
     if &rev_reg_def_id != id {
@@ -373,6 +420,12 @@ pub async fn resolve_revocation_registry_definition(
         )));
     }
 
+    if let Some(cache) = client.cache() {
+        cache.set(
+            &CacheKey::RevocationRegistryDefinition(id),
+            &rev_reg_def_record.revocation_registry_definition,
+        );
+    }
     Ok(rev_reg_def_record.revocation_registry_definition)
 }
 
@@ -731,6 +784,29 @@ async fn receive_revocation_registry_history(
     Ok(history)
 }
 
+/// Subscribe to Revocation Registry associated events
+///
+/// # Params
+/// - `client`: [LedgerClient] - client connected to the network where contract will be executed
+/// - `id`: [RevocationRegistryDefinitionId] - id of Revocation Registry Definition to watch
+/// - `from_block`: [Block] - start block. If set, past events starting from this block are delivered first
+///
+/// # Returns
+///   stream: [EventStream] - stream of parsed Revocation Registry events
+#[logfn_inputs(Debug)]
+pub async fn subscribe_revocation_registry_events<'a>(
+    client: &'a LedgerClient,
+    id: &RevocationRegistryDefinitionId,
+    from_block: Option<&Block>,
+) -> VdrResult<EventStream<'a, RevocationRegistryEvents>> {
+    let query =
+        build_get_revocation_registry_entry_events_query(client, id, from_block, None).await?;
+    let stream = client
+        .subscribe_events(&query)
+        .map(move |log| log.and_then(|log| parse_revocation_registry_event_response(client, &log)));
+    Ok(Box::pin(stream))
+}
+
 /// Build event query to obtain revocation registry entry associated events from the ledger
 ///
 /// #Params
@@ -753,7 +829,7 @@ async fn build_get_revocation_registry_entry_events_query(
         .set_contract(CONTRACT_NAME)
         .set_from_block(from_block.cloned())
         .set_to_block(to_block.cloned())
-        .set_event_filer(rev_reg_def_id.without_network().unwrap().to_filter())
+        .set_event_filter(rev_reg_def_id.without_network().unwrap().to_filter())
         .build(client)
 }
 
@@ -767,7 +843,7 @@ async fn build_get_revocation_registry_entry_events_query(
 ///   event: [RevocationRegistryEvents] Parsed Revocation Registry Event object
 #[logfn(Info)]
 #[logfn_inputs(Debug)]
-fn parse_revocation_registry_event_response(
+pub(crate) fn parse_revocation_registry_event_response(
     client: &LedgerClient,
     event: &EventLog,
 ) -> VdrResult<RevocationRegistryEvents> {
@@ -906,6 +982,7 @@ pub mod test {
                 ],
                 signature: None,
                 hash: None,
+                ..Transaction::default()
             };
             assert_eq!(expected_transaction, transaction);
         }
@@ -983,6 +1060,7 @@ pub mod test {
                 ],
                 signature: None,
                 hash: None,
+                ..Transaction::default()
             };
             assert_eq!(expected_transaction, transaction);
         }
@@ -1059,6 +1137,7 @@ pub mod test {
                 ],
                 signature: None,
                 hash: None,
+                ..Transaction::default()
             };
             assert_eq!(expected_transaction, transaction);
         }
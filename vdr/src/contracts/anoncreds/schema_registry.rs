@@ -5,7 +5,7 @@
 use log_derive::{logfn, logfn_inputs};
 
 use crate::{
-    client::LedgerClient,
+    client::{cache::CacheKey, LedgerClient},
     contracts::anoncreds::types::{
         schema::{Schema, SchemaRecord},
         schema_id::{ParsedSchemaId, SchemaId},
@@ -133,6 +133,41 @@ pub fn parse_resolve_schema_result(client: &LedgerClient, bytes: &[u8]) -> VdrRe
 #[logfn(Info)]
 #[logfn_inputs(Debug)]
 pub async fn resolve_schema(client: &LedgerClient, id: &SchemaId) -> VdrResult<Schema> {
+    if let Some(schema) = get_cached_schema(client, id)? {
+        return Ok(schema);
+    }
+
+    let transaction = build_resolve_schema_transaction(client, id).await?;
+    let response = client.submit_transaction(&transaction).await?;
+    process_resolve_schema_response(client, id, &response)
+}
+
+/// Single step function to resolve multiple Schemas with a single batch request to the ledger
+///
+/// # Params
+/// - `client`: [LedgerClient] - client connected to the network where contract will be executed
+/// - `ids`: [Vec] - list of ids of schemas to resolve
+///
+/// # Returns
+///   schemas: [Vec] - results of Schemas resolution in the order of requested ids
+#[logfn(Info)]
+#[logfn_inputs(Debug)]
+pub async fn resolve_schemas(
+    client: &LedgerClient,
+    ids: &[SchemaId],
+) -> VdrResult<Vec<VdrResult<Schema>>> {
+    client
+        .resolve_batch(
+            ids,
+            |id| get_cached_schema(client, id),
+            |id| build_resolve_schema_transaction(client, id),
+            |id, response| process_resolve_schema_response(client, id, response),
+        )
+        .await
+}
+
+/// Validate the requested Schema id and get the Schema from the client cache if it is enabled
+fn get_cached_schema(client: &LedgerClient, id: &SchemaId) -> VdrResult<Option<Schema>> {
     let parsed_id = ParsedSchemaId::try_from(id)?;
     match (parsed_id.network.as_ref(), client.network()) {
         (Some(schema_network), Some(client_network)) if schema_network != client_network => {
@@ -144,15 +179,24 @@ pub async fn resolve_schema(client: &LedgerClient, id: &SchemaId) -> VdrResult<S
         _ => {}
     };
 
-    let transaction = build_resolve_schema_transaction(client, id).await?;
-    let response = client.submit_transaction(&transaction).await?;
+    Ok(client
+        .cache()
+        .and_then(|cache| cache.get(&CacheKey::Schema(id))))
+}
+
+/// Parse and validate the Schema returned by the ledger and put it into the client cache
+fn process_resolve_schema_response(
+    client: &LedgerClient,
+    id: &SchemaId,
+    response: &[u8],
+) -> VdrResult<Schema> {
     if response.is_empty() {
         return Err(VdrError::ClientInvalidResponse(format!(
             "Schema not found for id: {:?}",
             id
         )));
     }
-    let schema_record = parse_resolve_schema_result(client, &response)?;
+    let schema_record = parse_resolve_schema_result(client, response)?;
     let schema = schema_record.schema;
 
     let schema_id = schema.id();
@@ -164,6 +208,9 @@ pub async fn resolve_schema(client: &LedgerClient, id: &SchemaId) -> VdrResult<S
         )));
     }
 
+    if let Some(cache) = client.cache() {
+        cache.set(&CacheKey::Schema(id), &schema);
+    }
     Ok(schema)
 }
 
@@ -223,6 +270,7 @@ pub mod test {
                 ],
                 signature: None,
                 hash: None,
+                ..Transaction::default()
             };
             assert_eq!(expected_transaction, transaction);
         }
@@ -273,6 +321,7 @@ pub mod test {
                 ],
                 signature: None,
                 hash: None,
+                ..Transaction::default()
             };
             assert_eq!(expected_transaction, transaction);
         }
@@ -305,4 +354,91 @@ pub mod test {
             assert_eq!(expected_schema, parsed_schema.schema);
         }
     }
+
+    mod resolve_schema {
+        use super::*;
+        use crate::client::{client::test::mock_cached_client, MockClient};
+
+        #[async_std::test]
+        async fn resolve_schema_uses_cache_test() {
+            let mut client = MockClient::new();
+            client
+                .expect_call_transaction()
+                .times(1)
+                .returning(|_, _| Ok(resolve_schema_result()));
+            let client = mock_cached_client(Box::new(client));
+            let expected_schema = schema(&DID::from(TEST_ETHR_DID), Some(SCHEMA_NAME));
+
+            let first = resolve_schema(&client, &expected_schema.id())
+                .await
+                .unwrap();
+            let second = resolve_schema(&client, &expected_schema.id())
+                .await
+                .unwrap();
+
+            assert_eq!(expected_schema, first);
+            assert_eq!(expected_schema, second);
+        }
+    }
+
+    mod resolve_schemas {
+        use super::*;
+        use crate::{
+            client::{client::test::mock_cached_client, MockClient},
+            BatchResponse,
+        };
+
+        #[async_std::test]
+        async fn resolve_schemas_test() {
+            let mut client = MockClient::new();
+            client
+                .expect_submit_batch()
+                .withf(|requests| requests.len() == 2)
+                .times(1)
+                .returning(|_| {
+                    Ok(vec![
+                        Ok(BatchResponse::Call(resolve_schema_result())),
+                        Ok(BatchResponse::Call(vec![])),
+                    ])
+                });
+            let client = mock_cached_client(Box::new(client));
+            let expected_schema = schema(&DID::from(TEST_ETHR_DID), Some(SCHEMA_NAME));
+            let missing_schema = schema(&DID::from(TEST_ETHR_DID), None);
+
+            let schemas = resolve_schemas(&client, &[expected_schema.id(), missing_schema.id()])
+                .await
+                .unwrap();
+
+            assert_eq!(2, schemas.len());
+            assert_eq!(Ok(expected_schema.clone()), schemas[0]);
+            assert!(matches!(
+                schemas[1],
+                Err(VdrError::ClientInvalidResponse(_))
+            ));
+
+            // resolved schema is cached so it is not requested in the batch again
+            let schemas = resolve_schemas(&client, &[expected_schema.id()])
+                .await
+                .unwrap();
+            assert_eq!(vec![Ok(expected_schema)], schemas);
+        }
+    }
+
+    fn resolve_schema_result() -> Vec<u8> {
+        vec![
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 32, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 64, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 101, 203, 143, 187, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 141, 123, 34, 105, 115, 115, 117, 101, 114,
+            73, 100, 34, 58, 34, 100, 105, 100, 58, 101, 116, 104, 114, 58, 116, 101, 115, 116,
+            110, 101, 116, 58, 48, 120, 102, 48, 101, 50, 100, 98, 54, 99, 56, 100, 99, 54, 99, 54,
+            56, 49, 98, 98, 53, 100, 54, 97, 100, 49, 50, 49, 97, 49, 48, 55, 102, 51, 48, 48, 101,
+            57, 98, 50, 98, 53, 34, 44, 34, 110, 97, 109, 101, 34, 58, 34, 70, 49, 68, 67, 108, 97,
+            70, 69, 122, 105, 51, 116, 34, 44, 34, 118, 101, 114, 115, 105, 111, 110, 34, 58, 34,
+            49, 46, 48, 46, 48, 34, 44, 34, 97, 116, 116, 114, 78, 97, 109, 101, 115, 34, 58, 91,
+            34, 70, 105, 114, 115, 116, 32, 78, 97, 109, 101, 34, 93, 125, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ]
+    }
 }
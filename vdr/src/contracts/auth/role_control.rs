@@ -5,9 +5,14 @@
 use crate::{
     client::LedgerClient,
     contracts::auth::{HasRole, Role},
-    error::VdrResult,
-    types::{Address, Transaction, TransactionBuilder, TransactionParser, TransactionType},
+    error::{VdrError, VdrResult},
+    types::{
+        Address, EventQuery, EventQueryBuilder, Transaction, TransactionBuilder, TransactionParser,
+        TransactionType,
+    },
+    Block,
 };
+use log::warn;
 use log_derive::{logfn, logfn_inputs};
 
 const CONTRACT_NAME: &str = "RoleControl";
@@ -16,6 +21,12 @@ const METHOD_REVOKE_ROLE: &str = "revokeRole";
 const METHOD_HAS_ROLE: &str = "hasRole";
 const METHOD_GET_ROLE: &str = "getRole";
 
+const EVENT_ROLE_ASSIGNED: &str = "RoleAssigned";
+const EVENT_ROLE_REVOKED: &str = "RoleRevoked";
+
+const EVENT_SIGNATURE_TOPIC: usize = 0;
+const EVENT_ACCOUNT_TOPIC: usize = 1;
+
 /// Build transaction to execute RoleControl.assignRole contract method to assign a role to an account
 ///
 /// # Params
@@ -123,6 +134,50 @@ pub async fn build_get_role_transaction(
         .await
 }
 
+/// Build event query to obtain role assignment and revocation events (RoleAssigned, RoleRevoked)
+///     of any of the given accounts from the ledger
+///
+/// #Params
+///  - `client`: [LedgerClient] - client connected to the network where contract will be executed
+///  - `accounts`: [Vec] - target accounts
+///  - `from_block`: [Block] - start block
+///  - `to_block`: [Block] - finish block
+///
+/// #Returns
+///   query: [EventQuery] - prepared event query to send
+#[logfn(Info)]
+#[logfn_inputs(Debug)]
+pub async fn build_get_role_events_query(
+    client: &LedgerClient,
+    accounts: &[Address],
+    from_block: Option<&Block>,
+    to_block: Option<&Block>,
+) -> VdrResult<EventQuery> {
+    if accounts.is_empty() {
+        let vdr_error = VdrError::CommonInvalidData(
+            "At least one account must be provided to query role events".to_string(),
+        );
+
+        warn!("Error: {} during building role events query", vdr_error);
+
+        return Err(vdr_error);
+    }
+
+    let contract = client.contract(CONTRACT_NAME)?;
+    let mut builder = EventQueryBuilder::new()
+        .set_contract(CONTRACT_NAME)
+        .set_from_block(from_block.cloned())
+        .set_to_block(to_block.cloned());
+    for event in [EVENT_ROLE_ASSIGNED, EVENT_ROLE_REVOKED] {
+        let signature = contract.event(event)?.signature();
+        builder = builder.add_topic(EVENT_SIGNATURE_TOPIC, hex::encode(signature.as_bytes()))?;
+    }
+    for account in accounts {
+        builder = builder.add_topic(EVENT_ACCOUNT_TOPIC, account.to_filter())?;
+    }
+    builder.build(client)
+}
+
 /// Parse the result of execution RoleControl.HasRole contract method to check an account has a role
 ///
 /// # Params
@@ -197,6 +252,7 @@ pub mod test {
                 data: expected_data,
                 signature: None,
                 hash: None,
+                ..Transaction::default()
             };
 
             assert_eq!(expected_transaction, transaction);
@@ -233,6 +289,7 @@ pub mod test {
                 data: expected_data,
                 signature: None,
                 hash: None,
+                ..Transaction::default()
             };
 
             assert_eq!(expected_transaction, transaction);
@@ -263,6 +320,7 @@ pub mod test {
                 data: expected_data,
                 signature: None,
                 hash: None,
+                ..Transaction::default()
             };
 
             assert_eq!(expected_transaction, transaction);
@@ -309,6 +367,7 @@ pub mod test {
                 data: expected_data,
                 signature: None,
                 hash: None,
+                ..Transaction::default()
             };
 
             assert_eq!(expected_transaction, transaction);
@@ -329,4 +388,59 @@ pub mod test {
             assert_eq!(expected_has_role, has_role);
         }
     }
+
+    mod build_get_role_events_query {
+        use super::*;
+
+        fn event_signature(client: &LedgerClient, event: &str) -> String {
+            hex::encode(
+                client
+                    .contract(CONTRACT_NAME)
+                    .unwrap()
+                    .event(event)
+                    .unwrap()
+                    .signature()
+                    .as_bytes(),
+            )
+        }
+
+        #[async_std::test]
+        async fn build_get_role_events_query_test() {
+            let client = mock_client();
+
+            let query = build_get_role_events_query(
+                &client,
+                &[TEST_ACCOUNT.clone(), TRUSTEE_ACCOUNT.clone()],
+                Some(&Block::from(1)),
+                None,
+            )
+            .await
+            .unwrap();
+
+            let expected_query = EventQuery {
+                addresses: vec![CONFIG.contracts.role_control.address.clone()],
+                from_block: Some(Block::from(1)),
+                to_block: None,
+                topics: vec![
+                    Some(vec![
+                        event_signature(&client, EVENT_ROLE_ASSIGNED),
+                        event_signature(&client, EVENT_ROLE_REVOKED),
+                    ]),
+                    Some(vec![TEST_ACCOUNT.to_filter(), TRUSTEE_ACCOUNT.to_filter()]),
+                ],
+            };
+            assert_eq!(expected_query, query);
+        }
+
+        #[async_std::test]
+        async fn build_get_role_events_query_without_accounts_test() {
+            let client = mock_client();
+
+            let err = build_get_role_events_query(&client, &[], None, None)
+                .await
+                .unwrap_err();
+
+            assert!(matches!(err, VdrError::CommonInvalidData(_)));
+        }
+    }
 }
@@ -2,10 +2,12 @@
 // https://www.dsr-corporation.com
 // SPDX-License-Identifier: Apache-2.0
 
+use futures::StreamExt;
+use log::warn;
 use log_derive::{logfn, logfn_inputs};
 
 use crate::{
-    client::LedgerClient,
+    client::{EventStream, LedgerClient},
     contracts::did::types::{
         did_doc_attribute::{DelegateType, DidDocAttribute, Validity},
         did_events::{DidAttributeChanged, DidDelegateChanged, DidEvents, DidOwnerChanged},
@@ -38,6 +40,8 @@ const METHOD_REVOKE_ATTRIBUTE_SIGNED: &str = "revokeAttributeSigned";
 const EVENT_DID_ATTRIBUTE_CHANGED: &str = "DIDAttributeChanged";
 const EVENT_DID_DELEGATE_CHANGED: &str = "DIDDelegateChanged";
 const EVENT_DID_OWNER_CHANGED: &str = "DIDOwnerChanged";
+// position of the indexed `identity` parameter among the DID event topics
+const EVENT_IDENTITY_TOPIC: usize = 1;
 
 pub const ETHR_DID_METHOD: &str = "ethr";
 
@@ -468,10 +472,71 @@ pub async fn build_get_did_events_query(
         .set_contract(CONTRACT_NAME)
         .set_from_block(from_block.cloned())
         .set_to_block(to_block.cloned())
-        .set_event_filer(address.to_filter())
+        .set_event_filter(address.to_filter())
         .build(client)
 }
 
+/// Build event query to obtain log events associated with any of the given DIDs from the ledger
+///
+/// #Params
+///  - `client`: [LedgerClient] - client connected to the network where contract will be executed
+///  - `dids`: [Vec] - target DIDs
+///  - `from_block`: [Block] - start block
+///  - `to_block`: [Block] - finish block
+///
+/// #Returns
+///   query: [EventQuery] - prepared event query to send
+#[logfn(Info)]
+#[logfn_inputs(Debug)]
+pub async fn build_get_dids_events_query(
+    client: &LedgerClient,
+    dids: &[DID],
+    from_block: Option<&Block>,
+    to_block: Option<&Block>,
+) -> VdrResult<EventQuery> {
+    if dids.is_empty() {
+        let vdr_error = VdrError::CommonInvalidData(
+            "At least one DID must be provided to query DID events".to_string(),
+        );
+
+        warn!("Error: {} during building DID events query", vdr_error);
+
+        return Err(vdr_error);
+    }
+
+    let mut builder = EventQueryBuilder::new()
+        .set_contract(CONTRACT_NAME)
+        .set_from_block(from_block.cloned())
+        .set_to_block(to_block.cloned());
+    for did in dids {
+        let address = Address::try_from(did)?;
+        builder = builder.add_topic(EVENT_IDENTITY_TOPIC, address.to_filter())?;
+    }
+    builder.build(client)
+}
+
+/// Subscribe to DID associated events (DidAttributeChanged, DidDelegateChanged, DidOwnerChanged)
+///
+/// #Params
+///  - `client`: [LedgerClient] - client connected to the network where contract will be executed
+///  - `did`: [DID] - target DID
+///  - `from_block`: [Block] - start block. If set, past events starting from this block are delivered first
+///
+/// #Returns
+///   stream: [EventStream] - stream of parsed DID events
+#[logfn_inputs(Debug)]
+pub async fn subscribe_did_events<'a>(
+    client: &'a LedgerClient,
+    did: &DID,
+    from_block: Option<&Block>,
+) -> VdrResult<EventStream<'a, DidEvents>> {
+    let query = build_get_did_events_query(client, did, from_block, None).await?;
+    let stream = client
+        .subscribe_events(&query)
+        .map(move |log| log.and_then(|log| parse_did_event_response(client, &log)));
+    Ok(Box::pin(stream))
+}
+
 /// Parse the result of execution EthereumExtDidRegistry.changed contract method to receive
 ///   a block number when DID was changed last time
 ///
@@ -728,6 +793,7 @@ pub mod test {
                 ],
                 signature: None,
                 hash: None,
+                ..Transaction::default()
             };
             assert_eq!(expected_transaction, transaction);
         }
@@ -766,6 +832,7 @@ pub mod test {
                 ],
                 signature: None,
                 hash: None,
+                ..Transaction::default()
             };
             assert_eq!(expected_transaction, transaction);
         }
@@ -801,6 +868,7 @@ pub mod test {
                 ],
                 signature: None,
                 hash: None,
+                ..Transaction::default()
             };
             assert_eq!(expected_transaction, transaction);
         }
@@ -840,6 +908,7 @@ pub mod test {
                 ],
                 signature: None,
                 hash: None,
+                ..Transaction::default()
             };
             assert_eq!(expected_transaction, transaction);
         }
@@ -876,11 +945,53 @@ pub mod test {
                 ],
                 signature: None,
                 hash: None,
+                ..Transaction::default()
             };
             assert_eq!(expected_transaction, transaction);
         }
     }
 
+    mod build_get_dids_events_query {
+        use super::*;
+
+        #[async_std::test]
+        async fn build_get_dids_events_query_test() {
+            let client = mock_client();
+            let other_did = DID::from(format!("did:ethr:{}", TRUSTEE_ACCOUNT.as_ref()).as_str());
+
+            let query = build_get_dids_events_query(
+                &client,
+                &[did(), other_did],
+                Some(&Block::from(1)),
+                None,
+            )
+            .await
+            .unwrap();
+
+            let expected_query = EventQuery {
+                addresses: vec![CONFIG.contracts.ethereum_did_registry.address.clone()],
+                from_block: Some(Block::from(1)),
+                to_block: None,
+                topics: vec![
+                    None,
+                    Some(vec![TEST_ACCOUNT.to_filter(), TRUSTEE_ACCOUNT.to_filter()]),
+                ],
+            };
+            assert_eq!(expected_query, query);
+        }
+
+        #[async_std::test]
+        async fn build_get_dids_events_query_without_dids_test() {
+            let client = mock_client();
+
+            let err = build_get_dids_events_query(&client, &[], None, None)
+                .await
+                .unwrap_err();
+
+            assert!(matches!(err, VdrError::CommonInvalidData(_)));
+        }
+    }
+
     mod build_did_revoke_attribute_transaction {
         use super::*;
 
@@ -909,6 +1020,7 @@ pub mod test {
                 ],
                 signature: None,
                 hash: None,
+                ..Transaction::default()
             };
             assert_eq!(expected_transaction, transaction);
         }
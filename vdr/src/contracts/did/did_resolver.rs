@@ -2,7 +2,11 @@
 // https://www.dsr-corporation.com
 // SPDX-License-Identifier: Apache-2.0
 
+use std::collections::{HashMap, HashSet};
+
 use chrono::Utc;
+use futures::future::try_join_all;
+use log::warn;
 use log_derive::{logfn, logfn_inputs};
 
 use crate::{
@@ -23,6 +27,9 @@ use crate::{
     VdrResult, VerificationKeyType, DID,
 };
 
+/// Maximal number of blocks covered by a single query while receiving `did:ethr` changes history
+pub const DID_HISTORY_BLOCK_RANGE: u64 = 5000;
+
 /// Single step function to resolve a DidDocument with metadata for the given DID
 ///
 /// # Params
@@ -127,6 +134,11 @@ mod indybesu {
     #[logfn(Trace)]
     #[logfn_inputs(Trace)]
     pub(crate) async fn resolve(client: &LedgerClient, did: &DID) -> VdrResult<DidRecord> {
+        // DID record does not contain the block of the last change, so cached record is used until it expires
+        if let Some(did_record) = client.cache().and_then(|cache| cache.get_did(did, None)) {
+            return Ok(did_record);
+        }
+
         let transaction = build_resolve_did_transaction(client, did).await?;
         let response = client.submit_transaction(&transaction).await?;
         if response.is_empty() {
@@ -135,7 +147,12 @@ mod indybesu {
                 did
             )));
         }
-        parse_resolve_did_result(client, &response)
+        let did_record = parse_resolve_did_result(client, &response)?;
+
+        if let Some(cache) = client.cache() {
+            cache.set_did(did, None, &did_record);
+        }
+        Ok(did_record)
     }
 }
 
@@ -166,6 +183,16 @@ mod ethr {
             });
         }
 
+        // cached document can be used only for the current DID state and if DID has not been changed since
+        let cache = match block {
+            Some(_) => None,
+            None => client.cache(),
+        };
+        let changed = Some(did_changed_block.value());
+        if let Some(did_record) = cache.and_then(|cache| cache.get_did(did, changed)) {
+            return Ok(did_record);
+        }
+
         let mut version_id: Option<Block> = None;
         let mut next_version_id: Option<Block> = None;
 
@@ -216,10 +243,15 @@ mod ethr {
             next_version_id.as_ref(),
         )
         .await?;
-        Ok(DidRecord {
+        let did_record = DidRecord {
             document: did_doc_builder.build(),
             metadata,
-        })
+        };
+
+        if let Some(cache) = cache {
+            cache.set_did(did, changed, &did_record);
+        }
+        Ok(did_record)
     }
 
     #[logfn(Trace)]
@@ -230,38 +262,130 @@ mod ethr {
         parse_did_changed_result(client, &response)
     }
 
+    /// Receive the history of DID changes ordered from the newest to the oldest event.
+    ///
+    /// Events are requested with bulk queries covering up to [DID_HISTORY_BLOCK_RANGE] blocks.
+    /// Completeness of the received history is verified by walking the `previousChange` linked list:
+    /// the changes missing in the bulk query result are requested with single block queries.
     #[logfn(Trace)]
     #[logfn_inputs(Trace)]
-    async fn receive_did_history(
+    pub(crate) async fn receive_did_history(
         client: &LedgerClient,
         did: &DID,
         first_block: Block,
     ) -> VdrResult<Vec<(Block, DidEvents)>> {
         let mut history: Vec<(Block, DidEvents)> = Vec::new();
-        let mut previous_block: Option<Block> = Some(first_block);
-        while previous_block.is_some() {
-            let transaction = build_get_did_events_query(
-                client,
-                did,
-                previous_block.as_ref(),
-                previous_block.as_ref(),
-            )
-            .await?;
-            let logs = client.query_events(&transaction).await?;
+        let mut received: HashMap<u64, Vec<(Block, DidEvents)>> = HashMap::new();
+        let mut next_block = Some(first_block.value());
+        while let Some(block) = next_block {
+            if !received.contains_key(&block) {
+                received.extend(receive_did_history_range(client, did, block).await?);
+            }
+
+            let events = match received.remove(&block) {
+                Some(events) => events,
+                None => {
+                    // the change referenced by the linked list is missing in the bulk query result
+                    match query_did_events(client, did, block, block)
+                        .await?
+                        .remove(&block)
+                    {
+                        Some(events) => events,
+                        // if no logs, break the loop as nothing to add to the change history
+                        None => break,
+                    }
+                }
+            };
 
-            // if no logs, break the loop as nothing to add to the change history
-            if logs.is_empty() {
+            next_block = previous_change_block(block, &events);
+            // events of the same block are received in the order of emitting
+            history.extend(events.into_iter().rev());
+        }
+        Ok(history)
+    }
+
+    /// Receive DID events emitted in the range of blocks ending with `to_block`.
+    ///     Changes referenced by the received events but missing in the range query result are requested concurrently
+    async fn receive_did_history_range(
+        client: &LedgerClient,
+        did: &DID,
+        to_block: u64,
+    ) -> VdrResult<HashMap<u64, Vec<(Block, DidEvents)>>> {
+        let from_block = to_block.saturating_sub(DID_HISTORY_BLOCK_RANGE - 1).max(1);
+        let mut received = match query_did_events(client, did, from_block, to_block).await {
+            Ok(received) => received,
+            Err(err) => {
+                warn!(
+                    "Unable to query DID events in blocks {}-{}: {}. Falling back to single block queries",
+                    from_block, to_block, err
+                );
+                return Ok(HashMap::new());
+            }
+        };
+
+        loop {
+            let missing: HashSet<u64> = received
+                .iter()
+                .filter_map(|(block, events)| previous_change_block(*block, events))
+                .filter(|block| *block >= from_block && !received.contains_key(block))
+                .collect();
+            if missing.is_empty() {
                 break;
             }
 
-            // parse events
-            for log in logs {
-                let event = parse_did_event_response(client, &log)?;
-                previous_block = Some(event.previous_change());
-                history.push((log.block, event));
+            let responses = try_join_all(
+                missing
+                    .iter()
+                    .map(|block| query_did_events(client, did, *block, *block)),
+            )
+            .await?;
+            let mut found = false;
+            for response in responses {
+                found |= !response.is_empty();
+                received.extend(response);
+            }
+            if !found {
+                break;
             }
         }
-        Ok(history)
+        Ok(received)
+    }
+
+    /// Query DID events emitted in the range of blocks and group them by block
+    async fn query_did_events(
+        client: &LedgerClient,
+        did: &DID,
+        from_block: u64,
+        to_block: u64,
+    ) -> VdrResult<HashMap<u64, Vec<(Block, DidEvents)>>> {
+        let query = build_get_did_events_query(
+            client,
+            did,
+            Some(&Block::from(from_block)),
+            Some(&Block::from(to_block)),
+        )
+        .await?;
+        let logs = client.query_events(&query).await?;
+
+        let mut events: HashMap<u64, Vec<(Block, DidEvents)>> = HashMap::new();
+        for log in logs {
+            let event = parse_did_event_response(client, &log)?;
+            events
+                .entry(log.block.value())
+                .or_default()
+                .push((log.block, event));
+        }
+        Ok(events)
+    }
+
+    /// Get the block of the DID change preceding to the events emitted in the given block.
+    ///     Events emitted after the first one in the same block reference the block itself
+    fn previous_change_block(block: u64, events: &[(Block, DidEvents)]) -> Option<u64> {
+        events
+            .iter()
+            .map(|(_, event)| event.previous_change().value())
+            .filter(|previous_block| *previous_block > 0 && *previous_block < block)
+            .min()
     }
 
     #[logfn(Trace)]
@@ -448,3 +572,148 @@ mod ethr {
         })
     }
 }
+
+#[cfg(test)]
+pub mod test {
+    use super::*;
+    use crate::{
+        client::{client::test::mock_custom_client, MockClient},
+        contracts::did::types::did_doc::test::TEST_ETHR_DID,
+        Address, EventLog, EventQuery,
+    };
+
+    fn did_owner_changed_log(
+        client: &LedgerClient,
+        block: u64,
+        previous_change: u64,
+        owner: u8,
+    ) -> EventLog {
+        let signature = client
+            .contract("EthereumExtDidRegistry")
+            .unwrap()
+            .event("DIDOwnerChanged")
+            .unwrap()
+            .signature();
+        let identity = Address::try_from(&DID::from(TEST_ETHR_DID))
+            .unwrap()
+            .to_filter();
+        let mut data = vec![0; 12];
+        data.extend_from_slice(&[owner; 20]);
+        data.extend_from_slice(&[0; 24]);
+        data.extend_from_slice(&previous_change.to_be_bytes());
+
+        EventLog::new(
+            vec![
+                signature.as_bytes().to_vec(),
+                hex::decode(identity).unwrap(),
+            ],
+            data,
+            block,
+        )
+    }
+
+    fn did_history_logs() -> Vec<EventLog> {
+        let abi_client = mock_custom_client(Box::new(MockClient::new()));
+        vec![
+            did_owner_changed_log(&abi_client, 100, 0, 2),
+            did_owner_changed_log(&abi_client, 7000, 100, 2),
+            did_owner_changed_log(&abi_client, 7001, 7000, 2),
+        ]
+    }
+
+    fn logs_in_range(logs: &[EventLog], query: &EventQuery) -> Vec<EventLog> {
+        let from_block = query.from_block.as_ref().unwrap().value();
+        let to_block = query.to_block.as_ref().unwrap().value();
+        logs.iter()
+            .filter(|log| log.block.value() >= from_block && log.block.value() <= to_block)
+            .cloned()
+            .collect()
+    }
+
+    fn history_blocks(history: &[(Block, DidEvents)]) -> Vec<u64> {
+        history.iter().map(|(block, _)| block.value()).collect()
+    }
+
+    fn history_owners(history: &[(Block, DidEvents)]) -> Vec<String> {
+        history
+            .iter()
+            .map(|(_, event)| match event {
+                DidEvents::OwnerChanged(event) => event.owner.as_ref().to_lowercase(),
+                _ => panic!("unexpected event: {:?}", event),
+            })
+            .collect()
+    }
+
+    #[async_std::test]
+    async fn receive_did_history_with_range_queries_test() {
+        let logs = did_history_logs();
+        let mut client_mock = MockClient::new();
+        client_mock
+            .expect_query_events()
+            .times(2)
+            .returning(move |query| Ok(logs_in_range(&logs, query)));
+        let client = mock_custom_client(Box::new(client_mock));
+
+        let history =
+            ethr::receive_did_history(&client, &DID::from(TEST_ETHR_DID), Block::from(7001))
+                .await
+                .unwrap();
+
+        assert_eq!(vec![7001, 7000, 100], history_blocks(&history));
+    }
+
+    #[async_std::test]
+    async fn receive_did_history_with_incomplete_range_query_test() {
+        let logs = did_history_logs();
+        let mut client_mock = MockClient::new();
+        client_mock.expect_query_events().returning(move |query| {
+            let logs = logs_in_range(&logs, query);
+            // range query result misses the change referenced by the linked list
+            if query.from_block != query.to_block {
+                return Ok(logs
+                    .into_iter()
+                    .filter(|log| log.block.value() != 7000)
+                    .collect());
+            }
+            Ok(logs)
+        });
+        let client = mock_custom_client(Box::new(client_mock));
+
+        let history =
+            ethr::receive_did_history(&client, &DID::from(TEST_ETHR_DID), Block::from(7001))
+                .await
+                .unwrap();
+
+        assert_eq!(vec![7001, 7000, 100], history_blocks(&history));
+    }
+
+    #[async_std::test]
+    async fn receive_did_history_with_several_events_in_block_test() {
+        let abi_client = mock_custom_client(Box::new(MockClient::new()));
+        // events emitted after the first one in the block reference the block itself
+        let logs = vec![
+            did_owner_changed_log(&abi_client, 100, 0, 1),
+            did_owner_changed_log(&abi_client, 7000, 100, 2),
+            did_owner_changed_log(&abi_client, 7000, 7000, 3),
+            did_owner_changed_log(&abi_client, 7000, 7000, 4),
+        ];
+        let mut client_mock = MockClient::new();
+        client_mock
+            .expect_query_events()
+            .returning(move |query| Ok(logs_in_range(&logs, query)));
+        let client = mock_custom_client(Box::new(client_mock));
+
+        let history =
+            ethr::receive_did_history(&client, &DID::from(TEST_ETHR_DID), Block::from(7000))
+                .await
+                .unwrap();
+
+        // history is ordered from the newest to the oldest event, including events of the same block
+        assert_eq!(vec![7000, 7000, 7000, 100], history_blocks(&history));
+        let expected_owners: Vec<String> = [4u8, 3, 2, 1]
+            .iter()
+            .map(|owner| format!("0x{}", hex::encode([*owner; 20])))
+            .collect();
+        assert_eq!(expected_owners, history_owners(&history));
+    }
+}
@@ -0,0 +1,426 @@
+// Copyright (c) 2024 DSR Corporation, Denver, Colorado.
+// https://www.dsr-corporation.com
+// SPDX-License-Identifier: Apache-2.0
+
+use chrono::Utc;
+use log_derive::{logfn, logfn_inputs};
+use serde_derive::{Deserialize, Serialize};
+
+use crate::{
+    contracts::{
+        auth::role_control::{build_get_role_transaction, parse_get_role_result},
+        did::did_ethr_registry::{build_get_identity_nonce_transaction, parse_did_nonce_result},
+        endorsing::build_endorsement_transaction,
+    },
+    Address, LedgerClient, Role, Transaction, TransactionEndorsingData, VdrError, VdrResult, DID,
+};
+
+/// Version of the endorsement request format produced by this library
+pub const ENDORSEMENT_REQUEST_VERSION: u8 = 1;
+
+// Endorsing contract methods accept author identity and signature (v, r, s) before the original method params
+const ENDORSING_METHOD_PREFIX_PARAMS: usize = 4;
+const SUMMARY_MAX_VALUE_LENGTH: usize = 64;
+
+/// Request of the transaction author to an Endorser to submit the signed transaction on the author behalf
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EndorsementRequest {
+    /// Version of the request format
+    pub version: u8,
+    /// DID of the transaction author
+    pub author: DID,
+    /// Unix timestamp (seconds) after which the request must not be endorsed
+    pub expires_at: u64,
+    /// Purpose of the transaction stated by the author
+    pub purpose: String,
+    /// Human-readable summary of the contract call to endorse
+    pub summary: String,
+    /// Transaction endorsing data signed by the author
+    pub endorsing_data: TransactionEndorsingData,
+}
+
+impl EndorsementRequest {
+    /// Serialize endorsement request as JSON string
+    #[logfn(Trace)]
+    #[logfn_inputs(Trace)]
+    pub fn to_string(&self) -> VdrResult<String> {
+        serde_json::to_string(self).map_err(|err| {
+            VdrError::ClientInvalidEndorsementData(format!(
+                "Unable to serialize endorsement request as JSON. Err: {:?}",
+                err
+            ))
+        })
+    }
+
+    /// Deserialize endorsement request from JSON string
+    #[logfn(Trace)]
+    #[logfn_inputs(Trace)]
+    pub fn from_string(value: &str) -> VdrResult<Self> {
+        serde_json::from_str(value).map_err(|err| {
+            VdrError::ClientInvalidEndorsementData(format!(
+                "Unable to deserialize endorsement request from JSON. Err: {:?}",
+                err
+            ))
+        })
+    }
+}
+
+/// Wrap author signed endorsing data into the request to pass to an Endorser
+///
+/// # Params
+/// - `client`: [LedgerClient] - client connected to the network where contract will be executed
+/// - `author`: [DID] - DID of the transaction author (must be controlled by endorsing data `from` account)
+/// - `endorsing_data`: [TransactionEndorsingData] - endorsing data signed by the author
+/// - `purpose`: [String] - purpose of the transaction for the Endorser
+/// - `expires_at`: [u64] - Unix timestamp (seconds) after which the request must not be endorsed
+///
+/// # Returns
+/// Endorsement request to pass to an Endorser
+#[logfn(Info)]
+#[logfn_inputs(Debug)]
+pub fn build_endorsement_request(
+    client: &LedgerClient,
+    author: &DID,
+    endorsing_data: &TransactionEndorsingData,
+    purpose: &str,
+    expires_at: u64,
+) -> VdrResult<EndorsementRequest> {
+    if endorsing_data.signature.is_none() {
+        return Err(VdrError::ClientInvalidEndorsementData(
+            "Missing author signature".to_string(),
+        ));
+    }
+
+    Ok(EndorsementRequest {
+        version: ENDORSEMENT_REQUEST_VERSION,
+        author: author.to_owned(),
+        expires_at,
+        purpose: purpose.to_string(),
+        summary: build_summary(client, endorsing_data)?,
+        endorsing_data: endorsing_data.to_owned(),
+    })
+}
+
+/// Validate the endorsement request on the Endorser side before spending a transaction on it.
+///
+/// Checks that:
+///  - request version is supported and request is not expired
+///  - endorsing data targets the registered contract and the summary matches the contract call
+///  - endorsing data is signed by the author account which the author DID belongs to
+///  - Endorser account has a role allowing endorsement (Trustee, Endorser or Steward)
+///  - endorsing nonce (if used by the contract) matches the current author identity nonce
+///
+/// # Params
+/// - `client`: [LedgerClient] - client connected to the network where contract will be executed
+/// - `endorser`: [Address] - Endorser account address which will submit the transaction
+/// - `request`: [EndorsementRequest] - request received from the author
+#[logfn(Info)]
+#[logfn_inputs(Debug)]
+pub async fn validate_endorsement_request(
+    client: &LedgerClient,
+    endorser: &Address,
+    request: &EndorsementRequest,
+) -> VdrResult<()> {
+    let data = &request.endorsing_data;
+
+    if request.version != ENDORSEMENT_REQUEST_VERSION {
+        return Err(invalid_request(format!(
+            "Unsupported request version: {}",
+            request.version
+        )));
+    }
+    if request.expires_at <= Utc::now().timestamp() as u64 {
+        return Err(invalid_request("Request is expired".to_string()));
+    }
+
+    let contract = client.contract(&data.contract)?;
+    if !same_address(contract.address(), &data.to) {
+        return Err(invalid_request(format!(
+            "Endorsing data targets unexpected address {} instead of {} contract",
+            data.to.as_ref(),
+            data.contract
+        )));
+    }
+    if request.summary != build_summary(client, data)? {
+        return Err(invalid_request(
+            "Summary does not match the endorsing data".to_string(),
+        ));
+    }
+
+    let author = Address::try_from(&request.author)?;
+    if !same_address(&author, &data.from) {
+        return Err(invalid_request(format!(
+            "Author DID {} does not belong to the account {}",
+            request.author.as_ref(),
+            data.from.as_ref()
+        )));
+    }
+    if !data.verify_signature()? {
+        return Err(invalid_request(
+            "Endorsing data is not signed by the author".to_string(),
+        ));
+    }
+
+    let transaction = build_get_role_transaction(client, endorser).await?;
+    let role = parse_get_role_result(client, &client.submit_transaction(&transaction).await?)?;
+    if !matches!(role, Role::Trustee | Role::Endorser | Role::Steward) {
+        return Err(invalid_request(format!(
+            "Account {} does not have a role allowing endorsement",
+            endorser.as_ref()
+        )));
+    }
+
+    if let Some(ref nonce) = data.nonce {
+        let transaction = build_get_identity_nonce_transaction(client, &data.from).await?;
+        let current_nonce =
+            parse_did_nonce_result(client, &client.submit_transaction(&transaction).await?)?;
+        if current_nonce.value() != nonce.value() {
+            return Err(invalid_request(format!(
+                "Endorsing nonce {} is stale, current nonce is {}",
+                nonce.value(),
+                current_nonce.value()
+            )));
+        }
+    }
+
+    Ok(())
+}
+
+/// Validate the endorsement request and build the transaction to endorse it
+///
+/// # Params
+/// - `client`: [LedgerClient] - client connected to the network where contract will be executed
+/// - `from`: [Address] - Endorser account address which will submit the transaction
+/// - `request`: [EndorsementRequest] - request received from the author
+///
+/// # Returns
+/// Write transaction to sign and submit
+#[logfn(Info)]
+#[logfn_inputs(Debug)]
+pub async fn build_endorsement_request_transaction(
+    client: &LedgerClient,
+    from: &Address,
+    request: &EndorsementRequest,
+) -> VdrResult<Transaction> {
+    validate_endorsement_request(client, from, request).await?;
+    build_endorsement_transaction(client, from, &request.endorsing_data).await
+}
+
+fn build_summary(client: &LedgerClient, data: &TransactionEndorsingData) -> VdrResult<String> {
+    let function = client
+        .contract(&data.contract)?
+        .function(&data.endorsing_method)?;
+    let params = function
+        .inputs
+        .iter()
+        .skip(ENDORSING_METHOD_PREFIX_PARAMS)
+        .zip(data.params.iter())
+        .map(|(input, value)| {
+            let mut value = value.to_string();
+            if value.len() > SUMMARY_MAX_VALUE_LENGTH {
+                // string params are arbitrary text: cut at a char boundary
+                let mut end = SUMMARY_MAX_VALUE_LENGTH;
+                while !value.is_char_boundary(end) {
+                    end -= 1;
+                }
+                value.truncate(end);
+                value.push_str("...");
+            }
+            format!("{}: {}", input.name, value)
+        })
+        .collect::<Vec<String>>();
+
+    Ok(format!(
+        "{}.{}({}) on behalf of {}",
+        data.contract,
+        data.method,
+        params.join(", "),
+        data.from.as_ref()
+    ))
+}
+
+fn same_address(left: &Address, right: &Address) -> bool {
+    left.as_ref().eq_ignore_ascii_case(right.as_ref())
+}
+
+fn invalid_request(reason: String) -> VdrError {
+    VdrError::ClientInvalidEndorsementData(format!("Invalid endorsement request: {}", reason))
+}
+
+#[cfg(test)]
+pub mod test {
+    use super::*;
+    use crate::{
+        client::{
+            client::test::{mock_custom_client, CONFIG, TRUSTEE_ACCOUNT},
+            MockClient,
+        },
+        contracts::{
+            anoncreds::{
+                schema_registry::build_create_schema_endorsing_data, types::schema::test::schema,
+            },
+            did::{
+                did_ethr_registry::build_did_change_owner_endorsing_data,
+                types::did_doc::test::TEST_ETHR_DID,
+            },
+        },
+        types::signature::test::trustee_signature,
+        ContractParam,
+    };
+    use once_cell::sync::Lazy;
+
+    const IDENTITY_NONCE: u8 = 3;
+
+    static NEW_OWNER: Lazy<Address> =
+        Lazy::new(|| Address::from("0x0886328869e4e1f401e1052a5f4aae8b45f42610"));
+
+    fn author() -> DID {
+        DID::from(format!("did:ethr:{}", TRUSTEE_ACCOUNT.as_ref()).as_str())
+    }
+
+    fn uint(value: u8) -> Vec<u8> {
+        let mut bytes = vec![0; 31];
+        bytes.push(value);
+        bytes
+    }
+
+    fn client(role: Role, nonce: u8) -> LedgerClient {
+        let mut client = MockClient::new();
+        client.expect_get_transaction_count().returning(|_| Ok(0));
+        client.expect_call_transaction().returning(move |to, _| {
+            if to.eq_ignore_ascii_case(CONFIG.contracts.role_control.address.as_ref()) {
+                Ok(uint(role.into()))
+            } else {
+                Ok(uint(nonce))
+            }
+        });
+        mock_custom_client(Box::new(client))
+    }
+
+    async fn request(client: &LedgerClient, expires_at: u64) -> EndorsementRequest {
+        let mut data = build_did_change_owner_endorsing_data(client, &author(), &NEW_OWNER)
+            .await
+            .unwrap();
+        data.set_signature(trustee_signature(&data.get_signing_bytes().unwrap()));
+        build_endorsement_request(client, &author(), &data, "rotate key", expires_at).unwrap()
+    }
+
+    fn expires_at() -> u64 {
+        Utc::now().timestamp() as u64 + 3600
+    }
+
+    #[async_std::test]
+    async fn build_endorsement_request_test() {
+        let client = client(Role::Endorser, IDENTITY_NONCE);
+
+        let request = request(&client, expires_at()).await;
+
+        assert_eq!(ENDORSEMENT_REQUEST_VERSION, request.version);
+        assert_eq!(
+            format!(
+                "EthereumExtDidRegistry.changeOwner(newOwner: {}) on behalf of {}",
+                NEW_OWNER.as_ref().trim_start_matches("0x"),
+                TRUSTEE_ACCOUNT.as_ref()
+            ),
+            request.summary
+        );
+        let restored = EndorsementRequest::from_string(&request.to_string().unwrap()).unwrap();
+        assert_eq!(request.summary, restored.summary);
+    }
+
+    #[async_std::test]
+    async fn build_summary_with_long_non_ascii_param_test() {
+        let client = client(Role::Endorser, IDENTITY_NONCE);
+        let schema = schema(&DID::from(TEST_ETHR_DID), Some("F1DClaFEzi3t"));
+        let mut data = build_create_schema_endorsing_data(&client, &schema)
+            .await
+            .unwrap();
+        // 3 bytes per char, so the byte limit falls inside a char
+        data.params[1] = ContractParam::String("€".repeat(30));
+
+        let summary = build_summary(&client, &data).unwrap();
+
+        assert!(summary.contains(&format!("issuerId: {}...,", "€".repeat(21))));
+    }
+
+    #[async_std::test]
+    async fn build_endorsement_request_transaction_test() {
+        let client = client(Role::Endorser, IDENTITY_NONCE);
+        let request = request(&client, expires_at()).await;
+
+        let transaction =
+            build_endorsement_request_transaction(&client, &TRUSTEE_ACCOUNT, &request)
+                .await
+                .unwrap();
+
+        assert_eq!(
+            CONFIG.contracts.ethereum_did_registry.address,
+            transaction.to
+        );
+    }
+
+    #[async_std::test]
+    async fn validate_expired_request_test() {
+        let client = client(Role::Endorser, IDENTITY_NONCE);
+        let request = request(&client, Utc::now().timestamp() as u64 - 1).await;
+
+        let err = validate_endorsement_request(&client, &TRUSTEE_ACCOUNT, &request)
+            .await
+            .unwrap_err();
+
+        assert!(matches!(err, VdrError::ClientInvalidEndorsementData(_)));
+    }
+
+    #[async_std::test]
+    async fn validate_tampered_summary_test() {
+        let client = client(Role::Endorser, IDENTITY_NONCE);
+        let mut request = request(&client, expires_at()).await;
+        request.summary = "Harmless call".to_string();
+
+        let err = validate_endorsement_request(&client, &TRUSTEE_ACCOUNT, &request)
+            .await
+            .unwrap_err();
+
+        assert!(matches!(err, VdrError::ClientInvalidEndorsementData(_)));
+    }
+
+    #[async_std::test]
+    async fn validate_request_signed_by_other_account_test() {
+        let client = client(Role::Endorser, IDENTITY_NONCE);
+        let mut request = request(&client, expires_at()).await;
+        request
+            .endorsing_data
+            .set_signature(trustee_signature(&[1; 32]));
+
+        let err = validate_endorsement_request(&client, &TRUSTEE_ACCOUNT, &request)
+            .await
+            .unwrap_err();
+
+        assert!(matches!(err, VdrError::ClientInvalidEndorsementData(_)));
+    }
+
+    #[async_std::test]
+    async fn validate_request_by_account_without_role_test() {
+        let request = request(&client(Role::Endorser, IDENTITY_NONCE), expires_at()).await;
+        let client = client(Role::Empty, IDENTITY_NONCE);
+
+        let err = validate_endorsement_request(&client, &TRUSTEE_ACCOUNT, &request)
+            .await
+            .unwrap_err();
+
+        assert!(matches!(err, VdrError::ClientInvalidEndorsementData(_)));
+    }
+
+    #[async_std::test]
+    async fn validate_request_with_stale_nonce_test() {
+        let request = request(&client(Role::Endorser, IDENTITY_NONCE), expires_at()).await;
+        let client = client(Role::Endorser, IDENTITY_NONCE + 1);
+
+        let err = validate_endorsement_request(&client, &TRUSTEE_ACCOUNT, &request)
+            .await
+            .unwrap_err();
+
+        assert!(matches!(err, VdrError::ClientInvalidEndorsementData(_)));
+    }
+}
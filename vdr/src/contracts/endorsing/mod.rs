@@ -0,0 +1,9 @@
+// Copyright (c) 2024 DSR Corporation, Denver, Colorado.
+// https://www.dsr-corporation.com
+// SPDX-License-Identifier: Apache-2.0
+
+pub mod endorsement_request;
+pub mod endorsement_transaction;
+
+pub use endorsement_request::*;
+pub use endorsement_transaction::*;
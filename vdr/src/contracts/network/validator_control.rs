@@ -156,6 +156,7 @@ pub mod test {
                 data: expected_data.into(),
                 signature: None,
                 hash: None,
+                ..Transaction::default()
             };
 
             assert_eq!(expected_transaction, transaction);
@@ -186,6 +187,7 @@ pub mod test {
                 data: expected_data.into(),
                 signature: None,
                 hash: None,
+                ..Transaction::default()
             };
 
             assert_eq!(expected_transaction, transaction);
@@ -210,6 +212,7 @@ pub mod test {
                 data: encoded_method.into(),
                 signature: None,
                 hash: None,
+                ..Transaction::default()
             };
 
             assert_eq!(expected_transaction, transaction);
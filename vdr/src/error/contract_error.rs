@@ -0,0 +1,184 @@
+// Copyright (c) 2024 DSR Corporation, Denver, Colorado.
+// https://www.dsr-corporation.com
+// SPDX-License-Identifier: Apache-2.0
+
+use std::fmt::{Display, Formatter};
+
+use serde_derive::{Deserialize, Serialize};
+
+use crate::types::DecodedParam;
+
+/// Custom Solidity error raised by a contract during the transaction execution
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ContractError {
+    /// Name of the contract the transaction was sent to (if the contract is registered in the client)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub contract: Option<String>,
+    /// Name of the error as defined in the contract ABI
+    pub name: String,
+    /// Decoded error arguments
+    pub args: Vec<DecodedParam>,
+    /// Well-known kind of the error
+    pub kind: ContractErrorKind,
+}
+
+/// Well-known errors of the ledger contracts (`DidErrors.sol`, `AnoncredsErrors.sol`, `AuthErrors.sol`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ContractErrorKind {
+    DidNotFound,
+    DidAlreadyExist,
+    DidHasBeenDeactivated,
+    IncorrectDid,
+    NotIdentityOwner,
+    IssuerNotFound,
+    InvalidIssuerId,
+    IssuerHasBeenDeactivated,
+    SchemaAlreadyExist,
+    SchemaNotFound,
+    CredentialDefinitionAlreadyExist,
+    CredentialDefinitionNotFound,
+    AccumulatorMismatch,
+    NotRevocationRegistryDefinitionIssuer,
+    RevocationRegistryDefinitionAlreadyExist,
+    RevocationRegistryDefinitionNotFound,
+    Unauthorized,
+    /// Any other error defined in the contract ABI
+    Other,
+}
+
+impl ContractError {
+    pub(crate) fn new(
+        contract: Option<&str>,
+        name: &str,
+        args: Vec<DecodedParam>,
+    ) -> ContractError {
+        ContractError {
+            contract: contract.map(String::from),
+            name: name.to_string(),
+            args,
+            kind: ContractErrorKind::from(name),
+        }
+    }
+}
+
+impl Display for ContractError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let args = self
+            .args
+            .iter()
+            .map(|arg| format!("{}: {}", arg.name, arg.value))
+            .collect::<Vec<String>>()
+            .join(", ");
+        write!(f, "{}({})", self.name, args)
+    }
+}
+
+impl ContractErrorKind {
+    /// Whether the error reports that the requested record already exists on the ledger
+    pub fn is_already_exist(&self) -> bool {
+        matches!(
+            self,
+            ContractErrorKind::DidAlreadyExist
+                | ContractErrorKind::SchemaAlreadyExist
+                | ContractErrorKind::CredentialDefinitionAlreadyExist
+                | ContractErrorKind::RevocationRegistryDefinitionAlreadyExist
+        )
+    }
+
+    /// Whether the error reports that the requested record does not exist on the ledger
+    pub fn is_not_found(&self) -> bool {
+        matches!(
+            self,
+            ContractErrorKind::DidNotFound
+                | ContractErrorKind::IssuerNotFound
+                | ContractErrorKind::SchemaNotFound
+                | ContractErrorKind::CredentialDefinitionNotFound
+                | ContractErrorKind::RevocationRegistryDefinitionNotFound
+        )
+    }
+
+    /// Whether the error reports that the sender is not allowed to perform the operation
+    pub fn is_unauthorized(&self) -> bool {
+        matches!(
+            self,
+            ContractErrorKind::Unauthorized
+                | ContractErrorKind::NotIdentityOwner
+                | ContractErrorKind::NotRevocationRegistryDefinitionIssuer
+        )
+    }
+}
+
+impl From<&str> for ContractErrorKind {
+    fn from(name: &str) -> Self {
+        match name {
+            "DidNotFound" => ContractErrorKind::DidNotFound,
+            "DidAlreadyExist" => ContractErrorKind::DidAlreadyExist,
+            "DidHasBeenDeactivated" => ContractErrorKind::DidHasBeenDeactivated,
+            "IncorrectDid" => ContractErrorKind::IncorrectDid,
+            "NotIdentityOwner" => ContractErrorKind::NotIdentityOwner,
+            "IssuerNotFound" => ContractErrorKind::IssuerNotFound,
+            "InvalidIssuerId" => ContractErrorKind::InvalidIssuerId,
+            "IssuerHasBeenDeactivated" => ContractErrorKind::IssuerHasBeenDeactivated,
+            "SchemaAlreadyExist" => ContractErrorKind::SchemaAlreadyExist,
+            "SchemaNotFound" => ContractErrorKind::SchemaNotFound,
+            "CredentialDefinitionAlreadyExist" => {
+                ContractErrorKind::CredentialDefinitionAlreadyExist
+            }
+            "CredentialDefinitionNotFound" => ContractErrorKind::CredentialDefinitionNotFound,
+            "AccumulatorMismatch" => ContractErrorKind::AccumulatorMismatch,
+            "NotRevocationRegistryDefinitionIssuer" => {
+                ContractErrorKind::NotRevocationRegistryDefinitionIssuer
+            }
+            "RevocationRegistryDefinitionAlreadyExist" => {
+                ContractErrorKind::RevocationRegistryDefinitionAlreadyExist
+            }
+            "RevocationRegistryDefinitionNotFound" => {
+                ContractErrorKind::RevocationRegistryDefinitionNotFound
+            }
+            "Unauthorized" => ContractErrorKind::Unauthorized,
+            _ => ContractErrorKind::Other,
+        }
+    }
+}
+
+#[cfg(test)]
+pub mod test {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case::did_already_exist("DidAlreadyExist", ContractErrorKind::DidAlreadyExist)]
+    #[case::schema_not_found("SchemaNotFound", ContractErrorKind::SchemaNotFound)]
+    #[case::unauthorized("Unauthorized", ContractErrorKind::Unauthorized)]
+    #[case::unknown("ValidatorNotFound", ContractErrorKind::Other)]
+    fn contract_error_kind_from_name_test(#[case] name: &str, #[case] expected: ContractErrorKind) {
+        assert_eq!(expected, ContractErrorKind::from(name));
+    }
+
+    #[test]
+    fn contract_error_display_test() {
+        let error = ContractError::new(
+            Some("IndyDidRegistry"),
+            "NotIdentityOwner",
+            vec![
+                DecodedParam {
+                    name: "actor".to_string(),
+                    type_: "address".to_string(),
+                    value: "0x01".to_string(),
+                },
+                DecodedParam {
+                    name: "identity".to_string(),
+                    type_: "address".to_string(),
+                    value: "0x02".to_string(),
+                },
+            ],
+        );
+
+        assert!(error.kind.is_unauthorized());
+        assert_eq!(
+            "NotIdentityOwner(actor: 0x01, identity: 0x02)",
+            error.to_string()
+        );
+    }
+}
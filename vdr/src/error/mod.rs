@@ -2,10 +2,16 @@
 // https://www.dsr-corporation.com
 // SPDX-License-Identifier: Apache-2.0
 
+mod contract_error;
+
+pub use contract_error::{ContractError, ContractErrorKind};
+
 use std::ops::RangeInclusive;
 
 use serde_json::json;
 
+use crate::client::QuorumReport;
+
 use jsonrpc_core::types::error::{Error as RpcError, ErrorCode};
 #[cfg(not(feature = "wasm"))]
 use web3::{ethabi::Error as Web3EthabiError, Error as Web3Error};
@@ -16,8 +22,8 @@ const RPC_SERVER_ERROR_RANGE: RangeInclusive<i64> = -32099..=-32000;
 
 #[derive(thiserror::Error, Debug, PartialEq, Clone)]
 pub enum VdrError {
-    #[error("Ledger: Quorum not reached: {}", _0)]
-    QuorumNotReached(String),
+    #[error("Ledger: Quorum not reached: {}. Report: {}", _0, _1)]
+    QuorumNotReached(String, QuorumReport),
 
     #[error("Ledger Client: Node is unreachable")]
     ClientNodeUnreachable,
@@ -34,6 +40,9 @@ pub enum VdrError {
     #[error("Ledger Client: Transaction reverted: {}", _0)]
     ClientTransactionReverted(String),
 
+    #[error("Ledger Client: Transaction timed out: {}", _0)]
+    ClientTransactionTimeout(String),
+
     #[error("Ledger Client: Unexpected error occurred: {}", _0)]
     ClientUnexpectedError(String),
 
@@ -52,6 +61,9 @@ pub enum VdrError {
     #[error("Contract: Invalid response data: {}", _0)]
     ContractInvalidResponseData(String),
 
+    #[error("Contract: Transaction reverted with error: {}", _0)]
+    ContractError(ContractError),
+
     #[error("Signer: Invalid private key")]
     SignerInvalidPrivateKey,
 
@@ -64,6 +76,12 @@ pub enum VdrError {
     #[error("Signer: Unexpected error occurred: {}", _0)]
     SignerUnexpectedError(String),
 
+    #[error("Signer: Invalid keystore: {}", _0)]
+    SignerInvalidKeystore(String),
+
+    #[error("Signer: Invalid mnemonic: {}", _0)]
+    SignerInvalidMnemonic(String),
+
     #[error("Invalid data: {}", _0)]
     CommonInvalidData(String),
 
@@ -94,7 +112,9 @@ pub type VdrResult<T> = Result<T, VdrError>;
 impl From<Web3Error> for VdrError {
     fn from(value: Web3Error) -> Self {
         match value {
-            Web3Error::Unreachable => VdrError::ClientNodeUnreachable,
+            Web3Error::Unreachable | Web3Error::Transport(_) | Web3Error::Io(_) => {
+                VdrError::ClientNodeUnreachable
+            }
             Web3Error::InvalidResponse(err) => VdrError::ClientInvalidResponse(err),
             Web3Error::Rpc(err) => err.into(),
             _ => VdrError::ClientUnexpectedError(value.to_string()),
@@ -143,6 +163,25 @@ impl From<secp256k1::Error> for VdrError {
     }
 }
 
+#[cfg(feature = "basic_signer")]
+impl From<bip39::Error> for VdrError {
+    fn from(value: bip39::Error) -> Self {
+        VdrError::SignerInvalidMnemonic(value.to_string())
+    }
+}
+
+#[cfg(feature = "basic_signer")]
+impl From<eth_keystore::KeystoreError> for VdrError {
+    fn from(value: eth_keystore::KeystoreError) -> Self {
+        match value {
+            eth_keystore::KeystoreError::MacMismatch => VdrError::SignerInvalidKeystore(
+                "Keystore MAC mismatch: wrong password or corrupted file".to_string(),
+            ),
+            err => VdrError::SignerInvalidKeystore(err.to_string()),
+        }
+    }
+}
+
 #[cfg(test)]
 pub mod test {
     use super::*;
@@ -151,6 +190,7 @@ pub mod test {
         Value,
     };
     use rstest::rstest;
+    use web3::error::TransportError;
 
     #[rstest]
     #[case::rpc_error_with_hex_string_data(
@@ -181,4 +221,27 @@ pub mod test {
 
         assert_eq!(actual_vdr_error, expected_vdr_error);
     }
+
+    #[rstest]
+    #[case::unreachable(Web3Error::Unreachable, VdrError::ClientNodeUnreachable)]
+    #[case::transport_error(
+        Web3Error::Transport(TransportError::Message("connection refused".to_string())),
+        VdrError::ClientNodeUnreachable
+    )]
+    #[case::io_error(
+        Web3Error::Io(std::io::ErrorKind::ConnectionReset.into()),
+        VdrError::ClientNodeUnreachable
+    )]
+    #[case::rpc_error(
+        Web3Error::Rpc(RpcError { code: ErrorCode::ServerError(-32000), message: "nonce too low".to_string(), data: Option::None }),
+        VdrError::ClientUnexpectedError("{\"code\":-32000,\"message\":\"nonce too low\"}".to_string()),
+    )]
+    fn convert_web3_error_to_vdr_error_test(
+        #[case] web3_error: Web3Error,
+        #[case] expected_vdr_error: VdrError,
+    ) {
+        let actual_vdr_error: VdrError = web3_error.into();
+
+        assert_eq!(actual_vdr_error, expected_vdr_error);
+    }
 }
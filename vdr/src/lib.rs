@@ -9,7 +9,6 @@ mod error;
 mod types;
 mod utils;
 
-#[cfg(feature = "basic_signer")]
 mod signer;
 
 #[cfg(feature = "migration")]
@@ -19,7 +18,10 @@ pub mod migration;
 #[cfg(test)]
 mod test;
 
-pub use client::{Client, Contract, LedgerClient};
+pub use client::{
+    CacheConfig, CacheStorage, Client, Contract, EventStream, FailoverClient, GasPolicy,
+    LedgerClient, LedgerClientBuilder, NodeSelection, NonceManager, OfflineClient, ResolutionCache,
+};
 pub use contracts::{
     anoncreds::{
         credential_definition_registry, revocation_registry, schema_registry,
@@ -39,6 +41,7 @@ pub use contracts::{
             revocation_registry_entry::{
                 Accumulator, RevocationRegistryEntry, RevocationRegistryEntryData,
             },
+            revocation_registry_events::{RevRegEntryCreated, RevocationRegistryEvents},
             schema::{Schema, SchemaRecord},
             schema_id::SchemaId,
         },
@@ -60,7 +63,7 @@ pub use contracts::{
             did_events::{DidAttributeChanged, DidDelegateChanged, DidEvents, DidOwnerChanged},
         },
     },
-    endorsing,
+    endorsing::{self, EndorsementRequest},
     migration::{
         legacy_mapping_registry,
         types::{
@@ -71,9 +74,12 @@ pub use contracts::{
     },
     network::validator_control,
 };
-pub use error::{VdrError, VdrResult};
+pub use error::{ContractError, ContractErrorKind, VdrError, VdrResult};
 pub use types::*;
 
-pub use crate::client::QuorumConfig;
+pub use crate::client::{
+    QuorumConfig, QuorumNodeReport, QuorumNodeStatus, QuorumReport, QuorumThreshold,
+};
+pub use signer::Signer;
 #[cfg(feature = "basic_signer")]
 pub use signer::{BasicSigner, KeyPair};
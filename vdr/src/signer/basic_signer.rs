@@ -2,19 +2,29 @@
 // https://www.dsr-corporation.com
 // SPDX-License-Identifier: Apache-2.0
 
-use crate::error::{VdrError, VdrResult};
+use crate::{
+    error::{VdrError, VdrResult},
+    signer::{
+        mnemonic::{derive_private_key, generate_mnemonic, ETHEREUM_DERIVATION_PATH},
+        Signer,
+    },
+};
 
+use async_trait::async_trait;
 use log::warn;
 use log_derive::{logfn, logfn_inputs};
 use secp256k1::{All, Message, PublicKey, Secp256k1, SecretKey};
 use std::{
     collections::HashMap,
     fmt::{Debug, Formatter},
+    fs,
+    path::{Path, PathBuf},
 };
 
 use crate::types::{Address, SignatureData};
 use std::str::FromStr;
 use web3::signing::keccak256;
+use zeroize::Zeroizing;
 
 pub struct KeyPair {
     pub public_key: PublicKey,
@@ -40,9 +50,65 @@ impl BasicSigner {
     #[logfn_inputs(Trace)]
     pub fn create_key(&mut self, private_key: Option<&str>) -> VdrResult<(Address, Vec<u8>)> {
         let (account, key_pair) = self.create_account(private_key)?;
+        Ok(self.add_key(account, key_pair))
+    }
+
+    /// Generate a new random 24 words BIP-39 mnemonic which can be used to derive accounts
+    ///
+    /// # Returns
+    ///  mnemonic phrase
+    #[logfn(Trace)]
+    pub fn generate_mnemonic() -> VdrResult<String> {
+        generate_mnemonic()
+    }
+
+    /// Derive the key from the BIP-39 mnemonic for the Ethereum account `m/44'/60'/0'/0/{index}` and add it to the signer.
+    ///     The same mnemonic, passphrase and index always result in the same account
+    ///
+    /// # Params
+    ///  - `mnemonic`: BIP-39 mnemonic phrase
+    ///  - `passphrase`: (Optional) BIP-39 passphrase
+    ///  - `index`: index of the account
+    ///
+    /// # Returns
+    ///  account address and public key
+    #[logfn(Trace)]
+    pub fn create_key_from_mnemonic(
+        &mut self,
+        mnemonic: &str,
+        passphrase: Option<&str>,
+        index: u32,
+    ) -> VdrResult<(Address, Vec<u8>)> {
+        let path = format!("{}/{}", ETHEREUM_DERIVATION_PATH, index);
+        let (account, key_pair) = self.create_account_from_mnemonic(mnemonic, passphrase, &path)?;
+        Ok(self.add_key(account, key_pair))
+    }
+
+    /// Derive the account key from the BIP-39 mnemonic using BIP-32 derivation path
+    ///
+    /// # Params
+    ///  - `mnemonic`: BIP-39 mnemonic phrase
+    ///  - `passphrase`: (Optional) BIP-39 passphrase
+    ///  - `path`: BIP-32 derivation path, e.g. `m/44'/60'/0'/0/0`
+    ///
+    /// # Returns
+    ///  account address and key pair
+    #[logfn(Trace)]
+    pub fn create_account_from_mnemonic(
+        &self,
+        mnemonic: &str,
+        passphrase: Option<&str>,
+        path: &str,
+    ) -> VdrResult<(Address, KeyPair)> {
+        let private_key =
+            derive_private_key(&self.secp, mnemonic, passphrase.unwrap_or_default(), path)?;
+        Ok(self.key_pair(private_key))
+    }
+
+    fn add_key(&mut self, account: Address, key_pair: KeyPair) -> (Address, Vec<u8>) {
         let public_key_bytes = key_pair.public_key.serialize_uncompressed().to_vec();
         self.keys.insert(account.to_string(), key_pair);
-        Ok((account, public_key_bytes))
+        (account, public_key_bytes)
     }
 
     #[logfn(Trace)]
@@ -74,13 +140,102 @@ impl BasicSigner {
             Some(private_key) => SecretKey::from_str(private_key)?,
             None => SecretKey::new(&mut rand::thread_rng()),
         };
+        Ok(self.key_pair(private_key))
+    }
+
+    fn key_pair(&self, private_key: SecretKey) -> (Address, KeyPair) {
         let public_key = PublicKey::from_secret_key(&self.secp, &private_key);
         let address = Address::from(self.account_from_key(&public_key).as_str());
         let key_pair = KeyPair {
             public_key,
             private_key,
         };
-        Ok((address, key_pair))
+        (address, key_pair)
+    }
+
+    /// Import the key from an encrypted Ethereum V3 JSON keystore file (Besu, Geth, MetaMask)
+    ///
+    /// # Params
+    ///  - `path`: path to the keystore file
+    ///  - `password`: password the keystore is encrypted with
+    ///
+    /// # Returns
+    ///  address of the imported account
+    #[logfn(Trace)]
+    pub fn import_keystore(&mut self, path: &Path, password: &str) -> VdrResult<Address> {
+        let private_key = Zeroizing::new(eth_keystore::decrypt_key(path, password)?);
+        let (account, key_pair) = self.key_pair(SecretKey::from_slice(&private_key)?);
+        Ok(self.add_key(account, key_pair).0)
+    }
+
+    /// Export the key of the account into an encrypted Ethereum V3 JSON keystore file (scrypt + AES-128-CTR)
+    ///
+    /// # Params
+    ///  - `account`: address of the account which key to export
+    ///  - `dir`: directory to store the keystore file in (file is named after the account address)
+    ///  - `password`: password to encrypt the keystore with
+    ///
+    /// # Returns
+    ///  path to the created keystore file
+    #[logfn(Trace)]
+    pub fn export_keystore(&self, account: &str, dir: &Path, password: &str) -> VdrResult<PathBuf> {
+        let key = self.key_for_account(account)?;
+        let name = account.trim_start_matches("0x").to_lowercase();
+        let private_key = Zeroizing::new(key.private_key.secret_bytes());
+        eth_keystore::encrypt_key(
+            dir,
+            &mut rand::thread_rng(),
+            private_key.as_slice(),
+            password,
+            Some(&name),
+        )?;
+        Ok(dir.join(name))
+    }
+
+    /// Import all keystore files from the directory encrypted with the same password
+    ///
+    /// # Params
+    ///  - `dir`: directory containing keystore files
+    ///  - `password`: password the keystores are encrypted with
+    ///
+    /// # Returns
+    ///  addresses of the imported accounts
+    #[logfn(Trace)]
+    pub fn load_keystore_dir(&mut self, dir: &Path, password: &str) -> VdrResult<Vec<Address>> {
+        let mut paths = fs::read_dir(dir)
+            .map_err(|err| VdrError::SignerInvalidKeystore(err.to_string()))?
+            .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+            .filter(|path| {
+                path.is_file()
+                    && !path
+                        .file_name()
+                        .and_then(|name| name.to_str())
+                        .map_or(true, |name| name.starts_with('.'))
+            })
+            .collect::<Vec<PathBuf>>();
+        paths.sort();
+
+        paths
+            .iter()
+            .map(|path| self.import_keystore(path, password))
+            .collect()
+    }
+
+    /// Persist the keys of all accounts into the directory as keystore files encrypted with the password
+    ///
+    /// # Params
+    ///  - `dir`: directory to store keystore files in (created if missing)
+    ///  - `password`: password to encrypt the keystores with
+    ///
+    /// # Returns
+    ///  paths to the created keystore files
+    #[logfn(Trace)]
+    pub fn save_keystore_dir(&self, dir: &Path, password: &str) -> VdrResult<Vec<PathBuf>> {
+        fs::create_dir_all(dir).map_err(|err| VdrError::SignerInvalidKeystore(err.to_string()))?;
+        self.keys
+            .keys()
+            .map(|account| self.export_keystore(account, dir, password))
+            .collect()
     }
 
     #[logfn(Trace)]
@@ -99,6 +254,22 @@ impl BasicSigner {
     }
 }
 
+#[cfg_attr(not(feature = "wasm"), async_trait)]
+#[cfg_attr(feature = "wasm", async_trait(?Send))]
+impl Signer for BasicSigner {
+    async fn accounts(&self) -> VdrResult<Vec<Address>> {
+        Ok(self
+            .keys
+            .keys()
+            .map(|account| Address::from(account.as_str()))
+            .collect())
+    }
+
+    async fn sign(&self, digest: &[u8], account: &Address) -> VdrResult<SignatureData> {
+        BasicSigner::sign(self, digest, account.as_ref())
+    }
+}
+
 impl Debug for BasicSigner {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(f, r#"BasicSigner {{ }}"#)
@@ -114,6 +285,7 @@ impl Debug for KeyPair {
 #[cfg(test)]
 pub mod test {
     use super::*;
+    use crate::signer::mnemonic::test::TEST_MNEMONIC;
     use once_cell::sync::Lazy;
 
     pub static TRUSTEE_ACC: Lazy<Address> =
@@ -157,4 +329,125 @@ pub mod test {
         ];
         assert_eq!(expected, signature.signature);
     }
+
+    #[test]
+    fn create_key_from_mnemonic_test() {
+        let mut signer = BasicSigner::new().unwrap();
+
+        let (first, _) = signer
+            .create_key_from_mnemonic(TEST_MNEMONIC, None, 0)
+            .unwrap();
+        let (second, _) = signer
+            .create_key_from_mnemonic(TEST_MNEMONIC, None, 1)
+            .unwrap();
+
+        assert_eq!(
+            Address::from("0xf39fd6e51aad88f6f4ce6ab8827279cfffb92266"),
+            first
+        );
+        assert_eq!(
+            Address::from("0x70997970c51812dc3a010c7d01b50e0d17dc79c8"),
+            second
+        );
+        signer.key_for_account(first.as_ref()).unwrap();
+        signer.key_for_account(second.as_ref()).unwrap();
+    }
+
+    #[test]
+    fn create_key_from_mnemonic_with_passphrase_test() {
+        let mut signer = BasicSigner::new().unwrap();
+
+        let (account, _) = signer
+            .create_key_from_mnemonic(TEST_MNEMONIC, Some("passphrase"), 0)
+            .unwrap();
+
+        assert_ne!(
+            Address::from("0xf39fd6e51aad88f6f4ce6ab8827279cfffb92266"),
+            account
+        );
+    }
+
+    #[test]
+    fn create_key_from_invalid_mnemonic_test() {
+        let err = BasicSigner::new()
+            .unwrap()
+            .create_key_from_mnemonic("test test test", None, 0)
+            .unwrap_err();
+
+        assert!(matches!(err, VdrError::SignerInvalidMnemonic(_)));
+    }
+
+    fn keystore_dir(name: &str) -> PathBuf {
+        let dir =
+            std::env::temp_dir().join(format!("indy-besu-vdr-{}-{}", name, rand::random::<u32>()));
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[test]
+    fn keystore_export_import_test() {
+        let dir = keystore_dir("keystore");
+        let path = basic_signer()
+            .export_keystore(TRUSTEE_ACC.as_ref(), &dir, "password")
+            .unwrap();
+
+        let mut signer = BasicSigner::new().unwrap();
+        let account = signer.import_keystore(&path, "password").unwrap();
+
+        let data = [1; 32];
+        assert_eq!(*TRUSTEE_ACC, account);
+        assert_eq!(
+            basic_signer().sign(&data, TRUSTEE_ACC.as_ref()).unwrap(),
+            signer.sign(&data, TRUSTEE_ACC.as_ref()).unwrap()
+        );
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn keystore_wrong_password_test() {
+        let dir = keystore_dir("keystore");
+        let path = basic_signer()
+            .export_keystore(TRUSTEE_ACC.as_ref(), &dir, "password")
+            .unwrap();
+
+        let err = BasicSigner::new()
+            .unwrap()
+            .import_keystore(&path, "wrong")
+            .unwrap_err();
+
+        assert!(matches!(err, VdrError::SignerInvalidKeystore(_)));
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn keystore_dir_test() {
+        let dir = keystore_dir("keystore-dir");
+        let mut signer = basic_signer();
+        let (account, _) = signer.create_key(None).unwrap();
+        signer.save_keystore_dir(&dir, "password").unwrap();
+
+        let mut accounts = BasicSigner::new()
+            .unwrap()
+            .load_keystore_dir(&dir, "password")
+            .unwrap();
+        accounts.sort_by(|a, b| a.as_ref().cmp(b.as_ref()));
+
+        let mut expected = vec![TRUSTEE_ACC.clone(), account];
+        expected.sort_by(|a, b| a.as_ref().cmp(b.as_ref()));
+        assert_eq!(expected, accounts);
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[async_std::test]
+    async fn basic_signer_trait_test() {
+        let basic_signer = basic_signer();
+        let signer: &dyn Signer = &basic_signer;
+        let data = [1; 32];
+
+        assert_eq!(vec![TRUSTEE_ACC.clone()], signer.accounts().await.unwrap());
+        assert_eq!(
+            basic_signer.sign(&data, TRUSTEE_ACC.as_ref()).unwrap(),
+            signer.sign(&data, &TRUSTEE_ACC).await.unwrap()
+        );
+    }
 }
@@ -0,0 +1,237 @@
+// Copyright (c) 2024 DSR Corporation, Denver, Colorado.
+// https://www.dsr-corporation.com
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::error::{VdrError, VdrResult};
+
+use bip39::Mnemonic;
+use hmac::{Hmac, Mac};
+use secp256k1::{All, PublicKey, Scalar, Secp256k1, SecretKey};
+use sha2::Sha512;
+use zeroize::{Zeroize, Zeroizing};
+
+/// BIP-44 derivation path of Ethereum accounts without the trailing account index
+pub const ETHEREUM_DERIVATION_PATH: &str = "m/44'/60'/0'/0";
+
+const HARDENED_INDEX: u32 = 0x8000_0000;
+const MASTER_KEY_SALT: &[u8] = b"Bitcoin seed";
+const MNEMONIC_ENTROPY_SIZE: usize = 32;
+
+/// Generate a new random 24 words BIP-39 mnemonic (English word list)
+pub(crate) fn generate_mnemonic() -> VdrResult<String> {
+    let entropy: [u8; MNEMONIC_ENTROPY_SIZE] = rand::random();
+    Ok(Mnemonic::from_entropy(&entropy)?.to_string())
+}
+
+/// Derive the private key from the BIP-39 mnemonic and optional passphrase using BIP-32 derivation path
+pub(crate) fn derive_private_key(
+    secp: &Secp256k1<All>,
+    mnemonic: &str,
+    passphrase: &str,
+    path: &str,
+) -> VdrResult<SecretKey> {
+    let seed = Zeroizing::new(Mnemonic::parse(mnemonic)?.to_seed(passphrase));
+    derive_private_key_from_seed(secp, seed.as_slice(), path)
+}
+
+fn derive_private_key_from_seed(
+    secp: &Secp256k1<All>,
+    seed: &[u8],
+    path: &str,
+) -> VdrResult<SecretKey> {
+    let indexes = parse_derivation_path(path)?;
+    let (mut private_key, mut chain_code) = hmac_sha512(MASTER_KEY_SALT, seed)?;
+
+    for index in indexes {
+        let mut data = Zeroizing::new(Vec::with_capacity(37));
+        if index >= HARDENED_INDEX {
+            data.push(0);
+            data.extend_from_slice(&private_key.secret_bytes());
+        } else {
+            data.extend_from_slice(&PublicKey::from_secret_key(secp, &private_key).serialize());
+        }
+        data.extend_from_slice(&index.to_be_bytes());
+
+        let (mut tweak, child_chain_code) = hmac_sha512(chain_code.as_slice(), &data)?;
+        let child_private_key = private_key.add_tweak(&Scalar::from(tweak));
+        tweak.non_secure_erase();
+        private_key.non_secure_erase();
+        private_key = child_private_key?;
+        chain_code = child_chain_code;
+    }
+
+    Ok(private_key)
+}
+
+/// Parse path in the form `m/44'/60'/0'/0/0` (hardened indexes are marked with `'` or `h`)
+fn parse_derivation_path(path: &str) -> VdrResult<Vec<u32>> {
+    let invalid_path = || VdrError::CommonInvalidData(format!("Invalid derivation path: {}", path));
+
+    let mut parts = path.split('/');
+    if parts.next() != Some("m") {
+        return Err(invalid_path());
+    }
+
+    parts
+        .map(|part| {
+            let (index, hardened) = match part.strip_suffix(['\'', 'h']) {
+                Some(index) => (index, true),
+                None => (part, false),
+            };
+            let index = index.parse::<u32>().map_err(|_| invalid_path())?;
+            if index >= HARDENED_INDEX {
+                return Err(invalid_path());
+            }
+            Ok(if hardened {
+                index + HARDENED_INDEX
+            } else {
+                index
+            })
+        })
+        .collect()
+}
+
+fn hmac_sha512(key: &[u8], data: &[u8]) -> VdrResult<(SecretKey, Zeroizing<[u8; 32]>)> {
+    let mut mac = Hmac::<Sha512>::new_from_slice(key)
+        .map_err(|err| VdrError::SignerUnexpectedError(err.to_string()))?;
+    mac.update(data);
+    let mut result = mac.finalize().into_bytes();
+
+    let private_key = SecretKey::from_slice(&result[..32]);
+    let mut chain_code = Zeroizing::new([0; 32]);
+    chain_code.copy_from_slice(&result[32..]);
+    result.as_mut_slice().zeroize();
+    Ok((private_key?, chain_code))
+}
+
+#[cfg(test)]
+pub mod test {
+    use super::*;
+    use rstest::rstest;
+
+    pub const TEST_MNEMONIC: &str = "test test test test test test test test test test test junk";
+
+    const SEED_1: &str = "000102030405060708090a0b0c0d0e0f";
+    const SEED_2: &str = "fffcf9f6f3f0edeae7e4e1dedbd8d5d2cfccc9c6c3c0bdbab7b4b1aeaba8a5a29f9c999693908d8a8784817e7b7875726f6c696663605d5a5754514e4b484542";
+    const SEED_3: &str = "4b381541583be4423346c643850da4b320e46a87ae3d2a4e6da11eba819cd4acba45d239319ac14f863b8d5ab5a0d0c64d2e8a1e7d1457df2e5a3c51c73235be";
+
+    // BIP-32 test vectors 1-3
+    #[rstest]
+    #[case::vector_1_master(
+        SEED_1,
+        "m",
+        "e8f32e723decf4051aefac8e2c93c9c5b214313817cdb01a1494b917c8436b35"
+    )]
+    #[case::vector_1_hardened(
+        SEED_1,
+        "m/0'",
+        "edb2e14f9ee77d26dd93b4ecede8d16ed408ce149b6cd80b0715a2d911a0afea"
+    )]
+    #[case::vector_1_normal(
+        SEED_1,
+        "m/0'/1",
+        "3c6cb8d0f6a264c91ea8b5030fadaa8e538b020f0a387421a12de9319dc93368"
+    )]
+    #[case::vector_1_hardened_with_h(
+        SEED_1,
+        "m/0h/1/2h",
+        "cbce0d719ecf7431d88e6a89fa1483e02e35092af60c042b1df2ff59fa424dca"
+    )]
+    #[case::vector_1_depth_4(
+        SEED_1,
+        "m/0'/1/2'/2",
+        "0f479245fb19a38a1954c5c7c0ebab2f9bdfd96a17563ef28a6a4b1a2a764ef4"
+    )]
+    #[case::vector_1_depth_5(
+        SEED_1,
+        "m/0'/1/2'/2/1000000000",
+        "471b76e389e528d6de6d816857e012c5455051cad6660850e58372a6c3e6e7c8"
+    )]
+    #[case::vector_2_master(
+        SEED_2,
+        "m",
+        "4b03d6fc340455b363f51020ad3ecca4f0850280cf436c70c727923f6db46c3e"
+    )]
+    #[case::vector_2_normal(
+        SEED_2,
+        "m/0",
+        "abe74a98f6c7eabee0428f53798f0ab8aa1bd37873999041703c742f15ac7e1e"
+    )]
+    #[case::vector_2_max_hardened(
+        SEED_2,
+        "m/0/2147483647'",
+        "877c779ad9687164e9c2f4f0f4ff0340814392330693ce95a58fe18fd52e6e93"
+    )]
+    #[case::vector_2_depth_3(
+        SEED_2,
+        "m/0/2147483647'/1",
+        "704addf544a06e5ee4bea37098463c23613da32020d604506da8c0518e1da4b7"
+    )]
+    #[case::vector_2_depth_4(
+        SEED_2,
+        "m/0/2147483647'/1/2147483646'",
+        "f1c7c871a54a804afe328b4c83a1c33b8e5ff48f5087273f04efa83b247d6a2d"
+    )]
+    #[case::vector_2_depth_5(
+        SEED_2,
+        "m/0/2147483647'/1/2147483646'/2",
+        "bb7d39bdb83ecf58f2fd82b6d918341cbef428661ef01ab97c28a4842125ac23"
+    )]
+    #[case::vector_3_master_with_leading_zero(
+        SEED_3,
+        "m",
+        "00ddb80b067e0d4993197fe10f2657a844a384589847602d56f0c629c81aae32"
+    )]
+    #[case::vector_3_hardened(
+        SEED_3,
+        "m/0'",
+        "491f7a2eebc7b57028e0d3faa0acda02e75c33b03c48fb288c41e2ea44e1daef"
+    )]
+    fn derive_private_key_from_seed_test(
+        #[case] seed: &str,
+        #[case] path: &str,
+        #[case] expected: &str,
+    ) {
+        let seed = hex::decode(seed).unwrap();
+
+        let private_key = derive_private_key_from_seed(&Secp256k1::new(), &seed, path).unwrap();
+
+        assert_eq!(expected, hex::encode(private_key.secret_bytes()));
+    }
+
+    #[test]
+    fn derive_private_key_from_mnemonic_test() {
+        let private_key = derive_private_key(
+            &Secp256k1::new(),
+            TEST_MNEMONIC,
+            "",
+            &format!("{}/0", ETHEREUM_DERIVATION_PATH),
+        )
+        .unwrap();
+
+        assert_eq!(
+            "ac0974bec39a17e36ba4a6b4d238ff944bacb478cbed5efcae784d7bf4f2ff80",
+            hex::encode(private_key.secret_bytes())
+        );
+    }
+
+    #[rstest]
+    #[case::empty("")]
+    #[case::missing_master("44'/60'")]
+    #[case::not_number("m/44'/eth'")]
+    #[case::out_of_range("m/2147483648")]
+    fn parse_invalid_derivation_path_test(#[case] path: &str) {
+        assert!(matches!(
+            parse_derivation_path(path).unwrap_err(),
+            VdrError::CommonInvalidData(_)
+        ));
+    }
+
+    #[test]
+    fn generate_mnemonic_test() {
+        let mnemonic = generate_mnemonic().unwrap();
+
+        assert_eq!(24, mnemonic.split_whitespace().count());
+        Mnemonic::parse(&mnemonic).unwrap();
+    }
+}
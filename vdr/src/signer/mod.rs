@@ -2,6 +2,43 @@
 // https://www.dsr-corporation.com
 // SPDX-License-Identifier: Apache-2.0
 
+#[cfg(feature = "basic_signer")]
 pub mod basic_signer;
+#[cfg(feature = "basic_signer")]
+pub mod mnemonic;
 
+#[cfg(feature = "basic_signer")]
 pub use basic_signer::{BasicSigner, KeyPair};
+
+use crate::{
+    error::VdrResult,
+    types::{Address, SignatureData},
+};
+use async_trait::async_trait;
+use std::fmt::Debug;
+
+#[cfg(test)]
+use mockall::automock;
+
+/// Signer holding the keys of ledger accounts.
+///     Implement it to sign transactions with keys stored in an external KMS/HSM
+#[cfg_attr(test, automock)]
+#[cfg_attr(not(feature = "wasm"), async_trait)]
+#[cfg_attr(feature = "wasm", async_trait(?Send))]
+pub trait Signer: Sync + Send + Debug {
+    /// Get the accounts which keys are available for signing
+    ///
+    /// # Returns
+    /// list of account addresses
+    async fn accounts(&self) -> VdrResult<Vec<Address>>;
+
+    /// Sign the message digest with the key of the account
+    ///
+    /// # Params
+    /// - `digest` 32 bytes hash of the message to sign (returned by `get_signing_bytes` of transaction)
+    /// - `account` [Address] address of the account to sign with
+    ///
+    /// # Returns
+    /// recoverable ECDSA signature
+    async fn sign(&self, digest: &[u8], account: &Address) -> VdrResult<SignatureData>;
+}
@@ -14,7 +14,7 @@ use crate::{
         did::{did_indy_registry, types::did_doc::test::did_doc, DidRecord, DID, ETHR_DID_METHOD},
     },
     signer::basic_signer::{test::basic_signer, BasicSigner},
-    types::{Address, SignatureData, Transaction},
+    types::{Address, SignatureData, Transaction, TransactionReceipt},
     LedgerClient,
 };
 
@@ -26,7 +26,7 @@ mod helpers {
         client: &LedgerClient,
         mut transaction: Transaction,
         signer: &BasicSigner,
-    ) -> String {
+    ) -> TransactionReceipt {
         let sign_bytes = transaction.get_signing_bytes().unwrap();
         let from = transaction.from.as_ref().unwrap();
         let signature = signer.sign(&sign_bytes, from.as_ref()).unwrap();
@@ -67,7 +67,7 @@ mod helpers {
         assignee_account: &Address,
         role_to_assign: &Role,
         signer: &BasicSigner,
-    ) -> String {
+    ) -> TransactionReceipt {
         let transaction = role_control::build_assign_role_transaction(
             client,
             &TRUSTEE_ACCOUNT,
@@ -957,7 +957,7 @@ mod role {
         revokee_account: &Address,
         role_to_revoke: &Role,
         signer: &BasicSigner,
-    ) -> String {
+    ) -> TransactionReceipt {
         let mut transaction = role_control::build_revoke_role_transaction(
             client,
             &TRUSTEE_ACCOUNT,
@@ -1043,7 +1043,7 @@ mod validator {
         client: &LedgerClient,
         new_validator_address: &Address,
         signer: &BasicSigner,
-    ) -> String {
+    ) -> TransactionReceipt {
         let transaction = validator_control::build_add_validator_transaction(
             &client,
             &TRUSTEE_ACCOUNT,
@@ -1058,7 +1058,7 @@ mod validator {
         client: &LedgerClient,
         validator_address: &Address,
         signer: &BasicSigner,
-    ) -> String {
+    ) -> TransactionReceipt {
         // write
         let transaction = validator_control::build_remove_validator_transaction(
             &client,
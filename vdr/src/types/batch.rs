@@ -0,0 +1,43 @@
+// Copyright (c) 2024 DSR Corporation, Denver, Colorado.
+// https://www.dsr-corporation.com
+// SPDX-License-Identifier: Apache-2.0
+
+use serde_derive::{Deserialize, Serialize};
+
+use crate::types::{EventLog, EventQuery, Transaction};
+
+/// Read request sent to the ledger as a part of a single JSON-RPC batch
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum BatchRequest {
+    /// Read transaction executed with `eth_call`
+    Call(Transaction),
+    /// Events query executed with `eth_getLogs`
+    Events(EventQuery),
+}
+
+/// Result of a single [BatchRequest] execution
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum BatchResponse {
+    /// Result data of the read transaction execution
+    Call(Vec<u8>),
+    /// Log events matching to the query
+    Events(Vec<EventLog>),
+}
+
+impl BatchResponse {
+    /// Get result data of the read transaction execution (`None` for events query response)
+    pub fn into_call(self) -> Option<Vec<u8>> {
+        match self {
+            BatchResponse::Call(data) => Some(data),
+            BatchResponse::Events(_) => None,
+        }
+    }
+
+    /// Get log events received for the events query (`None` for read transaction response)
+    pub fn into_events(self) -> Option<Vec<EventLog>> {
+        match self {
+            BatchResponse::Events(events) => Some(events),
+            BatchResponse::Call(_) => None,
+        }
+    }
+}
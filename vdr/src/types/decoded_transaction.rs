@@ -0,0 +1,130 @@
+// Copyright (c) 2024 DSR Corporation, Denver, Colorado.
+// https://www.dsr-corporation.com
+// SPDX-License-Identifier: Apache-2.0
+
+use ethabi::{Param, Token};
+use serde_derive::{Deserialize, Serialize};
+
+use crate::{
+    types::ContractParam, CredentialDefinition, DidDocument, RevocationRegistryDefinition,
+    RevocationRegistryEntry, Schema,
+};
+
+/// Contract method call encoded by a transaction or endorsing data
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DecodedTransaction {
+    /// Name of the called contract
+    pub contract: String,
+    /// Name of the called contract method
+    pub method: String,
+    /// Decoded method parameters
+    pub params: Vec<DecodedParam>,
+    /// Parsed object passed as the method payload (DID Document, Schema, etc.)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payload: Option<DecodedPayload>,
+}
+
+/// Decoded contract method parameter
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DecodedParam {
+    /// Name of the parameter in the contract ABI
+    pub name: String,
+    /// ABI type of the parameter (e.g. `address`, `bytes32`)
+    #[serde(rename = "type")]
+    pub type_: String,
+    /// Human-readable parameter value (addresses and bytes are `0x` prefixed hex)
+    pub value: String,
+}
+
+/// Known object passed to a contract method as JSON bytes
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum DecodedPayload {
+    DidDocument(DidDocument),
+    Schema(Schema),
+    CredentialDefinition(CredentialDefinition),
+    RevocationRegistryDefinition(RevocationRegistryDefinition),
+    RevocationRegistryEntry(RevocationRegistryEntry),
+}
+
+impl DecodedTransaction {
+    /// Build decoded call from the contract method inputs and parameter values
+    pub(crate) fn new(
+        contract: &str,
+        method: &str,
+        inputs: &[Param],
+        values: &[ContractParam],
+    ) -> DecodedTransaction {
+        let params = inputs
+            .iter()
+            .zip(values.iter())
+            .map(|(input, value)| DecodedParam::new(input, value))
+            .collect();
+        let payload = inputs
+            .iter()
+            .zip(values.iter())
+            .find_map(|(input, value)| DecodedPayload::parse(&input.name, value));
+
+        DecodedTransaction {
+            contract: contract.to_string(),
+            method: method.to_string(),
+            params,
+            payload,
+        }
+    }
+}
+
+impl DecodedParam {
+    /// Build decoded parameter from the ABI parameter definition and its value
+    pub(crate) fn new(input: &Param, value: &ContractParam) -> DecodedParam {
+        DecodedParam {
+            name: input.name.to_string(),
+            type_: input.kind.to_string(),
+            value: format_token(value),
+        }
+    }
+}
+
+impl DecodedPayload {
+    /// Parse the value of the contract method parameter if it is a known JSON payload
+    fn parse(name: &str, value: &ContractParam) -> Option<DecodedPayload> {
+        let bytes = match value {
+            Token::Bytes(bytes) => bytes,
+            _ => return None,
+        };
+        match name {
+            "document" => serde_json::from_slice(bytes)
+                .ok()
+                .map(DecodedPayload::DidDocument),
+            "schema" => serde_json::from_slice(bytes)
+                .ok()
+                .map(DecodedPayload::Schema),
+            "credDef" => serde_json::from_slice(bytes)
+                .ok()
+                .map(DecodedPayload::CredentialDefinition),
+            "revRegDef" => serde_json::from_slice(bytes)
+                .ok()
+                .map(DecodedPayload::RevocationRegistryDefinition),
+            "revRegEntry" => serde_json::from_slice(bytes)
+                .ok()
+                .map(DecodedPayload::RevocationRegistryEntry),
+            _ => None,
+        }
+    }
+}
+
+fn format_token(token: &Token) -> String {
+    match token {
+        Token::Address(address) => format!("0x{}", hex::encode(address.as_bytes())),
+        Token::Bytes(bytes) | Token::FixedBytes(bytes) => format!("0x{}", hex::encode(bytes)),
+        Token::Array(tokens) | Token::FixedArray(tokens) | Token::Tuple(tokens) => format!(
+            "[{}]",
+            tokens
+                .iter()
+                .map(format_token)
+                .collect::<Vec<String>>()
+                .join(", ")
+        ),
+        token => token.to_string(),
+    }
+}
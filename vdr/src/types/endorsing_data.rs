@@ -10,11 +10,11 @@ use std::fmt::Debug;
 
 use crate::{
     types::{ContractParam, MethodStringParam, MethodUintBytesParam},
-    Address, LedgerClient, Nonce, SignatureData, VdrError, VdrResult,
+    Address, LedgerClient, Nonce, SignatureData, Signer, VdrError, VdrResult,
 };
 
 /// Definition of transaction endorsing data for off-chain author signature
-#[derive(Debug, Default, Serialize, Deserialize)]
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
 pub struct TransactionEndorsingData {
     pub to: Address,
     pub from: Address,
@@ -66,6 +66,44 @@ impl TransactionEndorsingData {
         self.signature = Some(signature_data)
     }
 
+    /// Sign the endorsing data with the key of the author account and set the signature
+    ///
+    /// # Params
+    /// - `signer`: [Signer] - signer holding the key of the transaction author
+    #[logfn(Info)]
+    #[logfn_inputs(Debug)]
+    pub async fn sign(&mut self, signer: &dyn Signer) -> VdrResult<()> {
+        let signing_bytes = self.get_signing_bytes()?;
+        let signature = signer.sign(&signing_bytes, &self.from).await?;
+        self.set_signature(signature);
+        Ok(())
+    }
+
+    /// Recover the address of the account which signed the endorsing data
+    ///
+    /// # Returns
+    /// address of the signer account
+    #[logfn(Info)]
+    #[logfn_inputs(Debug)]
+    pub fn recover_signer(&self) -> VdrResult<Address> {
+        let signature = self.signature.as_ref().ok_or_else(|| {
+            VdrError::ClientInvalidEndorsementData("Missing author signature".to_string())
+        })?;
+        signature.recover(&self.get_signing_bytes()?)
+    }
+
+    /// Check that the endorsing data is signed by the author account (`from`).
+    ///     Should be checked by endorser before submitting the endorsement transaction
+    ///
+    /// # Returns
+    /// whether the signature belongs to the transaction author
+    #[logfn(Info)]
+    #[logfn_inputs(Debug)]
+    pub fn verify_signature(&self) -> VdrResult<bool> {
+        let signer = self.recover_signer()?;
+        Ok(signer.as_ref().eq_ignore_ascii_case(self.from.as_ref()))
+    }
+
     /// Serialize transaction endorsement as JSON string
     #[logfn(Trace)]
     #[logfn_inputs(Trace)]
@@ -169,3 +207,55 @@ impl TransactionEndorsingDataBuilder {
         })
     }
 }
+
+#[cfg(test)]
+pub mod test {
+    use super::*;
+    use crate::{
+        client::client::test::{CONFIG, TRUSTEE_ACCOUNT},
+        contracts::network::test::VALIDATOR_ADDRESS,
+        types::signature::test::trustee_signature,
+    };
+
+    fn endorsing_data(from: &Address) -> TransactionEndorsingData {
+        let mut data = TransactionEndorsingData {
+            to: CONFIG.contracts.validator_control.address.clone(),
+            from: TRUSTEE_ACCOUNT.clone(),
+            nonce: Some(Nonce::from(1)),
+            method: "addValidator".to_string(),
+            params: vec![(&*VALIDATOR_ADDRESS).try_into().unwrap()],
+            ..TransactionEndorsingData::default()
+        };
+        data.set_signature(trustee_signature(&data.get_signing_bytes().unwrap()));
+        data.from = from.clone();
+        data
+    }
+
+    #[test]
+    fn verify_author_signature_test() {
+        let data = endorsing_data(&TRUSTEE_ACCOUNT);
+
+        assert_eq!(*TRUSTEE_ACCOUNT, data.recover_signer().unwrap());
+        assert!(data.verify_signature().unwrap());
+    }
+
+    #[test]
+    fn verify_signature_of_other_author_test() {
+        // signing bytes include `from`, so the signature does not match the changed author
+        let data = endorsing_data(&VALIDATOR_ADDRESS);
+
+        assert!(!data.verify_signature().unwrap());
+    }
+
+    #[test]
+    fn verify_missing_signature_test() {
+        let data = TransactionEndorsingData {
+            signature: None,
+            ..endorsing_data(&TRUSTEE_ACCOUNT)
+        };
+
+        let err = data.verify_signature().unwrap_err();
+
+        assert!(matches!(err, VdrError::ClientInvalidEndorsementData(_)));
+    }
+}
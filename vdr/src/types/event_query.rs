@@ -12,30 +12,85 @@ use log_derive::{logfn, logfn_inputs};
 use serde_derive::{Deserialize, Serialize};
 use std::fmt::Debug;
 
+/// Maximal number of topics of a log event (event signature and up to three indexed parameters)
+pub const MAX_EVENT_TOPICS: usize = 4;
+
 /// Definition of query object to query logged events from the ledger
 #[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
-#[serde(rename_all = "camelCase")]
+#[serde(rename_all = "camelCase", from = "EventQueryObject")]
 pub struct EventQuery {
-    pub address: Address,
+    /// Addresses of contracts emitted the events: events of any listed contract match the query.
+    ///     At least one address is required, queries without addresses are rejected
+    pub addresses: Vec<Address>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub from_block: Option<Block>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub to_block: Option<Block>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub event_signature: Option<String>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub event_filter: Option<String>,
+    /// Filters of the event topics by position (up to [MAX_EVENT_TOPICS]):
+    ///     the first topic is the event signature, the next ones are indexed event parameters.
+    ///     `None` matches any topic value, otherwise the topic must be equal to one of the listed values
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub topics: Vec<Option<Vec<String>>>,
 }
 
-#[derive(Clone, Debug, Default, PartialEq)]
-pub(crate) struct EventQueryBuilder {
-    contract: String,
+// Serialized event query accepting the previous query format as well:
+//  single `address`, `eventSignature` (first topic) and `eventFilter` (second topic)
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct EventQueryObject {
+    #[serde(default)]
+    addresses: Vec<Address>,
+    #[serde(default)]
+    address: Option<Address>,
+    #[serde(default)]
     from_block: Option<Block>,
+    #[serde(default)]
     to_block: Option<Block>,
+    #[serde(default)]
+    topics: Vec<Option<Vec<String>>>,
+    #[serde(default)]
     event_signature: Option<String>,
+    #[serde(default)]
     event_filter: Option<String>,
 }
 
+impl From<EventQueryObject> for EventQuery {
+    fn from(query: EventQueryObject) -> Self {
+        let mut addresses = query.addresses;
+        if let Some(address) = query.address {
+            if !addresses.contains(&address) {
+                addresses.push(address);
+            }
+        }
+
+        let mut topics = query.topics;
+        if topics.is_empty() {
+            topics = vec![
+                query.event_signature.map(|topic| vec![topic]),
+                query.event_filter.map(|topic| vec![topic]),
+            ];
+            while let Some(None) = topics.last() {
+                topics.pop();
+            }
+        }
+
+        EventQuery {
+            addresses,
+            from_block: query.from_block,
+            to_block: query.to_block,
+            topics,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub(crate) struct EventQueryBuilder {
+    contracts: Vec<String>,
+    from_block: Option<Block>,
+    to_block: Option<Block>,
+    topics: [Vec<String>; MAX_EVENT_TOPICS],
+}
+
 impl EventQueryBuilder {
     #[logfn(Trace)]
     #[logfn_inputs(Trace)]
@@ -46,7 +101,15 @@ impl EventQueryBuilder {
     #[logfn(Trace)]
     #[logfn_inputs(Trace)]
     pub fn set_contract(mut self, contract: &str) -> EventQueryBuilder {
-        self.contract = contract.to_string();
+        self.contracts = vec![contract.to_string()];
+        self
+    }
+
+    /// Add contract which events match the query in addition to the already set ones
+    #[logfn(Trace)]
+    #[logfn_inputs(Trace)]
+    pub fn add_contract(mut self, contract: &str) -> EventQueryBuilder {
+        self.contracts.push(contract.to_string());
         self
     }
 
@@ -68,27 +131,66 @@ impl EventQueryBuilder {
     #[logfn_inputs(Trace)]
     #[allow(unused)]
     pub fn set_event_signature(mut self, event_signature: String) -> EventQueryBuilder {
-        self.event_signature = Some(event_signature);
+        self.topics[0] = vec![event_signature];
         self
     }
 
     #[logfn(Trace)]
     #[logfn_inputs(Trace)]
-    pub fn set_event_filer(mut self, event_filter: String) -> EventQueryBuilder {
-        self.event_filter = Some(event_filter);
+    pub fn set_event_filter(mut self, event_filter: String) -> EventQueryBuilder {
+        self.topics[1] = vec![event_filter];
         self
     }
 
+    /// Add alternative value for the topic at the given position
+    #[logfn(Trace)]
+    #[logfn_inputs(Trace)]
+    pub fn add_topic(mut self, position: usize, topic: String) -> VdrResult<EventQueryBuilder> {
+        let topics = self.topics.get_mut(position).ok_or_else(|| {
+            let vdr_error = VdrError::CommonInvalidData(format!(
+                "Event topic position {} exceeds the maximal number of topics {}",
+                position, MAX_EVENT_TOPICS
+            ));
+
+            warn!("Error: {} during adding event topic", vdr_error);
+
+            vdr_error
+        })?;
+        topics.push(topic);
+        Ok(self)
+    }
+
     #[logfn(Trace)]
     #[logfn_inputs(Trace)]
     pub fn build(self, client: &LedgerClient) -> VdrResult<EventQuery> {
-        let contract = client.contract(&self.contract)?;
+        if self.contracts.is_empty() {
+            let vdr_error = VdrError::CommonInvalidData(
+                "Event query must contain at least one contract".to_string(),
+            );
+
+            warn!("Error: {} during building event query", vdr_error);
+
+            return Err(vdr_error);
+        }
+
+        let addresses = self
+            .contracts
+            .iter()
+            .map(|contract| Ok(client.contract(contract)?.address().to_owned()))
+            .collect::<VdrResult<Vec<Address>>>()?;
+        let mut topics: Vec<Option<Vec<String>>> = self
+            .topics
+            .into_iter()
+            .map(|topics| (!topics.is_empty()).then_some(topics))
+            .collect();
+        while let Some(None) = topics.last() {
+            topics.pop();
+        }
         let query = EventQuery {
-            address: contract.address().to_owned(),
+            addresses,
             from_block: self.from_block,
             to_block: self.to_block,
-            event_signature: self.event_signature,
-            event_filter: self.event_filter,
+            topics,
         };
         Ok(query)
     }
@@ -138,6 +240,17 @@ impl EventParser {
         }
 
         let contract = client.contract(&self.contract)?;
+        if !log.is_emitted_by(contract.address()) {
+            let vdr_error = VdrError::ContractInvalidResponseData(format!(
+                "Event log is not emitted by {} contract",
+                self.contract
+            ));
+
+            warn!("Error: {:?} during event log parsing", vdr_error);
+
+            return Err(vdr_error);
+        }
+
         let event = contract.event(&self.event)?;
         let raw_log = RawLog {
             topics: log.topics.clone(),
@@ -159,10 +272,26 @@ impl EventParser {
 }
 
 #[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
 pub struct EventLog {
+    /// Address of the contract emitted the event (`None` if unknown)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub address: Option<Address>,
     pub topics: Vec<Hash>,
     pub data: Vec<u8>,
     pub block: Block,
+    /// Hash of the transaction emitted the event (`None` for pending logs)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub transaction_hash: Option<Vec<u8>>,
+    /// Index of the log in the block (`None` for pending logs)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub log_index: Option<u64>,
+    /// Hash of the block containing the log (`None` for pending logs)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub block_hash: Option<Vec<u8>>,
+    /// Whether the log was removed from the chain due to the chain reorganization
+    #[serde(default)]
+    pub removed: bool,
 }
 
 impl EventLog {
@@ -170,12 +299,158 @@ impl EventLog {
     #[logfn_inputs(Trace)]
     pub fn new(topics: Vec<Vec<u8>>, data: Vec<u8>, block: u64) -> EventLog {
         EventLog {
+            address: None,
             topics: topics
                 .iter()
                 .map(|topic| ethereum_types::H256::from_slice(topic))
                 .collect(),
             data,
             block: Block::from(block),
+            transaction_hash: None,
+            log_index: None,
+            block_hash: None,
+            removed: false,
         }
     }
+
+    /// Check whether the log could be emitted by the contract with the given address.
+    ///     Logs without known emitter address match any contract
+    pub(crate) fn is_emitted_by(&self, address: &Address) -> bool {
+        self.address.as_ref().map_or(true, |log_address| {
+            log_address.as_ref().eq_ignore_ascii_case(address.as_ref())
+        })
+    }
+}
+
+#[cfg(test)]
+pub mod test {
+    use super::*;
+    use crate::client::client::test::{mock_client, CONFIG};
+    use serde_json::json;
+
+    #[test]
+    fn build_event_query_with_topics_test() {
+        let client = mock_client();
+
+        let query = EventQueryBuilder::new()
+            .set_contract("EthereumExtDidRegistry")
+            .add_topic(2, "01".to_string())
+            .unwrap()
+            .add_topic(2, "02".to_string())
+            .unwrap()
+            .build(&client)
+            .unwrap();
+
+        let expected_query = EventQuery {
+            addresses: vec![CONFIG.contracts.ethereum_did_registry.address.clone()],
+            from_block: None,
+            to_block: None,
+            topics: vec![None, None, Some(vec!["01".to_string(), "02".to_string()])],
+        };
+        assert_eq!(expected_query, query);
+    }
+
+    #[test]
+    fn build_event_query_for_several_contracts_test() {
+        let client = mock_client();
+
+        let query = EventQueryBuilder::new()
+            .set_contract("EthereumExtDidRegistry")
+            .add_contract("IndyDidRegistry")
+            .build(&client)
+            .unwrap();
+
+        assert_eq!(
+            vec![
+                CONFIG.contracts.ethereum_did_registry.address.clone(),
+                CONFIG.contracts.indy_did_registry.address.clone(),
+            ],
+            query.addresses
+        );
+    }
+
+    #[test]
+    fn build_event_query_without_contract_test() {
+        let err = EventQueryBuilder::new().build(&mock_client()).unwrap_err();
+
+        assert!(matches!(err, VdrError::CommonInvalidData(_)));
+    }
+
+    #[test]
+    fn add_event_topic_exceeding_limit_test() {
+        let err = EventQueryBuilder::new()
+            .add_topic(MAX_EVENT_TOPICS, "01".to_string())
+            .unwrap_err();
+
+        assert!(matches!(err, VdrError::CommonInvalidData(_)));
+    }
+
+    #[test]
+    fn deserialize_event_query_test() {
+        let query: EventQuery = serde_json::from_value(json!({
+            "addresses": ["0x0000000000000000000000000000000000003333"],
+            "fromBlock": 1,
+            "topics": [null, ["01", "02"]]
+        }))
+        .unwrap();
+
+        let expected_query = EventQuery {
+            addresses: vec![Address::from("0x0000000000000000000000000000000000003333")],
+            from_block: Some(Block::from(1)),
+            to_block: None,
+            topics: vec![None, Some(vec!["01".to_string(), "02".to_string()])],
+        };
+        assert_eq!(expected_query, query);
+        assert_eq!(
+            query,
+            serde_json::from_value::<EventQuery>(serde_json::to_value(&query).unwrap()).unwrap()
+        );
+    }
+
+    #[test]
+    fn deserialize_event_query_in_previous_format_test() {
+        let query: EventQuery = serde_json::from_value(json!({
+            "address": "0x0000000000000000000000000000000000003333",
+            "toBlock": 10,
+            "eventFilter": "01"
+        }))
+        .unwrap();
+
+        let expected_query = EventQuery {
+            addresses: vec![Address::from("0x0000000000000000000000000000000000003333")],
+            from_block: None,
+            to_block: Some(Block::from(10)),
+            topics: vec![None, Some(vec!["01".to_string()])],
+        };
+        assert_eq!(expected_query, query);
+    }
+
+    #[test]
+    fn deserialize_event_log_without_transaction_details_test() {
+        let log: EventLog = serde_json::from_value(json!({
+            "topics": [],
+            "data": [1, 2, 3],
+            "block": 10
+        }))
+        .unwrap();
+
+        assert_eq!(EventLog::new(vec![], vec![1, 2, 3], 10), log);
+    }
+
+    #[test]
+    fn serialize_event_log_test() {
+        let log = EventLog {
+            transaction_hash: Some(vec![1; 32]),
+            log_index: Some(2),
+            block_hash: Some(vec![3; 32]),
+            removed: true,
+            ..EventLog::new(vec![], vec![], 10)
+        };
+
+        let value = serde_json::to_value(&log).unwrap();
+
+        assert_eq!(json!(2), value["logIndex"]);
+        assert_eq!(json!(true), value["removed"]);
+        assert_eq!(log, serde_json::from_value::<EventLog>(value).unwrap());
+    }
 }
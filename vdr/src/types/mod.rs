@@ -3,20 +3,31 @@
 // SPDX-License-Identifier: Apache-2.0
 
 mod address;
+mod batch;
 mod contract;
+mod decoded_transaction;
 mod endorsing_data;
 mod event_query;
+mod receipt;
 mod signature;
 mod status;
 pub(crate) mod transaction;
 
 pub use address::Address;
+pub use batch::{BatchRequest, BatchResponse};
 pub use contract::{ContractConfig, ContractParam, ContractSpec};
+pub use decoded_transaction::{DecodedParam, DecodedPayload, DecodedTransaction};
 pub use endorsing_data::TransactionEndorsingData;
-pub use event_query::{EventLog, EventQuery};
+pub use event_query::{EventLog, EventQuery, MAX_EVENT_TOPICS};
+pub use receipt::{
+    DecodedEvent, DecodedEventParam, ReceiptEvent, TransactionReceipt, TransactionStatus,
+};
 pub use signature::SignatureData;
 pub use status::{PingStatus, Status};
-pub use transaction::{Block, BlockDetails, Nonce, Transaction, TransactionType};
+pub use transaction::{
+    AccessListItem, Block, BlockDetails, Nonce, Transaction, TransactionEnvelopeType,
+    TransactionType,
+};
 
 pub(crate) use contract::{ContractEvent, ContractOutput, MethodStringParam, MethodUintBytesParam};
 pub(crate) use endorsing_data::TransactionEndorsingDataBuilder;
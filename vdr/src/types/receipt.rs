@@ -0,0 +1,186 @@
+// Copyright (c) 2024 DSR Corporation, Denver, Colorado.
+// https://www.dsr-corporation.com
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{
+    contracts::{
+        anoncreds::revocation_registry::parse_revocation_registry_event_response,
+        did::did_ethr_registry::parse_did_event_response,
+    },
+    types::EventLog,
+    Address, DidEvents, LedgerClient, RevocationRegistryEvents,
+};
+use ethabi::{Event, RawLog};
+use serde_derive::{Deserialize, Serialize};
+
+/// Execution status of a mined transaction
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub enum TransactionStatus {
+    Success,
+    Failed,
+}
+
+/// Receipt of a transaction included into a block
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TransactionReceipt {
+    /// Hash of the transaction
+    pub transaction_hash: Vec<u8>,
+    /// Whether the transaction execution succeeded or reverted
+    pub status: TransactionStatus,
+    /// Number of the block containing the transaction
+    pub block_number: u64,
+    /// Hash of the block containing the transaction
+    pub block_hash: Vec<u8>,
+    /// Amount of gas used by the transaction
+    pub gas_used: u64,
+    /// Address of the created contract if the transaction is a contract deployment
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub contract_address: Option<Address>,
+    /// Reason of the transaction revert (decoded with the contract errors if possible)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub revert_reason: Option<String>,
+    /// Raw log events emitted by the transaction
+    pub logs: Vec<EventLog>,
+    /// Log events decoded with the ABIs of the registered contracts
+    #[serde(default)]
+    pub events: Vec<ReceiptEvent>,
+}
+
+/// Log event emitted by the transaction
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub enum ReceiptEvent {
+    /// Event emitted by `EthereumExtDidRegistry` contract
+    Did(DidEvents),
+    /// Event emitted by `RevocationRegistry` contract
+    RevocationRegistry(RevocationRegistryEvents),
+    /// Event of other registered contract in the generic form
+    Other(DecodedEvent),
+}
+
+/// Log event decoded with the contract ABI
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct DecodedEvent {
+    /// Name of the contract emitted the event
+    pub contract: String,
+    /// Name of the event
+    pub name: String,
+    /// Decoded event parameters
+    pub params: Vec<DecodedEventParam>,
+}
+
+/// Decoded event parameter
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct DecodedEventParam {
+    pub name: String,
+    pub value: String,
+}
+
+impl ReceiptEvent {
+    /// Parse the log into a typed event if it is emitted by one of the known contracts,
+    ///  otherwise decode it with the ABIs of the registered contracts.
+    ///  Returns `None` if the log does not match to any registered contract event
+    pub(crate) fn parse(client: &LedgerClient, log: &EventLog) -> Option<ReceiptEvent> {
+        if let Ok(event) = parse_did_event_response(client, log) {
+            return Some(ReceiptEvent::Did(event));
+        }
+        if let Ok(event) = parse_revocation_registry_event_response(client, log) {
+            return Some(ReceiptEvent::RevocationRegistry(event));
+        }
+        client.decode_event(log).map(ReceiptEvent::Other)
+    }
+}
+
+impl DecodedEvent {
+    /// Decode the log if it was emitted by the given contract event, otherwise returns `None`
+    pub(crate) fn decode(contract: &str, event: &Event, log: &EventLog) -> Option<DecodedEvent> {
+        if event.anonymous || log.topics.first() != Some(&event.signature()) {
+            return None;
+        }
+
+        let raw_log = RawLog {
+            topics: log.topics.clone(),
+            data: log.data.clone(),
+        };
+        let parsed_log = event.parse_log(raw_log).ok()?;
+
+        Some(DecodedEvent {
+            contract: contract.to_string(),
+            name: event.name.clone(),
+            params: parsed_log
+                .params
+                .into_iter()
+                .map(|param| DecodedEventParam {
+                    name: param.name,
+                    value: param.value.to_string(),
+                })
+                .collect(),
+        })
+    }
+}
+
+#[cfg(test)]
+pub mod test {
+    use super::*;
+    use ethabi::{EventParam, Hash, ParamType};
+
+    fn event() -> Event {
+        Event {
+            name: "DIDAttributeChanged".to_string(),
+            inputs: vec![
+                EventParam {
+                    name: "identity".to_string(),
+                    kind: ParamType::Address,
+                    indexed: true,
+                },
+                EventParam {
+                    name: "validTo".to_string(),
+                    kind: ParamType::Uint(256),
+                    indexed: false,
+                },
+            ],
+            anonymous: false,
+        }
+    }
+
+    fn log(signature: Hash) -> EventLog {
+        let mut identity = vec![0; 12];
+        identity.extend_from_slice(&[1; 20]);
+        let mut valid_to = vec![0; 31];
+        valid_to.push(100);
+
+        EventLog::new(vec![signature.as_bytes().to_vec(), identity], valid_to, 1)
+    }
+
+    #[test]
+    fn decode_event_test() {
+        let event = event();
+
+        let decoded =
+            DecodedEvent::decode("EthereumExtDidRegistry", &event, &log(event.signature()))
+                .unwrap();
+
+        let expected = DecodedEvent {
+            contract: "EthereumExtDidRegistry".to_string(),
+            name: "DIDAttributeChanged".to_string(),
+            params: vec![
+                DecodedEventParam {
+                    name: "identity".to_string(),
+                    value: "0101010101010101010101010101010101010101".to_string(),
+                },
+                DecodedEventParam {
+                    name: "validTo".to_string(),
+                    value: "64".to_string(),
+                },
+            ],
+        };
+        assert_eq!(expected, decoded);
+    }
+
+    #[test]
+    fn decode_event_other_signature_test() {
+        let decoded = DecodedEvent::decode("EthereumExtDidRegistry", &event(), &log(Hash::zero()));
+
+        assert_eq!(None, decoded);
+    }
+}
@@ -2,9 +2,14 @@
 // https://www.dsr-corporation.com
 // SPDX-License-Identifier: Apache-2.0
 
-use crate::{types::ContractParam, VdrError};
+use crate::{error::VdrResult, types::ContractParam, Address, VdrError};
+use ethers_core::types::{RecoveryMessage, Signature, H256, U256};
+use log_derive::{logfn, logfn_inputs};
 use serde_derive::{Deserialize, Serialize};
 
+const SIGNATURE_LENGTH: usize = 64;
+const DIGEST_LENGTH: usize = 32;
+
 /// Definition of recoverable ECDSA signature
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct SignatureData {
@@ -15,6 +20,40 @@ pub struct SignatureData {
 }
 
 impl SignatureData {
+    /// Recover the address of the account which signed the message digest
+    ///
+    /// # Params
+    /// - `digest` 32 bytes hash of the signed message (returned by `get_signing_bytes` of transaction)
+    ///
+    /// # Returns
+    /// address of the signer account
+    #[logfn(Trace)]
+    #[logfn_inputs(Trace)]
+    pub fn recover(&self, digest: &[u8]) -> VdrResult<Address> {
+        if self.signature.len() != SIGNATURE_LENGTH || self.recovery_id > 1 {
+            return Err(VdrError::CommonInvalidData(
+                "Invalid signature: expected 64 bytes signature and recovery id 0 or 1".to_string(),
+            ));
+        }
+        if digest.len() != DIGEST_LENGTH {
+            return Err(VdrError::CommonInvalidData(
+                "Invalid message digest: expected 32 bytes hash".to_string(),
+            ));
+        }
+
+        let signature = Signature {
+            r: U256::from_big_endian(&self.r().0),
+            s: U256::from_big_endian(&self.s().0),
+            v: self.recovery_id + 27,
+        };
+        let address = signature
+            .recover(RecoveryMessage::Hash(H256::from_slice(digest)))
+            .map_err(|err| {
+                VdrError::CommonInvalidData(format!("Unable to recover signer. Err: {:?}", err))
+            })?;
+        Ok(Address::from(hex::encode(address.as_bytes()).as_str()))
+    }
+
     pub(crate) fn v(&self) -> SignatureV {
         SignatureV(self.recovery_id)
     }
@@ -60,3 +99,72 @@ impl TryFrom<&SignatureS> for ContractParam {
         Ok(ContractParam::FixedBytes(value.0.to_vec()))
     }
 }
+
+#[cfg(test)]
+pub mod test {
+    use super::*;
+    use crate::client::client::test::TRUSTEE_ACCOUNT;
+    use ethers_core::k256::ecdsa::SigningKey;
+
+    const TRUSTEE_PRIVATE_KEY: &str =
+        "8bbbb1b345af56b560a5b20bd4b0ed1cd8cc9958a16262bc75118453cb546df7";
+
+    const DIGEST: [u8; 32] = [
+        43, 20, 70, 238, 250, 209, 10, 195, 87, 39, 219, 125, 26, 151, 3, 233, 70, 185, 237, 52,
+        240, 127, 64, 8, 98, 136, 107, 144, 241, 122, 142, 64,
+    ];
+
+    fn signature() -> SignatureData {
+        SignatureData {
+            recovery_id: 1,
+            signature: vec![
+                200, 178, 128, 72, 163, 176, 188, 177, 119, 110, 11, 2, 194, 50, 220, 215, 0, 161,
+                247, 77, 43, 80, 139, 173, 141, 122, 58, 206, 72, 28, 63, 59, 9, 59, 95, 160, 244,
+                66, 209, 158, 150, 108, 172, 63, 216, 251, 53, 49, 237, 179, 250, 35, 127, 23, 178,
+                175, 72, 164, 219, 64, 129, 109, 109, 93,
+            ],
+        }
+    }
+
+    /// Sign the digest with the key of `TRUSTEE_ACCOUNT`
+    pub fn trustee_signature(digest: &[u8]) -> SignatureData {
+        let key = SigningKey::from_slice(&hex::decode(TRUSTEE_PRIVATE_KEY).unwrap()).unwrap();
+        let (signature, recovery_id) = key.sign_prehash_recoverable(digest).unwrap();
+        SignatureData {
+            recovery_id: recovery_id.to_byte() as u64,
+            signature: signature.to_bytes().to_vec(),
+        }
+    }
+
+    #[test]
+    fn recover_signer_test() {
+        assert_eq!(*TRUSTEE_ACCOUNT, signature().recover(&DIGEST).unwrap());
+    }
+
+    #[test]
+    fn recover_signer_of_other_message_test() {
+        let recovered = signature().recover(&[1; 32]).unwrap();
+
+        assert_ne!(*TRUSTEE_ACCOUNT, recovered);
+    }
+
+    #[test]
+    fn recover_trustee_signature_test() {
+        assert_eq!(
+            *TRUSTEE_ACCOUNT,
+            trustee_signature(&DIGEST).recover(&DIGEST).unwrap()
+        );
+    }
+
+    #[test]
+    fn recover_invalid_signature_test() {
+        let signature = SignatureData {
+            recovery_id: 1,
+            signature: vec![1; 63],
+        };
+
+        let err = signature.recover(&DIGEST).unwrap_err();
+
+        assert!(matches!(err, VdrError::CommonInvalidData(_)));
+    }
+}
@@ -4,8 +4,10 @@
 
 use ethabi::Uint;
 use ethereum::{
-    EnvelopedEncodable, LegacyTransaction, LegacyTransactionMessage, TransactionAction,
-    TransactionSignature as EthTransactionSignature,
+    AccessListItem as EthAccessListItem, EIP1559Transaction, EIP1559TransactionMessage,
+    EIP2930Transaction, EIP2930TransactionMessage, EnvelopedEncodable, LegacyTransaction,
+    LegacyTransactionMessage, TransactionAction, TransactionSignature as EthTransactionSignature,
+    TransactionV2,
 };
 use ethereum_types::{H160, H256, U256};
 use log::warn;
@@ -19,7 +21,7 @@ use crate::{
     types::{
         signature::SignatureData, Address, ContractOutput, ContractParam, MethodUintBytesParam,
     },
-    LedgerClient,
+    LedgerClient, Signer,
 };
 
 /// Type of transaction: write/read
@@ -31,6 +33,26 @@ pub enum TransactionType {
     Write,
 }
 
+/// Ethereum transaction envelope type defining the transaction encoding and gas fields
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub enum TransactionEnvelopeType {
+    /// Legacy transaction with EIP-155 replay protection (uses `gas_price`)
+    #[default]
+    Legacy,
+    /// EIP-2930 transaction with an access list (uses `gas_price`)
+    Eip2930,
+    /// EIP-1559 transaction with a dynamic fee (uses `max_fee_per_gas` and `max_priority_fee_per_gas`)
+    Eip1559,
+}
+
+/// Entry of EIP-2930 access list: contract address and storage keys accessed by the transaction
+#[derive(Debug, Default, PartialEq, Clone, Serialize, Deserialize)]
+pub struct AccessListItem {
+    pub address: Address,
+    /// 32 bytes storage keys
+    pub storage_keys: Vec<Vec<u8>>,
+}
+
 /// Definition of transaction object to send on the ledger
 #[derive(Debug, Default, PartialEq, Clone, Serialize, Deserialize)]
 pub struct Transaction {
@@ -54,6 +76,24 @@ pub struct Transaction {
     pub signature: Option<SignatureData>,
     /// transaction hash
     pub hash: Option<Vec<u8>>,
+    /// ethereum transaction envelope type: legacy, EIP-2930 or EIP-1559
+    #[serde(default)]
+    pub envelope_type: TransactionEnvelopeType,
+    /// gas limit. [GAS_LIMIT] is used if not set
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub gas_limit: Option<u64>,
+    /// gas price for legacy and EIP-2930 transactions. [GAS_PRICE] is used if not set
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub gas_price: Option<u64>,
+    /// max total fee per gas for EIP-1559 transactions. [GAS_PRICE] is used if not set
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_fee_per_gas: Option<u64>,
+    /// max priority fee per gas for EIP-1559 transactions. [GAS_PRICE] is used if not set
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_priority_fee_per_gas: Option<u64>,
+    /// access list for EIP-2930 and EIP-1559 transactions
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub access_list: Vec<AccessListItem>,
 }
 
 impl Transaction {
@@ -77,6 +117,7 @@ impl Transaction {
             nonce,
             signature,
             hash: None,
+            ..Transaction::default()
         }
     }
 
@@ -84,16 +125,41 @@ impl Transaction {
     #[logfn(Info)]
     #[logfn_inputs(Debug)]
     pub fn get_signing_bytes(&self) -> VdrResult<Vec<u8>> {
-        let eth_transaction: LegacyTransactionMessage = LegacyTransactionMessage {
-            nonce: self.get_nonce()?,
-            gas_price: *GAS_PRICE,
-            gas_limit: *GAS_LIMIT,
-            action: TransactionAction::Call(self.get_to()?),
-            value: Default::default(),
-            input: self.data.clone(),
-            chain_id: Some(self.chain_id),
+        let hash = match self.envelope_type {
+            TransactionEnvelopeType::Legacy => LegacyTransactionMessage {
+                nonce: self.get_nonce()?,
+                gas_price: self.get_gas_price(),
+                gas_limit: self.get_gas_limit(),
+                action: TransactionAction::Call(self.get_to()?),
+                value: Default::default(),
+                input: self.data.clone(),
+                chain_id: Some(self.chain_id),
+            }
+            .hash(),
+            TransactionEnvelopeType::Eip2930 => EIP2930TransactionMessage {
+                chain_id: self.chain_id,
+                nonce: self.get_nonce()?,
+                gas_price: self.get_gas_price(),
+                gas_limit: self.get_gas_limit(),
+                action: TransactionAction::Call(self.get_to()?),
+                value: Default::default(),
+                input: self.data.clone(),
+                access_list: self.get_access_list()?,
+            }
+            .hash(),
+            TransactionEnvelopeType::Eip1559 => EIP1559TransactionMessage {
+                chain_id: self.chain_id,
+                nonce: self.get_nonce()?,
+                max_priority_fee_per_gas: self.get_max_priority_fee_per_gas(),
+                max_fee_per_gas: self.get_max_fee_per_gas(),
+                gas_limit: self.get_gas_limit(),
+                action: TransactionAction::Call(self.get_to()?),
+                value: Default::default(),
+                input: self.data.clone(),
+                access_list: self.get_access_list()?,
+            }
+            .hash(),
         };
-        let hash = eth_transaction.hash();
         Ok(hash.as_bytes().to_vec())
     }
 
@@ -104,18 +170,93 @@ impl Transaction {
         self.signature = Some(signature_data)
     }
 
+    /// Sign the transaction with the key of the sender account and set the signature
+    ///
+    /// # Params
+    /// - `signer`: [Signer] - signer holding the key of the transaction sender
+    #[logfn(Info)]
+    #[logfn_inputs(Debug)]
+    pub async fn sign(&mut self, signer: &dyn Signer) -> VdrResult<()> {
+        let from = self.from.clone().ok_or_else(|| {
+            VdrError::ClientInvalidTransaction("Transaction `sender` is not set".to_string())
+        })?;
+        let signing_bytes = self.get_signing_bytes()?;
+        let signature = signer.sign(&signing_bytes, &from).await?;
+        self.set_signature(signature);
+        Ok(())
+    }
+
+    /// Recover the address of the account which signed the transaction
+    ///
+    /// # Returns
+    /// address of the signer account
+    #[logfn(Info)]
+    #[logfn_inputs(Debug)]
+    pub fn recover_signer(&self) -> VdrResult<Address> {
+        self.get_signature()?.recover(&self.get_signing_bytes()?)
+    }
+
+    /// Check that the transaction is signed by the sender account (`from`)
+    ///
+    /// # Returns
+    /// whether the signature belongs to the transaction sender
+    #[logfn(Info)]
+    #[logfn_inputs(Debug)]
+    pub fn verify_signature(&self) -> VdrResult<bool> {
+        let from = self.from.as_ref().ok_or_else(|| {
+            VdrError::ClientInvalidTransaction("Transaction `sender` is not set".to_string())
+        })?;
+        let signer = self.recover_signer()?;
+        Ok(signer.as_ref().eq_ignore_ascii_case(from.as_ref()))
+    }
+
     /// Encode transaction as bytes
     #[logfn(Trace)]
     #[logfn_inputs(Trace)]
     pub fn encode(&self) -> VdrResult<Vec<u8>> {
-        let transaction = LegacyTransaction {
-            nonce: self.get_nonce()?,
-            gas_price: *GAS_PRICE,
-            gas_limit: *GAS_LIMIT,
-            action: TransactionAction::Call(self.get_to()?),
-            value: Default::default(),
-            input: self.data.clone(),
-            signature: self.get_transaction_signature()?,
+        let transaction = match self.envelope_type {
+            TransactionEnvelopeType::Legacy => TransactionV2::Legacy(LegacyTransaction {
+                nonce: self.get_nonce()?,
+                gas_price: self.get_gas_price(),
+                gas_limit: self.get_gas_limit(),
+                action: TransactionAction::Call(self.get_to()?),
+                value: Default::default(),
+                input: self.data.clone(),
+                signature: self.get_transaction_signature()?,
+            }),
+            TransactionEnvelopeType::Eip2930 => {
+                let (odd_y_parity, r, s) = self.get_typed_transaction_signature()?;
+                TransactionV2::EIP2930(EIP2930Transaction {
+                    chain_id: self.chain_id,
+                    nonce: self.get_nonce()?,
+                    gas_price: self.get_gas_price(),
+                    gas_limit: self.get_gas_limit(),
+                    action: TransactionAction::Call(self.get_to()?),
+                    value: Default::default(),
+                    input: self.data.clone(),
+                    access_list: self.get_access_list()?,
+                    odd_y_parity,
+                    r,
+                    s,
+                })
+            }
+            TransactionEnvelopeType::Eip1559 => {
+                let (odd_y_parity, r, s) = self.get_typed_transaction_signature()?;
+                TransactionV2::EIP1559(EIP1559Transaction {
+                    chain_id: self.chain_id,
+                    nonce: self.get_nonce()?,
+                    max_priority_fee_per_gas: self.get_max_priority_fee_per_gas(),
+                    max_fee_per_gas: self.get_max_fee_per_gas(),
+                    gas_limit: self.get_gas_limit(),
+                    action: TransactionAction::Call(self.get_to()?),
+                    value: Default::default(),
+                    input: self.data.clone(),
+                    access_list: self.get_access_list()?,
+                    odd_y_parity,
+                    r,
+                    s,
+                })
+            }
         };
         Ok(transaction.encode().to_vec())
     }
@@ -164,14 +305,81 @@ impl Transaction {
         Ok(U256::from(nonce))
     }
 
+    fn get_gas_limit(&self) -> U256 {
+        self.gas_limit.map(U256::from).unwrap_or(*GAS_LIMIT)
+    }
+
+    fn get_gas_price(&self) -> U256 {
+        self.gas_price.map(U256::from).unwrap_or(*GAS_PRICE)
+    }
+
+    fn get_max_fee_per_gas(&self) -> U256 {
+        self.max_fee_per_gas.map(U256::from).unwrap_or(*GAS_PRICE)
+    }
+
+    fn get_max_priority_fee_per_gas(&self) -> U256 {
+        self.max_priority_fee_per_gas
+            .map(U256::from)
+            .unwrap_or(*GAS_PRICE)
+    }
+
+    #[logfn(Trace)]
+    #[logfn_inputs(Trace)]
+    fn get_access_list(&self) -> VdrResult<Vec<EthAccessListItem>> {
+        self.access_list
+            .iter()
+            .map(|item| {
+                let address = H160::from_str(item.address.as_ref()).map_err(|_| {
+                    VdrError::ClientInvalidTransaction(format!(
+                        "Invalid access list address {:?}",
+                        item.address
+                    ))
+                })?;
+                let storage_keys = item
+                    .storage_keys
+                    .iter()
+                    .map(|key| {
+                        if key.len() != H256::len_bytes() {
+                            return Err(VdrError::ClientInvalidTransaction(format!(
+                                "Invalid access list storage key {:?}",
+                                key
+                            )));
+                        }
+                        Ok(H256::from_slice(key))
+                    })
+                    .collect::<VdrResult<Vec<H256>>>()?;
+                Ok(EthAccessListItem {
+                    address,
+                    storage_keys,
+                })
+            })
+            .collect()
+    }
+
+    #[logfn(Trace)]
+    #[logfn_inputs(Trace)]
+    fn get_signature(&self) -> VdrResult<SignatureData> {
+        self.signature
+            .clone()
+            .ok_or_else(|| VdrError::ClientInvalidTransaction("Missing signature".to_string()))
+    }
+
+    // Typed transactions (EIP-2718) contain the recovery id as `y_parity` instead of EIP-155 `v`
+    #[logfn(Trace)]
+    #[logfn_inputs(Trace)]
+    fn get_typed_transaction_signature(&self) -> VdrResult<(bool, H256, H256)> {
+        let signature = self.get_signature()?;
+        Ok((
+            signature.v().0 == 1,
+            H256::from_slice(&signature.r().0),
+            H256::from_slice(&signature.s().0),
+        ))
+    }
+
     #[logfn(Trace)]
     #[logfn_inputs(Trace)]
     fn get_transaction_signature(&self) -> VdrResult<EthTransactionSignature> {
-        let signature = self
-            .signature
-            .as_ref()
-            .ok_or_else(|| VdrError::ClientInvalidTransaction("Missing signature".to_string()))?
-            .clone();
+        let signature = self.get_signature()?;
 
         let signature = EthTransactionSignature::new(
             signature.v().0 + 35 + self.chain_id * 2, // `v` is calculated according to EIP-155: https://eips.ethereum.org/EIPS/eip-155
@@ -270,28 +478,25 @@ impl TransactionBuilder {
             .function(&self.method)?
             .encode_input(&self.params)?;
 
-        let nonce = match self.type_ {
-            TransactionType::Write => {
-                let from = self.from.as_ref().ok_or_else(|| {
-                    VdrError::ClientInvalidTransaction(
-                        "Transaction `sender` is not set".to_string(),
-                    )
-                })?;
-                Some(client.get_transaction_count(from).await?)
-            }
-            TransactionType::Read => None,
-        };
-
-        let transaction = Transaction {
+        let mut transaction = Transaction {
             type_: self.type_,
             from: self.from,
             to: contract.address().clone(),
             chain_id: client.chain_id(),
             data,
-            nonce,
+            nonce: None,
             signature: None,
             hash: None,
+            ..Transaction::default()
         };
+        if transaction.type_ == TransactionType::Write {
+            let from = transaction.from.clone().ok_or_else(|| {
+                VdrError::ClientInvalidTransaction("Transaction `sender` is not set".to_string())
+            })?;
+            client.gas_policy().apply(client, &mut transaction).await?;
+            // nonce is reserved last, so it is not lost if the gas estimation fails
+            transaction.nonce = Some(client.next_nonce(&from).await?);
+        }
         Ok(transaction)
     }
 }
@@ -448,6 +653,7 @@ pub mod test {
         contracts::network::test::{
             ADD_VALIDATOR_METHOD, VALIDATOR_ADDRESS, VALIDATOR_CONTROL_NAME, VALIDATOR_LIST_BYTES,
         },
+        types::signature::test::trustee_signature,
     };
 
     const INVALID_METHOD: Option<&str> = Some(INVALID_ADDRESS);
@@ -466,6 +672,7 @@ pub mod test {
             data: vec![],
             signature: None,
             hash: None,
+            ..Transaction::default()
         }
     }
 
@@ -479,12 +686,14 @@ pub mod test {
             data: vec![],
             signature: None,
             hash: None,
+            ..Transaction::default()
         }
     }
 
     #[cfg(test)]
     pub mod txn_test {
         use super::*;
+        use rstest::rstest;
 
         #[async_std::test]
         async fn get_to_invalid() {
@@ -527,6 +736,117 @@ pub mod test {
                 get_sig_err,  | VdrError::ClientInvalidTransaction { .. }
             ));
         }
+
+        fn signed_transaction(envelope_type: TransactionEnvelopeType) -> Transaction {
+            Transaction {
+                envelope_type,
+                gas_limit: Some(100_000),
+                gas_price: Some(10),
+                max_fee_per_gas: Some(20),
+                max_priority_fee_per_gas: Some(1),
+                access_list: vec![AccessListItem {
+                    address: CONFIG.contracts.validator_control.address.clone(),
+                    storage_keys: vec![vec![1; 32]],
+                }],
+                signature: Some(SignatureData {
+                    recovery_id: 1,
+                    signature: vec![1; 64],
+                }),
+                ..write_transaction()
+            }
+        }
+
+        #[rstest]
+        #[case::legacy(TransactionEnvelopeType::Legacy, None)]
+        #[case::eip2930(TransactionEnvelopeType::Eip2930, Some(1))]
+        #[case::eip1559(TransactionEnvelopeType::Eip1559, Some(2))]
+        fn encode_typed_transaction(
+            #[case] envelope_type: TransactionEnvelopeType,
+            #[case] expected_prefix: Option<u8>,
+        ) {
+            let transaction = signed_transaction(envelope_type);
+
+            let encoded = transaction.encode().unwrap();
+
+            match expected_prefix {
+                // typed transactions are prefixed with the type byte (EIP-2718)
+                Some(prefix) => assert_eq!(prefix, encoded[0]),
+                // legacy transactions are plain RLP lists
+                None => assert!(encoded[0] >= 0xc0),
+            }
+        }
+
+        #[test]
+        fn signing_bytes_depend_on_envelope_type() {
+            let legacy = signed_transaction(TransactionEnvelopeType::Legacy)
+                .get_signing_bytes()
+                .unwrap();
+            let eip2930 = signed_transaction(TransactionEnvelopeType::Eip2930)
+                .get_signing_bytes()
+                .unwrap();
+            let eip1559 = signed_transaction(TransactionEnvelopeType::Eip1559)
+                .get_signing_bytes()
+                .unwrap();
+
+            assert_ne!(legacy, eip2930);
+            assert_ne!(legacy, eip1559);
+            assert_ne!(eip2930, eip1559);
+        }
+
+        #[test]
+        fn typed_transaction_signature_uses_y_parity() {
+            let transaction = signed_transaction(TransactionEnvelopeType::Eip1559);
+
+            let (odd_y_parity, _, _) = transaction.get_typed_transaction_signature().unwrap();
+
+            assert!(odd_y_parity);
+        }
+
+        #[rstest]
+        #[case::legacy(TransactionEnvelopeType::Legacy)]
+        #[case::eip1559(TransactionEnvelopeType::Eip1559)]
+        fn verify_transaction_signature(#[case] envelope_type: TransactionEnvelopeType) {
+            let mut transaction = signed_transaction(envelope_type);
+            transaction.set_signature(trustee_signature(&transaction.get_signing_bytes().unwrap()));
+
+            assert_eq!(*TRUSTEE_ACCOUNT, transaction.recover_signer().unwrap());
+            assert!(transaction.verify_signature().unwrap());
+        }
+
+        #[test]
+        fn verify_transaction_signature_of_other_sender() {
+            let mut transaction = signed_transaction(TransactionEnvelopeType::Legacy);
+            transaction.set_signature(trustee_signature(&transaction.get_signing_bytes().unwrap()));
+            transaction.from = Some(VALIDATOR_ADDRESS.clone());
+
+            assert!(!transaction.verify_signature().unwrap());
+        }
+
+        #[test]
+        fn access_list_invalid_storage_key() {
+            let transaction = Transaction {
+                access_list: vec![AccessListItem {
+                    address: CONFIG.contracts.validator_control.address.clone(),
+                    storage_keys: vec![vec![1; 31]],
+                }],
+                ..signed_transaction(TransactionEnvelopeType::Eip2930)
+            };
+
+            let err = transaction.encode().unwrap_err();
+
+            assert!(matches!(err, VdrError::ClientInvalidTransaction { .. }));
+        }
+
+        #[test]
+        fn deserialize_transaction_without_envelope_type() {
+            let mut json = serde_json::to_value(write_transaction()).unwrap();
+            json.as_object_mut().unwrap().remove("envelope_type");
+
+            let transaction = Transaction::from_string(&json.to_string()).unwrap();
+
+            assert_eq!(TransactionEnvelopeType::Legacy, transaction.envelope_type);
+            assert!(transaction.access_list.is_empty());
+        }
     }
 
     #[cfg(test)]
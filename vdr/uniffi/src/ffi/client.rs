@@ -4,14 +4,17 @@
 
 use crate::{
     ffi::{
+        endorsing_data::TransactionEndorsingData,
         error::VdrResult,
         event_query::{EventLog, EventQuery},
+        receipt::TransactionReceipt,
         transaction::Transaction,
-        types::{ContractConfig, PingStatus, QuorumConfig},
+        types::{ContractConfig, DecodedTransaction, PingStatus, QuorumConfig},
     },
     VdrError,
 };
-use indy_besu_vdr::{ContractConfig as ContractConfig_, LedgerClient as LedgerClient_};
+use indy_besu_vdr::{Address, ContractConfig as ContractConfig_, LedgerClient as LedgerClient_};
+use std::time::Duration;
 
 #[derive(uniffi::Object)]
 pub struct LedgerClient {
@@ -43,6 +46,26 @@ impl LedgerClient {
         Ok(LedgerClient { client })
     }
 
+    #[uniffi::constructor]
+    pub fn offline(
+        chain_id: u64,
+        contract_configs: Vec<ContractConfig>,
+        network: Option<String>,
+    ) -> VdrResult<LedgerClient> {
+        let contract_configs: Vec<ContractConfig_> = contract_configs
+            .into_iter()
+            .map(ContractConfig::into)
+            .collect();
+        let client = LedgerClient_::offline(chain_id, &contract_configs, network.as_deref())?;
+        Ok(LedgerClient { client })
+    }
+
+    pub fn set_next_nonce(&self, address: String, nonce: u64) -> VdrResult<()> {
+        self.client
+            .set_next_nonce(&Address::from(address.as_str()), nonce)
+            .map_err(VdrError::from)
+    }
+
     pub async fn ping(&self) -> VdrResult<PingStatus> {
         let ping = self.client.ping().await?;
         Ok(ping.into())
@@ -55,6 +78,33 @@ impl LedgerClient {
             .map_err(VdrError::from)
     }
 
+    pub async fn send_transaction(&self, transaction: &Transaction) -> VdrResult<Vec<u8>> {
+        self.client
+            .send_transaction(&transaction.into())
+            .await
+            .map_err(VdrError::from)
+    }
+
+    pub async fn simulate_transaction(&self, transaction: &Transaction) -> VdrResult<Vec<u8>> {
+        self.client
+            .simulate_transaction(&transaction.into())
+            .await
+            .map_err(VdrError::from)
+    }
+
+    pub async fn wait_for_receipt(
+        &self,
+        hash: Vec<u8>,
+        confirmations: u64,
+        timeout: u64,
+    ) -> VdrResult<TransactionReceipt> {
+        self.client
+            .wait_for_receipt(&hash, confirmations, Duration::from_millis(timeout))
+            .await
+            .map(TransactionReceipt::from)
+            .map_err(VdrError::from)
+    }
+
     pub async fn query_events(&self, query: &EventQuery) -> VdrResult<Vec<EventLog>> {
         Ok(self
             .client
@@ -65,7 +115,28 @@ impl LedgerClient {
             .collect())
     }
 
-    pub async fn get_receipt(&self, hash: Vec<u8>) -> VdrResult<String> {
-        self.client.get_receipt(&hash).await.map_err(VdrError::from)
+    pub async fn get_receipt(&self, hash: Vec<u8>) -> VdrResult<TransactionReceipt> {
+        self.client
+            .get_receipt(&hash)
+            .await
+            .map(TransactionReceipt::from)
+            .map_err(VdrError::from)
+    }
+
+    pub fn decode_transaction(&self, transaction: &Transaction) -> VdrResult<DecodedTransaction> {
+        self.client
+            .decode_transaction(&transaction.into())
+            .map(DecodedTransaction::from)
+            .map_err(VdrError::from)
+    }
+
+    pub fn decode_endorsing_data(
+        &self,
+        data: &TransactionEndorsingData,
+    ) -> VdrResult<DecodedTransaction> {
+        self.client
+            .decode_endorsing_data(&data.into())
+            .map(DecodedTransaction::from)
+            .map_err(VdrError::from)
     }
 }
@@ -277,6 +277,25 @@ pub async fn build_get_did_events_query(
     .map_err(VdrError::from)
 }
 
+#[uniffi::export(async_runtime = "tokio")]
+pub async fn build_get_dids_events_query(
+    client: &LedgerClient,
+    dids: Vec<String>,
+    from_block: Option<u64>,
+    to_block: Option<u64>,
+) -> VdrResult<EventQuery> {
+    let dids: Vec<DID> = dids.iter().map(|did| DID::from(did.as_str())).collect();
+    did_ethr_registry::build_get_dids_events_query(
+        &client.client,
+        &dids,
+        from_block.map(Block::from).as_ref(),
+        to_block.map(Block::from).as_ref(),
+    )
+    .await
+    .map(EventQuery::from)
+    .map_err(VdrError::from)
+}
+
 #[uniffi::export]
 pub fn parse_did_changed_result(client: &LedgerClient, bytes: Vec<u8>) -> VdrResult<u64> {
     let block = did_ethr_registry::parse_did_changed_result(&client.client, &bytes)?;
@@ -8,7 +8,17 @@ use crate::ffi::{
     error::{VdrError, VdrResult},
     transaction::Transaction,
 };
-use indy_besu_vdr::{endorsing, Address};
+use indy_besu_vdr::{endorsing, Address, EndorsementRequest as EndorsementRequest_, DID};
+
+#[derive(uniffi::Record)]
+pub struct EndorsementRequest {
+    pub version: u8,
+    pub author: String,
+    pub expires_at: u64,
+    pub purpose: String,
+    pub summary: String,
+    pub endorsing_data: TransactionEndorsingData,
+}
 
 #[uniffi::export(async_runtime = "tokio")]
 pub async fn build_endorsement_transaction(
@@ -25,3 +35,93 @@ pub async fn build_endorsement_transaction(
     .map(Transaction::from)
     .map_err(VdrError::from)
 }
+
+#[uniffi::export]
+pub fn build_endorsement_request(
+    client: &LedgerClient,
+    author: &str,
+    endorsing_data: &TransactionEndorsingData,
+    purpose: &str,
+    expires_at: u64,
+) -> VdrResult<EndorsementRequest> {
+    endorsing::build_endorsement_request(
+        &client.client,
+        &DID::from(author),
+        &endorsing_data.into(),
+        purpose,
+        expires_at,
+    )
+    .map(EndorsementRequest::from)
+    .map_err(VdrError::from)
+}
+
+#[uniffi::export(async_runtime = "tokio")]
+pub async fn validate_endorsement_request(
+    client: &LedgerClient,
+    endorser: &str,
+    request: &EndorsementRequest,
+) -> VdrResult<()> {
+    endorsing::validate_endorsement_request(
+        &client.client,
+        &Address::from(endorser),
+        &request.into(),
+    )
+    .await
+    .map_err(VdrError::from)
+}
+
+#[uniffi::export(async_runtime = "tokio")]
+pub async fn build_endorsement_request_transaction(
+    client: &LedgerClient,
+    from: &str,
+    request: &EndorsementRequest,
+) -> VdrResult<Transaction> {
+    endorsing::build_endorsement_request_transaction(
+        &client.client,
+        &Address::from(from),
+        &request.into(),
+    )
+    .await
+    .map(Transaction::from)
+    .map_err(VdrError::from)
+}
+
+#[uniffi::export]
+pub fn endorsement_request_to_string(request: &EndorsementRequest) -> VdrResult<String> {
+    EndorsementRequest_::from(request)
+        .to_string()
+        .map_err(VdrError::from)
+}
+
+#[uniffi::export]
+pub fn endorsement_request_from_string(value: &str) -> VdrResult<EndorsementRequest> {
+    EndorsementRequest_::from_string(value)
+        .map(EndorsementRequest::from)
+        .map_err(VdrError::from)
+}
+
+impl From<EndorsementRequest_> for EndorsementRequest {
+    fn from(request: EndorsementRequest_) -> Self {
+        EndorsementRequest {
+            version: request.version,
+            author: request.author.to_string(),
+            expires_at: request.expires_at,
+            purpose: request.purpose,
+            summary: request.summary,
+            endorsing_data: TransactionEndorsingData::from(request.endorsing_data),
+        }
+    }
+}
+
+impl From<&EndorsementRequest> for EndorsementRequest_ {
+    fn from(request: &EndorsementRequest) -> Self {
+        EndorsementRequest_ {
+            version: request.version,
+            author: DID::from(request.author.as_str()),
+            expires_at: request.expires_at,
+            purpose: request.purpose.to_string(),
+            summary: request.summary.to_string(),
+            endorsing_data: (&request.endorsing_data).into(),
+        }
+    }
+}
@@ -9,8 +9,10 @@ use crate::{
 };
 use indy_besu_vdr::{
     revocation_registry, Address, CredentialDefinitionId, RegistryType,
+    RevRegEntryCreated as RevRegEntryCreated_,
     RevocationRegistryDefinition as RevocationRegistryDefinition_, RevocationRegistryDefinitionId,
     RevocationRegistryDefinitionValue, RevocationRegistryEntry as RevocationRegistryEntry_,
+    RevocationRegistryEvents as RevocationRegistryEvents_,
     RevocationStatusList as RevocationStatusList_, DID,
 };
 use serde::Deserialize;
@@ -315,3 +317,39 @@ pub fn revocation_status_list_from_string(
         .map(RevocationStatusList::from)
         .map_err(VdrError::from)
 }
+
+#[derive(uniffi::Record)]
+pub struct RevRegEntryCreated {
+    pub revocation_registry_definition_id: Vec<u8>,
+    pub timestamp: u64,
+    pub parent_block_number: u64,
+    pub rev_reg_entry: RevocationRegistryEntry,
+}
+
+impl From<RevRegEntryCreated_> for RevRegEntryCreated {
+    fn from(event: RevRegEntryCreated_) -> Self {
+        RevRegEntryCreated {
+            revocation_registry_definition_id: event.revocation_registry_definition_id,
+            timestamp: event.timestamp,
+            parent_block_number: event.parent_block_number.value(),
+            rev_reg_entry: event.rev_reg_entry.into(),
+        }
+    }
+}
+
+#[derive(uniffi::Enum)]
+pub enum RevocationRegistryEvents {
+    RevocationRegistryEntryCreatedEvent { event: RevRegEntryCreated },
+}
+
+impl From<RevocationRegistryEvents_> for RevocationRegistryEvents {
+    fn from(event: RevocationRegistryEvents_) -> Self {
+        match event {
+            RevocationRegistryEvents_::RevocationRegistryEntryCreatedEvent(event) => {
+                RevocationRegistryEvents::RevocationRegistryEntryCreatedEvent {
+                    event: event.into(),
+                }
+            }
+        }
+    }
+}
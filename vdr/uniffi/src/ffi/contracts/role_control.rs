@@ -2,12 +2,15 @@
 // https://www.dsr-corporation.com
 // SPDX-License-Identifier: Apache-2.0
 
-use crate::ffi::{
-    client::LedgerClient,
-    error::{VdrError, VdrResult},
-    transaction::Transaction,
+use crate::{
+    ffi::{
+        client::LedgerClient,
+        error::{VdrError, VdrResult},
+        transaction::Transaction,
+    },
+    EventQuery,
 };
-use indy_besu_vdr::{role_control, Address, Role};
+use indy_besu_vdr::{role_control, Address, Block, Role};
 
 #[uniffi::export(async_runtime = "tokio")]
 pub async fn build_assign_role_transaction(
@@ -72,6 +75,28 @@ pub async fn build_get_role_transaction(
         .map_err(VdrError::from)
 }
 
+#[uniffi::export(async_runtime = "tokio")]
+pub async fn build_get_role_events_query(
+    client: &LedgerClient,
+    accounts: Vec<String>,
+    from_block: Option<u64>,
+    to_block: Option<u64>,
+) -> VdrResult<EventQuery> {
+    let accounts: Vec<Address> = accounts
+        .iter()
+        .map(|account| Address::from(account.as_str()))
+        .collect();
+    role_control::build_get_role_events_query(
+        &client.client,
+        &accounts,
+        from_block.map(Block::from).as_ref(),
+        to_block.map(Block::from).as_ref(),
+    )
+    .await
+    .map(EventQuery::from)
+    .map_err(VdrError::from)
+}
+
 #[uniffi::export]
 pub fn parse_has_role_result(client: &LedgerClient, bytes: Vec<u8>) -> VdrResult<bool> {
     role_control::parse_has_role_result(&client.client, &bytes).map_err(VdrError::from)
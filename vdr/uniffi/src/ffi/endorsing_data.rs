@@ -53,6 +53,25 @@ pub fn transaction_endorsing_data_get_signing_bytes(
         .map_err(VdrError::from)
 }
 
+#[uniffi::export]
+pub fn transaction_endorsing_data_recover_signer(
+    data: &TransactionEndorsingData,
+) -> VdrResult<String> {
+    TransactionEndorsingData_::from(data)
+        .recover_signer()
+        .map(|address| address.to_string())
+        .map_err(VdrError::from)
+}
+
+#[uniffi::export]
+pub fn transaction_endorsing_data_verify_signature(
+    data: &TransactionEndorsingData,
+) -> VdrResult<bool> {
+    TransactionEndorsingData_::from(data)
+        .verify_signature()
+        .map_err(VdrError::from)
+}
+
 #[uniffi::export]
 pub fn transaction_endorsing_data_to_string(data: &TransactionEndorsingData) -> VdrResult<String> {
     TransactionEndorsingData_::from(data)
@@ -2,7 +2,8 @@
 // https://www.dsr-corporation.com
 // SPDX-License-Identifier: Apache-2.0
 
-use indy_besu_vdr::VdrError as VdrError_;
+use crate::ffi::types::{DecodedParam, QuorumReport};
+use indy_besu_vdr::{ContractErrorKind as ContractErrorKind_, VdrError as VdrError_};
 
 #[derive(thiserror::Error, Debug, uniffi::Error)]
 pub enum VdrError {
@@ -21,6 +22,9 @@ pub enum VdrError {
     #[error("Ledger Client: Transaction reverted: {}", msg)]
     ClientTransactionReverted { msg: String },
 
+    #[error("Ledger Client: Transaction timed out: {}", msg)]
+    ClientTransactionTimeout { msg: String },
+
     #[error("Ledger Client: Unexpected error occurred: {}", msg)]
     ClientUnexpectedError { msg: String },
 
@@ -39,6 +43,15 @@ pub enum VdrError {
     #[error("Contract: Invalid response data: {}", msg)]
     ContractInvalidResponseData { msg: String },
 
+    #[error("Contract: Transaction reverted with error: {}", msg)]
+    ContractError {
+        msg: String,
+        contract: Option<String>,
+        name: String,
+        args: Vec<DecodedParam>,
+        kind: ContractErrorKind,
+    },
+
     #[error("Signer: Invalid private key")]
     SignerInvalidPrivateKey,
 
@@ -51,11 +64,17 @@ pub enum VdrError {
     #[error("Signer: Unexpected error occurred: {}", msg)]
     SignerUnexpectedError { msg: String },
 
+    #[error("Signer: Invalid keystore: {}", msg)]
+    SignerInvalidKeystore { msg: String },
+
+    #[error("Signer: Invalid mnemonic: {}", msg)]
+    SignerInvalidMnemonic { msg: String },
+
     #[error("Invalid data: {}", msg)]
     CommonInvalidData { msg: String },
 
     #[error("Ledger: Quorum not reached: {}", msg)]
-    QuorumNotReached { msg: String },
+    QuorumNotReached { msg: String, report: QuorumReport },
 
     #[error("Could not get transaction: {}", msg)]
     GetTransactionError { msg: String },
@@ -79,6 +98,28 @@ pub enum VdrError {
     InvalidRevocationRegistryStatusList { msg: String },
 }
 
+#[derive(Debug, uniffi::Enum)]
+pub enum ContractErrorKind {
+    DidNotFound,
+    DidAlreadyExist,
+    DidHasBeenDeactivated,
+    IncorrectDid,
+    NotIdentityOwner,
+    IssuerNotFound,
+    InvalidIssuerId,
+    IssuerHasBeenDeactivated,
+    SchemaAlreadyExist,
+    SchemaNotFound,
+    CredentialDefinitionAlreadyExist,
+    CredentialDefinitionNotFound,
+    AccumulatorMismatch,
+    NotRevocationRegistryDefinitionIssuer,
+    RevocationRegistryDefinitionAlreadyExist,
+    RevocationRegistryDefinitionNotFound,
+    Unauthorized,
+    Other,
+}
+
 pub type VdrResult<T> = Result<T, VdrError>;
 
 impl From<VdrError_> for VdrError {
@@ -93,6 +134,7 @@ impl From<VdrError_> for VdrError {
             VdrError_::ClientTransactionReverted(msg) => {
                 VdrError::ClientTransactionReverted { msg }
             }
+            VdrError_::ClientTransactionTimeout(msg) => VdrError::ClientTransactionTimeout { msg },
             VdrError_::ClientUnexpectedError(msg) => VdrError::ClientUnexpectedError { msg },
             VdrError_::ClientInvalidState(msg) => VdrError::ClientInvalidState { msg },
             VdrError_::ContractInvalidName(msg) => VdrError::ContractInvalidName { msg },
@@ -101,12 +143,24 @@ impl From<VdrError_> for VdrError {
             VdrError_::ContractInvalidResponseData(msg) => {
                 VdrError::ContractInvalidResponseData { msg }
             }
+            VdrError_::ContractError(error) => VdrError::ContractError {
+                msg: error.to_string(),
+                contract: error.contract,
+                name: error.name,
+                args: error.args.into_iter().map(DecodedParam::from).collect(),
+                kind: error.kind.into(),
+            },
             VdrError_::SignerInvalidPrivateKey => VdrError::SignerInvalidPrivateKey,
             VdrError_::SignerInvalidMessage => VdrError::SignerInvalidMessage,
             VdrError_::SignerMissingKey(msg) => VdrError::SignerMissingKey { msg },
             VdrError_::SignerUnexpectedError(msg) => VdrError::SignerUnexpectedError { msg },
+            VdrError_::SignerInvalidKeystore(msg) => VdrError::SignerInvalidKeystore { msg },
+            VdrError_::SignerInvalidMnemonic(msg) => VdrError::SignerInvalidMnemonic { msg },
             VdrError_::CommonInvalidData(msg) => VdrError::CommonInvalidData { msg },
-            VdrError_::QuorumNotReached(msg) => VdrError::QuorumNotReached { msg },
+            VdrError_::QuorumNotReached(msg, report) => VdrError::QuorumNotReached {
+                msg,
+                report: report.into(),
+            },
             VdrError_::GetTransactionError(msg) => VdrError::GetTransactionError { msg },
             VdrError_::InvalidSchema(msg) => VdrError::InvalidSchema { msg },
             VdrError_::InvalidCredentialDefinition(msg) => {
@@ -125,3 +179,40 @@ impl From<VdrError_> for VdrError {
         }
     }
 }
+
+impl From<ContractErrorKind_> for ContractErrorKind {
+    fn from(kind: ContractErrorKind_) -> Self {
+        match kind {
+            ContractErrorKind_::DidNotFound => ContractErrorKind::DidNotFound,
+            ContractErrorKind_::DidAlreadyExist => ContractErrorKind::DidAlreadyExist,
+            ContractErrorKind_::DidHasBeenDeactivated => ContractErrorKind::DidHasBeenDeactivated,
+            ContractErrorKind_::IncorrectDid => ContractErrorKind::IncorrectDid,
+            ContractErrorKind_::NotIdentityOwner => ContractErrorKind::NotIdentityOwner,
+            ContractErrorKind_::IssuerNotFound => ContractErrorKind::IssuerNotFound,
+            ContractErrorKind_::InvalidIssuerId => ContractErrorKind::InvalidIssuerId,
+            ContractErrorKind_::IssuerHasBeenDeactivated => {
+                ContractErrorKind::IssuerHasBeenDeactivated
+            }
+            ContractErrorKind_::SchemaAlreadyExist => ContractErrorKind::SchemaAlreadyExist,
+            ContractErrorKind_::SchemaNotFound => ContractErrorKind::SchemaNotFound,
+            ContractErrorKind_::CredentialDefinitionAlreadyExist => {
+                ContractErrorKind::CredentialDefinitionAlreadyExist
+            }
+            ContractErrorKind_::CredentialDefinitionNotFound => {
+                ContractErrorKind::CredentialDefinitionNotFound
+            }
+            ContractErrorKind_::AccumulatorMismatch => ContractErrorKind::AccumulatorMismatch,
+            ContractErrorKind_::NotRevocationRegistryDefinitionIssuer => {
+                ContractErrorKind::NotRevocationRegistryDefinitionIssuer
+            }
+            ContractErrorKind_::RevocationRegistryDefinitionAlreadyExist => {
+                ContractErrorKind::RevocationRegistryDefinitionAlreadyExist
+            }
+            ContractErrorKind_::RevocationRegistryDefinitionNotFound => {
+                ContractErrorKind::RevocationRegistryDefinitionNotFound
+            }
+            ContractErrorKind_::Unauthorized => ContractErrorKind::Unauthorized,
+            ContractErrorKind_::Other => ContractErrorKind::Other,
+        }
+    }
+}
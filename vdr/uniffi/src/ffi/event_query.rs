@@ -6,21 +6,23 @@ use indy_besu_vdr::{Address, Block, EventLog as EventLog_, EventQuery as EventQu
 
 #[derive(uniffi::Record)]
 pub struct EventQuery {
-    pub address: String,
+    pub addresses: Vec<String>,
     pub from_block: Option<u64>,
     pub to_block: Option<u64>,
-    pub event_signature: Option<String>,
-    pub event_filter: Option<String>,
+    pub topics: Vec<Option<Vec<String>>>,
 }
 
 impl From<&EventQuery> for EventQuery_ {
     fn from(query: &EventQuery) -> Self {
         EventQuery_ {
-            address: Address::from(query.address.as_ref()),
+            addresses: query
+                .addresses
+                .iter()
+                .map(|address| Address::from(address.as_ref()))
+                .collect(),
             from_block: query.from_block.map(Block::from),
             to_block: query.to_block.map(Block::from),
-            event_signature: query.event_signature.to_owned(),
-            event_filter: query.event_filter.to_owned(),
+            topics: query.topics.to_owned(),
         }
     }
 }
@@ -28,25 +30,34 @@ impl From<&EventQuery> for EventQuery_ {
 impl From<EventQuery_> for EventQuery {
     fn from(query: EventQuery_) -> Self {
         EventQuery {
-            address: query.address.as_ref().to_string(),
+            addresses: query
+                .addresses
+                .iter()
+                .map(|address| address.as_ref().to_string())
+                .collect(),
             from_block: query.from_block.map(|block| block.value()),
             to_block: query.to_block.map(|block| block.value()),
-            event_signature: query.event_signature,
-            event_filter: query.event_filter,
+            topics: query.topics,
         }
     }
 }
 
 #[derive(uniffi::Record)]
 pub struct EventLog {
+    pub address: Option<String>,
     pub topics: Vec<Vec<u8>>,
     pub data: Vec<u8>,
     pub block: u64,
+    pub transaction_hash: Option<Vec<u8>>,
+    pub log_index: Option<u64>,
+    pub block_hash: Option<Vec<u8>>,
+    pub removed: bool,
 }
 
 impl From<EventLog_> for EventLog {
     fn from(log: EventLog_) -> Self {
         EventLog {
+            address: log.address.map(|address| address.as_ref().to_string()),
             topics: log
                 .topics
                 .into_iter()
@@ -54,12 +65,23 @@ impl From<EventLog_> for EventLog {
                 .collect(),
             data: log.data,
             block: log.block.value(),
+            transaction_hash: log.transaction_hash,
+            log_index: log.log_index,
+            block_hash: log.block_hash,
+            removed: log.removed,
         }
     }
 }
 
 impl Into<EventLog_> for EventLog {
     fn into(self) -> EventLog_ {
-        EventLog_::new(self.topics, self.data, self.block)
+        EventLog_ {
+            address: self.address.map(|address| Address::from(address.as_ref())),
+            transaction_hash: self.transaction_hash,
+            log_index: self.log_index,
+            block_hash: self.block_hash,
+            removed: self.removed,
+            ..EventLog_::new(self.topics, self.data, self.block)
+        }
     }
 }
@@ -0,0 +1,116 @@
+// Copyright (c) 2024 DSR Corporation, Denver, Colorado.
+// https://www.dsr-corporation.com
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::ffi::{
+    contracts::{did_ethr_registry::DidEvents, revocation_registry::RevocationRegistryEvents},
+    event_query::EventLog,
+};
+use indy_besu_vdr::{
+    DecodedEvent as DecodedEvent_, DecodedEventParam as DecodedEventParam_,
+    ReceiptEvent as ReceiptEvent_, TransactionReceipt as TransactionReceipt_,
+    TransactionStatus as TransactionStatus_,
+};
+
+#[derive(uniffi::Enum)]
+pub enum TransactionStatus {
+    Success,
+    Failed,
+}
+
+#[derive(uniffi::Record)]
+pub struct TransactionReceipt {
+    pub transaction_hash: Vec<u8>,
+    pub status: TransactionStatus,
+    pub block_number: u64,
+    pub block_hash: Vec<u8>,
+    pub gas_used: u64,
+    pub contract_address: Option<String>,
+    pub revert_reason: Option<String>,
+    pub logs: Vec<EventLog>,
+    pub events: Vec<ReceiptEvent>,
+}
+
+#[derive(uniffi::Enum)]
+pub enum ReceiptEvent {
+    Did { event: DidEvents },
+    RevocationRegistry { event: RevocationRegistryEvents },
+    Other { event: DecodedEvent },
+}
+
+#[derive(uniffi::Record)]
+pub struct DecodedEvent {
+    pub contract: String,
+    pub name: String,
+    pub params: Vec<DecodedEventParam>,
+}
+
+#[derive(uniffi::Record)]
+pub struct DecodedEventParam {
+    pub name: String,
+    pub value: String,
+}
+
+impl From<TransactionStatus_> for TransactionStatus {
+    fn from(status: TransactionStatus_) -> Self {
+        match status {
+            TransactionStatus_::Success => TransactionStatus::Success,
+            TransactionStatus_::Failed => TransactionStatus::Failed,
+        }
+    }
+}
+
+impl From<TransactionReceipt_> for TransactionReceipt {
+    fn from(receipt: TransactionReceipt_) -> Self {
+        TransactionReceipt {
+            transaction_hash: receipt.transaction_hash,
+            status: receipt.status.into(),
+            block_number: receipt.block_number,
+            block_hash: receipt.block_hash,
+            gas_used: receipt.gas_used,
+            contract_address: receipt.contract_address.map(|address| address.to_string()),
+            revert_reason: receipt.revert_reason,
+            logs: receipt.logs.into_iter().map(EventLog::from).collect(),
+            events: receipt.events.into_iter().map(ReceiptEvent::from).collect(),
+        }
+    }
+}
+
+impl From<ReceiptEvent_> for ReceiptEvent {
+    fn from(event: ReceiptEvent_) -> Self {
+        match event {
+            ReceiptEvent_::Did(event) => ReceiptEvent::Did {
+                event: event.into(),
+            },
+            ReceiptEvent_::RevocationRegistry(event) => ReceiptEvent::RevocationRegistry {
+                event: event.into(),
+            },
+            ReceiptEvent_::Other(event) => ReceiptEvent::Other {
+                event: event.into(),
+            },
+        }
+    }
+}
+
+impl From<DecodedEvent_> for DecodedEvent {
+    fn from(event: DecodedEvent_) -> Self {
+        DecodedEvent {
+            contract: event.contract,
+            name: event.name,
+            params: event
+                .params
+                .into_iter()
+                .map(DecodedEventParam::from)
+                .collect(),
+        }
+    }
+}
+
+impl From<DecodedEventParam_> for DecodedEventParam {
+    fn from(param: DecodedEventParam_) -> Self {
+        DecodedEventParam {
+            name: param.name,
+            value: param.value,
+        }
+    }
+}
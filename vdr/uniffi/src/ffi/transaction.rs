@@ -4,7 +4,7 @@
 
 use crate::ffi::{
     error::{VdrError, VdrResult},
-    types::{SignatureData, TransactionType},
+    types::{AccessListItem, SignatureData, TransactionEnvelopeType, TransactionType},
 };
 use indy_besu_vdr::{Address, Transaction as Transaction_};
 
@@ -18,6 +18,12 @@ pub struct Transaction {
     pub data: Vec<u8>,
     pub signature: Option<SignatureData>,
     pub hash: Option<Vec<u8>>,
+    pub envelope_type: TransactionEnvelopeType,
+    pub gas_limit: Option<u64>,
+    pub gas_price: Option<u64>,
+    pub max_fee_per_gas: Option<u64>,
+    pub max_priority_fee_per_gas: Option<u64>,
+    pub access_list: Vec<AccessListItem>,
 }
 
 impl From<Transaction_> for Transaction {
@@ -31,6 +37,16 @@ impl From<Transaction_> for Transaction {
             data: transaction.data,
             signature: transaction.signature.as_ref().map(SignatureData::from),
             hash: transaction.hash,
+            envelope_type: TransactionEnvelopeType::from(&transaction.envelope_type),
+            gas_limit: transaction.gas_limit,
+            gas_price: transaction.gas_price,
+            max_fee_per_gas: transaction.max_fee_per_gas,
+            max_priority_fee_per_gas: transaction.max_priority_fee_per_gas,
+            access_list: transaction
+                .access_list
+                .iter()
+                .map(AccessListItem::from)
+                .collect(),
         }
     }
 }
@@ -46,6 +62,16 @@ impl From<&Transaction> for Transaction_ {
             data: transaction.data.to_owned(),
             signature: transaction.signature.as_ref().map(|data| data.into()),
             hash: transaction.hash.to_owned(),
+            envelope_type: (&transaction.envelope_type).into(),
+            gas_limit: transaction.gas_limit,
+            gas_price: transaction.gas_price,
+            max_fee_per_gas: transaction.max_fee_per_gas,
+            max_priority_fee_per_gas: transaction.max_priority_fee_per_gas,
+            access_list: transaction
+                .access_list
+                .iter()
+                .map(|item| item.into())
+                .collect(),
         }
     }
 }
@@ -70,6 +96,12 @@ pub fn transaction_create(
         data,
         signature,
         hash,
+        envelope_type: TransactionEnvelopeType::Legacy,
+        gas_limit: None,
+        gas_price: None,
+        max_fee_per_gas: None,
+        max_priority_fee_per_gas: None,
+        access_list: vec![],
     }
 }
 
@@ -80,6 +112,21 @@ pub fn transaction_get_signing_bytes(data: &Transaction) -> VdrResult<Vec<u8>> {
         .map_err(VdrError::from)
 }
 
+#[uniffi::export]
+pub fn transaction_recover_signer(data: &Transaction) -> VdrResult<String> {
+    Transaction_::from(data)
+        .recover_signer()
+        .map(|address| address.to_string())
+        .map_err(VdrError::from)
+}
+
+#[uniffi::export]
+pub fn transaction_verify_signature(data: &Transaction) -> VdrResult<bool> {
+    Transaction_::from(data)
+        .verify_signature()
+        .map_err(VdrError::from)
+}
+
 #[uniffi::export]
 pub fn transaction_to_string(data: &Transaction) -> VdrResult<String> {
     Transaction_::from(data).to_string().map_err(VdrError::from)
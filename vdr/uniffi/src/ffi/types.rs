@@ -4,10 +4,16 @@
 
 use crate::JsonValue;
 use indy_besu_vdr::{
-    ContractConfig as ContractConfig_, ContractSpec as ContractSpec_, PingStatus as PingStatus_,
-    QuorumConfig as QuorumConfig_, SignatureData as SignatureData_, Status as Status_,
-    TransactionType as TransactionType_,
+    AccessListItem as AccessListItem_, Address, ContractConfig as ContractConfig_,
+    ContractSpec as ContractSpec_, DecodedParam as DecodedParam_,
+    DecodedTransaction as DecodedTransaction_, PingStatus as PingStatus_,
+    QuorumConfig as QuorumConfig_, QuorumNodeReport as QuorumNodeReport_,
+    QuorumNodeStatus as QuorumNodeStatus_, QuorumReport as QuorumReport_,
+    QuorumThreshold as QuorumThreshold_, SignatureData as SignatureData_, Status as Status_,
+    TransactionEnvelopeType as TransactionEnvelopeType_, TransactionType as TransactionType_,
 };
+use serde_json::json;
+use std::collections::HashMap;
 
 #[derive(uniffi::Record)]
 pub struct PingStatus {
@@ -38,12 +44,40 @@ pub struct ContractSpec {
     pub abi: JsonValue,
 }
 
+#[derive(uniffi::Record)]
+pub struct DecodedTransaction {
+    pub contract: String,
+    pub method: String,
+    pub params: Vec<DecodedParam>,
+    pub payload: Option<JsonValue>,
+}
+
+#[derive(Debug, uniffi::Record)]
+pub struct DecodedParam {
+    pub name: String,
+    pub type_: String,
+    pub value: String,
+}
+
 #[derive(uniffi::Enum)]
 pub enum TransactionType {
     Read,
     Write,
 }
 
+#[derive(uniffi::Enum)]
+pub enum TransactionEnvelopeType {
+    Legacy,
+    Eip2930,
+    Eip1559,
+}
+
+#[derive(uniffi::Record)]
+pub struct AccessListItem {
+    pub address: String,
+    pub storage_keys: Vec<Vec<u8>>,
+}
+
 #[derive(uniffi::Record)]
 pub struct SignatureData {
     pub recovery_id: u64,
@@ -56,6 +90,39 @@ pub struct QuorumConfig {
     pub request_retries: Option<u8>,
     pub request_timeout: Option<u64>,
     pub retry_interval: Option<u64>,
+    pub threshold: Option<QuorumThreshold>,
+    pub weights: Option<HashMap<String, u64>>,
+}
+
+#[derive(uniffi::Enum)]
+pub enum QuorumThreshold {
+    Count { count: u64 },
+    Fraction { fraction: f64 },
+    All,
+}
+
+#[derive(Debug, uniffi::Record)]
+pub struct QuorumReport {
+    pub reached: bool,
+    pub required_weight: u64,
+    pub agreed_weight: u64,
+    pub nodes: Vec<QuorumNodeReport>,
+}
+
+#[derive(Debug, uniffi::Record)]
+pub struct QuorumNodeReport {
+    pub node: String,
+    pub weight: u64,
+    pub status: QuorumNodeStatus,
+}
+
+#[derive(Debug, uniffi::Enum)]
+pub enum QuorumNodeStatus {
+    Agreed { response: String },
+    Disagreed { response: String },
+    TimedOut,
+    Errored { error: String },
+    Pending,
 }
 
 impl From<PingStatus_> for PingStatus {
@@ -109,6 +176,44 @@ impl Into<TransactionType_> for &TransactionType {
     }
 }
 
+impl Into<TransactionEnvelopeType_> for &TransactionEnvelopeType {
+    fn into(self) -> TransactionEnvelopeType_ {
+        match self {
+            TransactionEnvelopeType::Legacy => TransactionEnvelopeType_::Legacy,
+            TransactionEnvelopeType::Eip2930 => TransactionEnvelopeType_::Eip2930,
+            TransactionEnvelopeType::Eip1559 => TransactionEnvelopeType_::Eip1559,
+        }
+    }
+}
+
+impl From<&TransactionEnvelopeType_> for TransactionEnvelopeType {
+    fn from(type_: &TransactionEnvelopeType_) -> TransactionEnvelopeType {
+        match type_ {
+            TransactionEnvelopeType_::Legacy => TransactionEnvelopeType::Legacy,
+            TransactionEnvelopeType_::Eip2930 => TransactionEnvelopeType::Eip2930,
+            TransactionEnvelopeType_::Eip1559 => TransactionEnvelopeType::Eip1559,
+        }
+    }
+}
+
+impl Into<AccessListItem_> for &AccessListItem {
+    fn into(self) -> AccessListItem_ {
+        AccessListItem_ {
+            address: Address::from(self.address.as_str()),
+            storage_keys: self.storage_keys.clone(),
+        }
+    }
+}
+
+impl From<&AccessListItem_> for AccessListItem {
+    fn from(item: &AccessListItem_) -> AccessListItem {
+        AccessListItem {
+            address: item.address.to_string(),
+            storage_keys: item.storage_keys.clone(),
+        }
+    }
+}
+
 impl From<&TransactionType_> for TransactionType {
     fn from(type_: &TransactionType_) -> TransactionType {
         match type_ {
@@ -143,6 +248,78 @@ impl Into<QuorumConfig_> for QuorumConfig {
             request_retries: self.request_retries,
             request_timeout: self.request_timeout,
             retry_interval: self.retry_interval,
+            threshold: self.threshold.map(QuorumThreshold::into),
+            weights: self.weights,
+        }
+    }
+}
+
+impl Into<QuorumThreshold_> for QuorumThreshold {
+    fn into(self) -> QuorumThreshold_ {
+        match self {
+            QuorumThreshold::Count { count } => QuorumThreshold_::Count(count),
+            QuorumThreshold::Fraction { fraction } => QuorumThreshold_::Fraction(fraction),
+            QuorumThreshold::All => QuorumThreshold_::All,
+        }
+    }
+}
+
+impl From<DecodedTransaction_> for DecodedTransaction {
+    fn from(decoded: DecodedTransaction_) -> Self {
+        DecodedTransaction {
+            contract: decoded.contract,
+            method: decoded.method,
+            params: decoded.params.into_iter().map(DecodedParam::from).collect(),
+            payload: decoded
+                .payload
+                .map(|payload| JsonValue::from(json!(payload))),
+        }
+    }
+}
+
+impl From<DecodedParam_> for DecodedParam {
+    fn from(param: DecodedParam_) -> Self {
+        DecodedParam {
+            name: param.name,
+            type_: param.type_,
+            value: param.value,
+        }
+    }
+}
+
+impl From<QuorumReport_> for QuorumReport {
+    fn from(report: QuorumReport_) -> Self {
+        QuorumReport {
+            reached: report.reached,
+            required_weight: report.required_weight,
+            agreed_weight: report.agreed_weight,
+            nodes: report
+                .nodes
+                .into_iter()
+                .map(QuorumNodeReport::from)
+                .collect(),
+        }
+    }
+}
+
+impl From<QuorumNodeReport_> for QuorumNodeReport {
+    fn from(report: QuorumNodeReport_) -> Self {
+        QuorumNodeReport {
+            node: report.node,
+            weight: report.weight,
+            status: report.status.into(),
+        }
+    }
+}
+
+impl From<QuorumNodeStatus_> for QuorumNodeStatus {
+    fn from(status: QuorumNodeStatus_) -> Self {
+        match status {
+            QuorumNodeStatus_::Agreed { response } => QuorumNodeStatus::Agreed { response },
+            QuorumNodeStatus_::Disagreed { response } => QuorumNodeStatus::Disagreed { response },
+            QuorumNodeStatus_::TimedOut => QuorumNodeStatus::TimedOut,
+            QuorumNodeStatus_::Errored { error } => QuorumNodeStatus::Errored { error },
+            QuorumNodeStatus_::Pending => QuorumNodeStatus::Pending,
         }
     }
 }
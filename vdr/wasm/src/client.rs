@@ -3,16 +3,16 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use js_sys::Promise;
-use std::rc::Rc;
+use std::{rc::Rc, time::Duration};
 use wasm_bindgen::prelude::*;
 use wasm_bindgen_futures::future_to_promise;
 
-use indy_besu_vdr::{ContractConfig, LedgerClient, QuorumConfig};
+use indy_besu_vdr::{Address, ContractConfig, LedgerClient, QuorumConfig};
 
 use crate::{
     error::{JsResult, Result},
     event_query::EventQueryWrapper,
-    transaction::TransactionWrapper,
+    transaction::{TransactionEndorsingDataWrapper, TransactionWrapper},
 };
 
 #[wasm_bindgen(js_name = LedgerClient)]
@@ -44,6 +44,27 @@ impl LedgerClientWrapper {
         Ok(LedgerClientWrapper(Rc::new(client)))
     }
 
+    pub fn offline(
+        chain_id: u32,
+        contract_configs: JsValue,
+        network: Option<String>,
+    ) -> Result<LedgerClientWrapper> {
+        console_error_panic_hook::set_once();
+        let contract_configs: Vec<ContractConfig> =
+            serde_wasm_bindgen::from_value(contract_configs)?;
+        let client = LedgerClient::offline(chain_id as u64, &contract_configs, network.as_deref())
+            .as_js()?;
+        Ok(LedgerClientWrapper(Rc::new(client)))
+    }
+
+    #[wasm_bindgen(js_name = setNextNonce)]
+    pub fn set_next_nonce(&self, address: &str, nonce: u32) -> Result<()> {
+        self.0
+            .set_next_nonce(&Address::from(address), nonce as u64)
+            .as_js()?;
+        Ok(())
+    }
+
     pub async fn ping(&self) -> Promise {
         let client = self.0.clone();
         future_to_promise(async move {
@@ -65,6 +86,52 @@ impl LedgerClientWrapper {
         })
     }
 
+    #[wasm_bindgen(js_name = sendTransaction)]
+    pub async fn send_transaction(&self, transaction: &TransactionWrapper) -> Promise {
+        let client = self.0.clone();
+        let transaction = transaction.0.clone();
+        future_to_promise(async move {
+            let transaction = transaction.borrow();
+            let hash = client.send_transaction(&transaction).await.as_js()?;
+            let result: JsValue = serde_wasm_bindgen::to_value(&hash)?;
+            Ok(result)
+        })
+    }
+
+    #[wasm_bindgen(js_name = simulateTransaction)]
+    pub async fn simulate_transaction(&self, transaction: &TransactionWrapper) -> Promise {
+        let client = self.0.clone();
+        let transaction = transaction.0.clone();
+        future_to_promise(async move {
+            let transaction = transaction.borrow();
+            let response = client.simulate_transaction(&transaction).await.as_js()?;
+            let result: JsValue = serde_wasm_bindgen::to_value(&response)?;
+            Ok(result)
+        })
+    }
+
+    #[wasm_bindgen(js_name = waitForReceipt)]
+    pub async fn wait_for_receipt(
+        &self,
+        hash: Vec<u8>,
+        confirmations: u32,
+        timeout: u32,
+    ) -> Promise {
+        let client = self.0.clone();
+        future_to_promise(async move {
+            let receipt = client
+                .wait_for_receipt(
+                    &hash,
+                    confirmations as u64,
+                    Duration::from_millis(timeout as u64),
+                )
+                .await
+                .as_js()?;
+            let result: JsValue = serde_wasm_bindgen::to_value(&receipt)?;
+            Ok(result)
+        })
+    }
+
     #[wasm_bindgen(js_name = queryEvents)]
     pub async fn query_events(&self, query: &EventQueryWrapper) -> Promise {
         let client = self.0.clone();
@@ -85,4 +152,18 @@ impl LedgerClientWrapper {
             Ok(result)
         })
     }
+
+    #[wasm_bindgen(js_name = decodeTransaction)]
+    pub fn decode_transaction(&self, transaction: &TransactionWrapper) -> Result<JsValue> {
+        let decoded = self.0.decode_transaction(&transaction.0.borrow()).as_js()?;
+        let result: JsValue = serde_wasm_bindgen::to_value(&decoded)?;
+        Ok(result)
+    }
+
+    #[wasm_bindgen(js_name = decodeEndorsingData)]
+    pub fn decode_endorsing_data(&self, data: &TransactionEndorsingDataWrapper) -> Result<JsValue> {
+        let decoded = self.0.decode_endorsing_data(&data.0.borrow()).as_js()?;
+        let result: JsValue = serde_wasm_bindgen::to_value(&decoded)?;
+        Ok(result)
+    }
 }
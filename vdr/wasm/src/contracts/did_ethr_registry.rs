@@ -300,6 +300,27 @@ impl EthrDidRegistry {
         Ok(EventQueryWrapper(Rc::new(query)))
     }
 
+    #[wasm_bindgen(js_name = buildGetDidsEventsQuery)]
+    pub async fn build_get_dids_events_query(
+        client: &LedgerClientWrapper,
+        dids: Vec<String>,
+        from_block: Option<u64>,
+        to_block: Option<u64>,
+    ) -> Result<EventQueryWrapper> {
+        let dids: Vec<DID> = dids.iter().map(|did| DID::from(did.as_str())).collect();
+        let from_block = from_block.map(Block::from);
+        let to_block = to_block.map(Block::from);
+        let query = did_ethr_registry::build_get_dids_events_query(
+            &client.0,
+            &dids,
+            from_block.as_ref(),
+            to_block.as_ref(),
+        )
+        .await
+        .as_js()?;
+        Ok(EventQueryWrapper(Rc::new(query)))
+    }
+
     #[wasm_bindgen(js_name = parseDidChangedResult)]
     pub fn parse_did_changed_result(client: &LedgerClientWrapper, bytes: Vec<u8>) -> Result<u64> {
         let block = did_ethr_registry::parse_did_changed_result(&client.0, &bytes).as_js()?;
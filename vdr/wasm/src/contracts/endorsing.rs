@@ -2,7 +2,7 @@
 // https://www.dsr-corporation.com
 // SPDX-License-Identifier: Apache-2.0
 
-use indy_besu_vdr::{endorsing, Address};
+use indy_besu_vdr::{endorsing, Address, EndorsementRequest, DID};
 use wasm_bindgen::prelude::*;
 
 use crate::{
@@ -29,4 +29,54 @@ impl Endorsement {
             .map(TransactionWrapper::from)
             .map_err(JsValue::from)
     }
+
+    #[wasm_bindgen(js_name = buildEndorsementRequest)]
+    pub fn build_endorsement_request(
+        client: &LedgerClientWrapper,
+        author: &str,
+        endorsing_data: &TransactionEndorsingDataWrapper,
+        purpose: &str,
+        expires_at: u64,
+    ) -> Result<JsValue> {
+        let author = DID::from(author);
+        let request = endorsing::build_endorsement_request(
+            &client.0,
+            &author,
+            &endorsing_data.0.borrow(),
+            purpose,
+            expires_at,
+        )
+        .as_js()?;
+        let result: JsValue = serde_wasm_bindgen::to_value(&request)?;
+        Ok(result)
+    }
+
+    #[wasm_bindgen(js_name = validateEndorsementRequest)]
+    pub async fn validate_endorsement_request(
+        client: &LedgerClientWrapper,
+        endorser: &str,
+        request: JsValue,
+    ) -> Result<()> {
+        let endorser = Address::from(endorser);
+        let request: EndorsementRequest = serde_wasm_bindgen::from_value(request)?;
+        endorsing::validate_endorsement_request(&client.0, &endorser, &request)
+            .await
+            .as_js()?;
+        Ok(())
+    }
+
+    #[wasm_bindgen(js_name = buildEndorsementRequestTransaction)]
+    pub async fn build_endorsement_request_transaction(
+        client: &LedgerClientWrapper,
+        from: &str,
+        request: JsValue,
+    ) -> Result<TransactionWrapper> {
+        let from = Address::from(from);
+        let request: EndorsementRequest = serde_wasm_bindgen::from_value(request)?;
+        endorsing::build_endorsement_request_transaction(&client.0, &from, &request)
+            .await
+            .as_js()
+            .map(TransactionWrapper::from)
+            .map_err(JsValue::from)
+    }
 }
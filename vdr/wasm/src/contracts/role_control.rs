@@ -2,12 +2,14 @@
 // https://www.dsr-corporation.com
 // SPDX-License-Identifier: Apache-2.0
 
-use indy_besu_vdr::{role_control, Address, Role};
+use indy_besu_vdr::{role_control, Address, Block, Role};
+use std::rc::Rc;
 use wasm_bindgen::prelude::*;
 
 use crate::{
     client::LedgerClientWrapper,
     error::{JsResult, Result},
+    event_query::EventQueryWrapper,
     transaction::TransactionWrapper,
 };
 
@@ -78,6 +80,30 @@ impl RoleControl {
             .map_err(JsValue::from)
     }
 
+    #[wasm_bindgen(js_name = buildGetRoleEventsQuery)]
+    pub async fn build_get_role_events_query(
+        client: &LedgerClientWrapper,
+        accounts: Vec<String>,
+        from_block: Option<u64>,
+        to_block: Option<u64>,
+    ) -> Result<EventQueryWrapper> {
+        let accounts: Vec<Address> = accounts
+            .iter()
+            .map(|account| Address::from(account.as_str()))
+            .collect();
+        let from_block = from_block.map(Block::from);
+        let to_block = to_block.map(Block::from);
+        let query = role_control::build_get_role_events_query(
+            &client.0,
+            &accounts,
+            from_block.as_ref(),
+            to_block.as_ref(),
+        )
+        .await
+        .as_js()?;
+        Ok(EventQueryWrapper(Rc::new(query)))
+    }
+
     #[wasm_bindgen(js_name = parseHasRoleResult)]
     pub fn parse_has_role_result(client: &LedgerClientWrapper, bytes: Vec<u8>) -> Result<bool> {
         let has_role = role_control::parse_has_role_result(&client.0, &bytes).as_js()?;
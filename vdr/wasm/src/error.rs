@@ -2,8 +2,8 @@
 // https://www.dsr-corporation.com
 // SPDX-License-Identifier: Apache-2.0
 
-use indy_besu_vdr::VdrResult;
-use js_sys::Error as JsError;
+use indy_besu_vdr::{VdrError, VdrResult};
+use js_sys::{Error as JsError, Reflect};
 use wasm_bindgen::JsValue;
 
 pub type Result<T> = core::result::Result<T, JsValue>;
@@ -15,6 +15,23 @@ pub(crate) trait JsResult<T> {
 
 impl<T> JsResult<T> for VdrResult<T> {
     fn as_js(self) -> core::result::Result<T, JsError> {
-        self.map_err(|e| JsError::new(&e.to_string()))
+        self.map_err(|e| {
+            let error = JsError::new(&e.to_string());
+            // expose decoded contract error so that callers can branch on its kind
+            if let VdrError::ContractError(contract_error) = &e {
+                error.set_name("ContractError");
+                if let Ok(details) = serde_wasm_bindgen::to_value(contract_error) {
+                    let _ = Reflect::set(&error, &JsValue::from_str("contractError"), &details);
+                }
+            }
+            // expose the quorum report so that callers can inspect results of every node
+            if let VdrError::QuorumNotReached(_, report) = &e {
+                error.set_name("QuorumNotReached");
+                if let Ok(details) = serde_wasm_bindgen::to_value(report) {
+                    let _ = Reflect::set(&error, &JsValue::from_str("quorumReport"), &details);
+                }
+            }
+            error
+        })
     }
 }
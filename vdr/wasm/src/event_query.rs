@@ -6,5 +6,16 @@ use indy_besu_vdr::EventQuery;
 use std::rc::Rc;
 use wasm_bindgen::prelude::*;
 
+use crate::error::Result;
+
 #[wasm_bindgen(js_name = EventQuery)]
 pub struct EventQueryWrapper(pub(crate) Rc<EventQuery>);
+
+#[wasm_bindgen(js_class = EventQuery)]
+impl EventQueryWrapper {
+    #[wasm_bindgen(constructor)]
+    pub fn new(query: JsValue) -> Result<EventQueryWrapper> {
+        let query: EventQuery = serde_wasm_bindgen::from_value(query)?;
+        Ok(EventQueryWrapper(Rc::new(query)))
+    }
+}
@@ -29,6 +29,18 @@ impl TransactionWrapper {
         self.0.get_mut().set_signature(signature_data);
         Ok(())
     }
+
+    #[wasm_bindgen(js_name = recoverSigner)]
+    pub fn recover_signer(&self) -> Result<String> {
+        let address = self.0.borrow().recover_signer().as_js()?;
+        Ok(address.to_string())
+    }
+
+    #[wasm_bindgen(js_name = verifySignature)]
+    pub fn verify_signature(&self) -> Result<bool> {
+        let verified = self.0.borrow().verify_signature().as_js()?;
+        Ok(verified)
+    }
 }
 
 impl From<Transaction> for TransactionWrapper {
@@ -54,6 +66,18 @@ impl TransactionEndorsingDataWrapper {
         self.0.get_mut().set_signature(signature_data);
         Ok(())
     }
+
+    #[wasm_bindgen(js_name = recoverSigner)]
+    pub fn recover_signer(&self) -> Result<String> {
+        let address = self.0.borrow().recover_signer().as_js()?;
+        Ok(address.to_string())
+    }
+
+    #[wasm_bindgen(js_name = verifySignature)]
+    pub fn verify_signature(&self) -> Result<bool> {
+        let verified = self.0.borrow().verify_signature().as_js()?;
+        Ok(verified)
+    }
 }
 
 impl From<TransactionEndorsingData> for TransactionEndorsingDataWrapper {